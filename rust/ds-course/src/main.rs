@@ -4,11 +4,15 @@ use std::error::Error;
 
 mod structs {
     pub mod arrays;
+    pub mod channels;
     pub mod expressions;
+    pub mod history;
     pub mod linked_lists;
     pub mod matrices;
     pub mod polynomials;
     pub mod queues;
+    pub mod scheduling;
+    pub mod skip_lists;
     pub mod smart_ptrs;
     pub mod stacks;
     pub mod strings;
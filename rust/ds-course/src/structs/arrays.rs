@@ -33,6 +33,10 @@ pub struct HeapArray<T> {
     length: usize,
 }
 
+// The Array owns its values, so it can move to another thread with them. It is not Sync because swap writes through
+// a shared reference.
+unsafe impl<T: Send> Send for HeapArray<T> {}
+
 impl<T> HeapArray<T> {
     fn init_mem(size: usize) -> Result<(*mut T, Layout), &'static str> {
         let layout = Layout::array::<T>(size).expect("Layout creation failed");
@@ -178,6 +182,15 @@ impl<T> HeapArray<T> {
         self.ptr
     }
 
+    // The caller must make sure that the first length slots hold values and that the rest were moved out or never
+    // written, otherwise Drop frees the values twice or leaks them.
+    pub(crate) unsafe fn set_len(&mut self, length: usize) {
+        if length > self.size {
+            panic!("Length is greater than the Array size!");
+        }
+        self.length = length;
+    }
+
     pub(crate) fn get_len(&self) -> usize {
         self.length
     }
@@ -893,10 +906,15 @@ impl<T> IndexMut<usize> for HeapArray<T> {
 
 impl<T> Drop for HeapArray<T> {
     fn drop(&mut self) {
+        if self.ptr.is_null() {
+            return;
+        }
         let layout = Layout::array::<T>(self.size).expect("Layout creation failed");
 
+        // Only the first length slots hold values. The values past the length were either never written or already
+        // moved out by pop and delete, so dropping them again would free their memory twice.
         unsafe {
-            for i in 0..self.size {
+            for i in 0..self.length {
                 ptr::drop_in_place(self.ptr.add(i));
             }
             alloc::dealloc(self.ptr as *mut u8, layout);
@@ -936,6 +954,8 @@ mod heap_array {
     use crate::structs::strings::HeapString;
     use paste::paste;
     use rand::{thread_rng, Rng};
+    use std::cell::Cell;
+    use std::rc::Rc;
 
     macro_rules! define_test_new {
         ($($struct:ident<$type:ty>),*) => {
@@ -1395,6 +1415,35 @@ mod heap_array {
         // assert_eq!(into_iterator.next(), None, "The into iterator impl. did not return the expected sequence.");
     }
 
+    #[test]
+    fn test_drop_after_pop() {
+        struct Counted(Rc<Cell<usize>>);
+
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut array: HeapArray<Counted> = HeapArray::with_capacity(2);
+        array.push(Counted(Rc::clone(&drops)));
+        array.push(Counted(Rc::clone(&drops)));
+        let popped = array.pop();
+        drop(array);
+        assert_eq!(
+            drops.get(),
+            1,
+            "Array must drop only the values it still holds!"
+        );
+        drop(popped);
+        assert_eq!(
+            drops.get(),
+            2,
+            "Popped value must be dropped once by its owner!"
+        );
+    }
+
     define_test_iterator!(
         char, usize, isize, i8, i16, i32, i64, u8, u16, u32, u64, f32, f64, String, HeapString
     );
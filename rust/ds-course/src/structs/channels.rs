@@ -0,0 +1,1075 @@
+/*
+Queues for passing values between threads
+- Single Producer Single Consumer Ring Buffer without locks
+- Bounded Multi Producer Multi Consumer Channel with blocking operations
+
+The Ring Buffer works like the Circular Queue, except that it is split into a Producer half and a Consumer half which
+can be moved to different threads. Only the Producer moves the rear and only the Consumer moves the front, so both
+halves can work at the same time with atomic indices instead of a lock.
+*/
+#![allow(dead_code)]
+use crate::structs::arrays::HeapArray;
+use crate::structs::queues::{CircularQueue, QueueADT};
+use std::cell::UnsafeCell;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/*
+NOTE: The front and the rear count every item which was ever pushed or popped instead of wrapping around at the size,
+so the length is always rear - front and the buffer can hold size items, the same as the Circular Queue. The counters
+use wrapping arithmetic, which would only matter after usize::MAX items.
+*/
+pub struct SpscRingBuffer<T> {
+    size: usize,
+    front: AtomicUsize,
+    rear: AtomicUsize,
+    slots: HeapArray<UnsafeCell<MaybeUninit<T>>>,
+}
+
+// The slots between front and rear are only read by the Consumer and the other slots are only written by the
+// Producer, so the buffer can be shared as long as the items can be sent to another thread.
+unsafe impl<T: Send> Send for SpscRingBuffer<T> {}
+unsafe impl<T: Send> Sync for SpscRingBuffer<T> {}
+
+impl<T> SpscRingBuffer<T> {
+    // Creates the buffer with the given size and splits it into its two halves.
+    pub fn split(size: usize) -> (Producer<T>, Consumer<T>) {
+        let mut slots = HeapArray::with_capacity(size);
+        for _i in 0..size {
+            slots.push(UnsafeCell::new(MaybeUninit::uninit()));
+        }
+        let buffer = Arc::new(Self {
+            size,
+            front: AtomicUsize::new(0),
+            rear: AtomicUsize::new(0),
+            slots,
+        });
+        let producer = Producer {
+            buffer: buffer.clone(),
+            rear: 0,
+            front: 0,
+        };
+        let consumer = Consumer {
+            buffer,
+            front: 0,
+            rear: 0,
+        };
+        (producer, consumer)
+    }
+
+    fn slot(&self, counter: usize) -> *mut MaybeUninit<T> {
+        self.slots[counter % self.size].get()
+    }
+
+    // The front is loaded first, so the rear can't be behind it. The front may move on in the meantime though, which
+    // could make the difference larger than the size.
+    fn len(&self) -> usize {
+        let front = self.front.load(Ordering::Acquire);
+        self.rear
+            .load(Ordering::Acquire)
+            .wrapping_sub(front)
+            .min(self.size)
+    }
+}
+
+impl<T> Drop for SpscRingBuffer<T> {
+    fn drop(&mut self) {
+        let front = *self.front.get_mut();
+        let rear = *self.rear.get_mut();
+        let mut counter = front;
+        while counter != rear {
+            unsafe { (*self.slot(counter)).assume_init_drop() };
+            counter = counter.wrapping_add(1);
+        }
+    }
+}
+
+pub struct Producer<T> {
+    buffer: Arc<SpscRingBuffer<T>>,
+    // The Producer is the only writer of the rear, so it keeps its own copy.
+    rear: usize,
+    // Last front seen by the Producer. The front only grows, so the buffer has at least as much space as it shows.
+    front: usize,
+}
+
+impl<T> Producer<T> {
+    // Number of the slots which are free for sure. The shared front is only loaded when the cached one shows no space.
+    fn free_slots(&mut self, needed: usize) -> usize {
+        let mut free = self.buffer.size - self.rear.wrapping_sub(self.front);
+        if free < needed {
+            self.front = self.buffer.front.load(Ordering::Acquire);
+            free = self.buffer.size - self.rear.wrapping_sub(self.front);
+        }
+        free
+    }
+
+    // Hands the item back if the buffer is full.
+    // Time Complexity is O(1).
+    pub fn push(&mut self, item: T) -> Result<(), T> {
+        if self.free_slots(1) == 0 {
+            return Err(item);
+        }
+        unsafe { (*self.buffer.slot(self.rear)).write(item) };
+        self.rear = self.rear.wrapping_add(1);
+        self.buffer.rear.store(self.rear, Ordering::Release);
+        Ok(())
+    }
+
+    // Pushes as many items as fit and publishes them at once. The items which didn't fit stay in the iterator.
+    // Time Complexity is O(k) where k is the number of pushed items.
+    pub fn push_batch<I: Iterator<Item = T>>(&mut self, items: &mut I) -> usize {
+        let free = self.free_slots(self.buffer.size);
+        let mut count = 0;
+        for item in items.take(free) {
+            unsafe { (*self.buffer.slot(self.rear.wrapping_add(count))).write(item) };
+            count += 1;
+        }
+        self.rear = self.rear.wrapping_add(count);
+        self.buffer.rear.store(self.rear, Ordering::Release);
+        count
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.buffer.size
+    }
+
+    // Returns true once the Consumer was dropped, so nobody will pop the items anymore.
+    pub fn is_abandoned(&self) -> bool {
+        Arc::strong_count(&self.buffer) == 1
+    }
+}
+
+pub struct Consumer<T> {
+    buffer: Arc<SpscRingBuffer<T>>,
+    // The Consumer is the only writer of the front, so it keeps its own copy.
+    front: usize,
+    // Last rear seen by the Consumer. The rear only grows, so the buffer has at least as many items as it shows.
+    rear: usize,
+}
+
+impl<T> Consumer<T> {
+    // Number of the items which are ready for sure. The shared rear is only loaded when the cached one shows too few.
+    fn ready_items(&mut self, needed: usize) -> usize {
+        let mut ready = self.rear.wrapping_sub(self.front);
+        if ready < needed {
+            self.rear = self.buffer.rear.load(Ordering::Acquire);
+            ready = self.rear.wrapping_sub(self.front);
+        }
+        ready
+    }
+
+    // Time Complexity is O(1).
+    pub fn pop(&mut self) -> Option<T> {
+        if self.ready_items(1) == 0 {
+            return None;
+        }
+        let item = unsafe { (*self.buffer.slot(self.front)).assume_init_read() };
+        self.front = self.front.wrapping_add(1);
+        self.buffer.front.store(self.front, Ordering::Release);
+        Some(item)
+    }
+
+    // Pops up to max items and frees their slots at once.
+    // Time Complexity is O(k) where k is the number of popped items.
+    pub fn pop_batch(&mut self, max: usize) -> Vec<T> {
+        let count = self.ready_items(max).min(max);
+        let mut items = Vec::with_capacity(count);
+        for i in 0..count {
+            items.push(unsafe {
+                (*self.buffer.slot(self.front.wrapping_add(i))).assume_init_read()
+            });
+        }
+        self.front = self.front.wrapping_add(count);
+        self.buffer.front.store(self.front, Ordering::Release);
+        items
+    }
+
+    pub fn peek(&mut self) -> Option<&T> {
+        if self.ready_items(1) == 0 {
+            return None;
+        }
+        Some(unsafe { (*self.buffer.slot(self.front)).assume_init_ref() })
+    }
+
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.buffer.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.buffer.size
+    }
+
+    // Returns true once the Producer was dropped, so no new items will arrive after the remaining ones.
+    pub fn is_abandoned(&self) -> bool {
+        Arc::strong_count(&self.buffer) == 1
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SendError<T> {
+    Full(T),
+    Timeout(T),
+    Disconnected(T),
+}
+
+impl<T> SendError<T> {
+    // Returns the item which couldn't be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            SendError::Full(item) | SendError::Timeout(item) | SendError::Disconnected(item) => {
+                item
+            }
+        }
+    }
+}
+
+// The item is left out, so the error can be printed for any item type.
+impl<T> Debug for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Full(_) => write!(f, "Full(..)"),
+            SendError::Timeout(_) => write!(f, "Timeout(..)"),
+            SendError::Disconnected(_) => write!(f, "Disconnected(..)"),
+        }
+    }
+}
+
+impl<T> Display for SendError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SendError::Full(_) => write!(f, "Channel is full!"),
+            SendError::Timeout(_) => write!(f, "Channel send timed out!"),
+            SendError::Disconnected(_) => write!(f, "Channel is disconnected!"),
+        }
+    }
+}
+
+impl<T> Error for SendError<T> {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecvError {
+    Empty,
+    Timeout,
+    Disconnected,
+}
+
+impl Display for RecvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RecvError::Empty => write!(f, "Channel is empty!"),
+            RecvError::Timeout => write!(f, "Channel receive timed out!"),
+            RecvError::Disconnected => write!(f, "Channel is disconnected!"),
+        }
+    }
+}
+
+impl Error for RecvError {}
+
+struct ChannelState<T> {
+    queue: CircularQueue<T>,
+    senders: usize,
+    receivers: usize,
+    closed: bool,
+}
+
+impl<T> ChannelState<T> {
+    // Nobody will receive the items anymore.
+    fn is_closed_for_senders(&self) -> bool {
+        self.closed || self.receivers == 0
+    }
+
+    // No new items will arrive after the remaining ones.
+    fn is_closed_for_receivers(&self) -> bool {
+        self.closed || self.senders == 0
+    }
+}
+
+/*
+Bounded Multi Producer Multi Consumer Channel
+The items wait in a Circular Queue behind a Mutex. Senders sleep on not_full while the Queue is full and receivers
+sleep on not_empty while it is empty, so a blocked thread doesn't use the CPU.
+
+The Channel is disconnected when it is closed by either side or when all the Senders or all the Receivers are dropped.
+Senders fail right away, but Receivers still get the items which were sent before.
+*/
+pub struct BoundedChannel<T> {
+    state: Mutex<ChannelState<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> BoundedChannel<T> {
+    // Creates the Channel with the given size and returns its first Sender and Receiver.
+    pub fn split(size: usize) -> (Sender<T>, Receiver<T>) {
+        if size == 0 {
+            panic!("Channel size must be at least 1!");
+        }
+        let channel = Arc::new(Self {
+            state: Mutex::new(ChannelState {
+                queue: CircularQueue::new(size),
+                senders: 1,
+                receivers: 1,
+                closed: false,
+            }),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        });
+        let sender = Sender {
+            channel: channel.clone(),
+        };
+        (sender, Receiver { channel })
+    }
+
+    // The state is never left half changed, so it stays valid when another thread panicked while holding the lock.
+    fn lock(&self) -> MutexGuard<'_, ChannelState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Waits on the condition until it is notified or the deadline passes. Returns None once the deadline passed.
+    fn wait<'a>(
+        &self,
+        condition: &Condvar,
+        state: MutexGuard<'a, ChannelState<T>>,
+        deadline: Option<Instant>,
+    ) -> Option<MutexGuard<'a, ChannelState<T>>> {
+        match deadline {
+            None => Some(
+                condition
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+            ),
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    return None;
+                }
+                let (state, _) = condition
+                    .wait_timeout(state, deadline - now)
+                    .unwrap_or_else(PoisonError::into_inner);
+                Some(state)
+            }
+        }
+    }
+
+    fn send(&self, item: T, deadline: Option<Instant>) -> Result<(), SendError<T>> {
+        let mut state = self.lock();
+        loop {
+            if state.is_closed_for_senders() {
+                return Err(SendError::Disconnected(item));
+            }
+            if !state.queue.is_full() {
+                break;
+            }
+            state = match self.wait(&self.not_full, state, deadline) {
+                Some(state) => state,
+                None => return Err(SendError::Timeout(item)),
+            };
+        }
+        // The Queue was checked above, so the item can't be rejected and lost.
+        if state.queue.enqueue(item).is_err() {
+            unreachable!("Channel queue must have space!");
+        }
+        drop(state);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    fn recv(&self, deadline: Option<Instant>) -> Result<T, RecvError> {
+        let mut state = self.lock();
+        loop {
+            if let Ok(item) = state.queue.dequeue() {
+                drop(state);
+                self.not_full.notify_one();
+                return Ok(item);
+            }
+            if state.is_closed_for_receivers() {
+                return Err(RecvError::Disconnected);
+            }
+            state = match self.wait(&self.not_empty, state, deadline) {
+                Some(state) => state,
+                None => return Err(RecvError::Timeout),
+            };
+        }
+    }
+
+    // Wakes up every blocked thread, so that it can notice the disconnection.
+    fn disconnect(&self, mut state: MutexGuard<'_, ChannelState<T>>, close: bool) {
+        state.closed |= close;
+        drop(state);
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+}
+
+pub struct Sender<T> {
+    channel: Arc<BoundedChannel<T>>,
+}
+
+impl<T> Sender<T> {
+    // Blocks while the Channel is full.
+    pub fn send(&self, item: T) -> Result<(), SendError<T>> {
+        self.channel.send(item, None)
+    }
+
+    pub fn try_send(&self, item: T) -> Result<(), SendError<T>> {
+        let mut state = self.channel.lock();
+        if state.is_closed_for_senders() {
+            return Err(SendError::Disconnected(item));
+        }
+        if state.queue.is_full() {
+            return Err(SendError::Full(item));
+        }
+        if state.queue.enqueue(item).is_err() {
+            unreachable!("Channel queue must have space!");
+        }
+        drop(state);
+        self.channel.not_empty.notify_one();
+        Ok(())
+    }
+
+    // A timeout too large for an Instant waits without a deadline.
+    pub fn send_timeout(&self, item: T, timeout: Duration) -> Result<(), SendError<T>> {
+        self.channel.send(item, Instant::now().checked_add(timeout))
+    }
+
+    // Disconnects every Sender and Receiver of the Channel.
+    pub fn close(&self) {
+        self.channel.disconnect(self.channel.lock(), true);
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        self.channel.lock().is_closed_for_senders()
+    }
+
+    pub fn len(&self) -> usize {
+        self.channel.lock().queue.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.channel.lock().queue.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.channel.lock().queue.is_full()
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.lock().senders += 1;
+        Self {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.channel.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            self.channel.disconnect(state, false);
+        }
+    }
+}
+
+pub struct Receiver<T> {
+    channel: Arc<BoundedChannel<T>>,
+}
+
+impl<T> Receiver<T> {
+    // Blocks while the Channel is empty.
+    pub fn recv(&self) -> Result<T, RecvError> {
+        self.channel.recv(None)
+    }
+
+    pub fn try_recv(&self) -> Result<T, RecvError> {
+        let mut state = self.channel.lock();
+        match state.queue.dequeue() {
+            Ok(item) => {
+                drop(state);
+                self.channel.not_full.notify_one();
+                Ok(item)
+            }
+            Err(_) if state.is_closed_for_receivers() => Err(RecvError::Disconnected),
+            Err(_) => Err(RecvError::Empty),
+        }
+    }
+
+    // A timeout too large for an Instant waits without a deadline.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvError> {
+        self.channel.recv(Instant::now().checked_add(timeout))
+    }
+
+    // Blocks for every item and ends once the Channel is disconnected and empty.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        iter::from_fn(|| self.recv().ok())
+    }
+
+    // Disconnects every Sender and Receiver of the Channel. The items which were already sent can still be received.
+    pub fn close(&self) {
+        self.channel.disconnect(self.channel.lock(), true);
+    }
+
+    pub fn is_disconnected(&self) -> bool {
+        self.channel.lock().is_closed_for_receivers()
+    }
+
+    pub fn len(&self) -> usize {
+        self.channel.lock().queue.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.channel.lock().queue.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.channel.lock().queue.is_full()
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.channel.lock().receivers += 1;
+        Self {
+            channel: self.channel.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.channel.lock();
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            self.channel.disconnect(state, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod spsc_ring_buffer {
+    use crate::structs::channels::SpscRingBuffer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    // Counts how many times it was dropped.
+    struct DropCounter(Arc<AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        let (mut producer, mut consumer) = SpscRingBuffer::split(3);
+        assert_eq!(
+            producer.capacity(),
+            3,
+            "SpscRingBuffer capacity is invalid!"
+        );
+        assert_eq!(consumer.pop(), None, "Empty SpscRingBuffer must not pop!");
+        producer.push(1).expect("Failed to push!");
+        producer.push(2).expect("Failed to push!");
+        producer.push(3).expect("Failed to push!");
+        assert!(producer.is_full(), "SpscRingBuffer must be full!");
+        assert_eq!(
+            producer.push(4),
+            Err(4),
+            "Full SpscRingBuffer must hand the item back!"
+        );
+        assert_eq!(
+            consumer.peek(),
+            Some(&1),
+            "SpscRingBuffer front is invalid!"
+        );
+        assert_eq!(consumer.pop(), Some(1), "SpscRingBuffer must pop in order!");
+        producer.push(4).expect("Failed to push!");
+        assert_eq!(consumer.len(), 3, "SpscRingBuffer length is invalid!");
+        for item in 2..=4 {
+            assert_eq!(
+                consumer.pop(),
+                Some(item),
+                "SpscRingBuffer must pop in order!"
+            );
+        }
+        assert!(consumer.is_empty(), "SpscRingBuffer must be empty!");
+    }
+
+    #[test]
+    fn test_batches() {
+        let (mut producer, mut consumer) = SpscRingBuffer::split(4);
+        let mut items = 0..10;
+        assert_eq!(
+            producer.push_batch(&mut items),
+            4,
+            "Batch must fill the SpscRingBuffer!"
+        );
+        assert_eq!(
+            items.next(),
+            Some(4),
+            "Items which didn't fit must stay in the iterator!"
+        );
+        assert_eq!(
+            consumer.pop_batch(3),
+            vec![0, 1, 2],
+            "Batch must pop in order!"
+        );
+        assert_eq!(
+            producer.push_batch(&mut items),
+            3,
+            "Batch must wrap around!"
+        );
+        assert_eq!(
+            consumer.pop_batch(10),
+            vec![3, 5, 6, 7],
+            "Batch must pop the ready items!"
+        );
+        assert_eq!(
+            consumer.pop_batch(10),
+            Vec::<i32>::new(),
+            "Empty SpscRingBuffer must pop nothing!"
+        );
+    }
+
+    #[test]
+    fn test_zero_size() {
+        let (mut producer, mut consumer) = SpscRingBuffer::split(0);
+        assert_eq!(
+            producer.push(1),
+            Err(1),
+            "Zero sized SpscRingBuffer must be full!"
+        );
+        assert_eq!(
+            producer.push_batch(&mut (0..3)),
+            0,
+            "Zero sized SpscRingBuffer must be full!"
+        );
+        assert_eq!(
+            consumer.pop(),
+            None,
+            "Zero sized SpscRingBuffer must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_abandoned() {
+        let (producer, mut consumer) = SpscRingBuffer::<u8>::split(2);
+        assert!(!consumer.is_abandoned(), "Producer is still alive!");
+        drop(producer);
+        assert!(consumer.is_abandoned(), "Producer was dropped!");
+        assert_eq!(
+            consumer.pop(),
+            None,
+            "Abandoned SpscRingBuffer must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_drop_remaining_items() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let (mut producer, mut consumer) = SpscRingBuffer::split(4);
+        for _i in 0..4 {
+            assert!(
+                producer.push(DropCounter(drops.clone())).is_ok(),
+                "Failed to push!"
+            );
+        }
+        drop(consumer.pop());
+        drop(consumer.pop());
+        assert!(
+            producer.push(DropCounter(drops.clone())).is_ok(),
+            "Failed to push!"
+        );
+        assert_eq!(
+            drops.load(Ordering::SeqCst),
+            2,
+            "Popped items must be dropped once!"
+        );
+        drop(producer);
+        drop(consumer);
+        assert_eq!(
+            drops.load(Ordering::SeqCst),
+            5,
+            "Remaining items must be dropped with the buffer!"
+        );
+    }
+
+    // Every pair of threads sends the numbers in order through its own buffer while the pairs compete for the CPU.
+    #[test]
+    fn test_stress() {
+        const ITEMS: usize = 50_000;
+        let mut handles = Vec::new();
+        for size in [1, 2, 3, 16, 64, 1000] {
+            let (mut producer, mut consumer) = SpscRingBuffer::split(size);
+            handles.push(thread::spawn(move || {
+                for item in 0..ITEMS {
+                    let mut item = item;
+                    while let Err(rejected) = producer.push(item) {
+                        item = rejected;
+                        thread::yield_now();
+                    }
+                }
+            }));
+            handles.push(thread::spawn(move || {
+                let mut expected = 0;
+                while expected < ITEMS {
+                    match consumer.pop() {
+                        Some(item) => {
+                            assert_eq!(item, expected, "SpscRingBuffer must keep the order!");
+                            expected += 1;
+                        }
+                        None => thread::yield_now(),
+                    }
+                }
+                assert_eq!(
+                    consumer.pop(),
+                    None,
+                    "SpscRingBuffer must not pop extra items!"
+                );
+            }));
+        }
+        for handle in handles {
+            handle.join().expect("Thread failed!");
+        }
+    }
+
+    #[test]
+    fn test_stress_batches() {
+        const ITEMS: usize = 50_000;
+        let mut handles = Vec::new();
+        for size in [1, 5, 32, 256] {
+            let (mut producer, mut consumer) = SpscRingBuffer::split(size);
+            handles.push(thread::spawn(move || {
+                let mut items = (0..ITEMS).map(|item| item.to_string()).peekable();
+                while items.peek().is_some() {
+                    if producer.push_batch(&mut items) == 0 {
+                        thread::yield_now();
+                    }
+                }
+            }));
+            handles.push(thread::spawn(move || {
+                let mut expected = 0;
+                while expected < ITEMS {
+                    let items = consumer.pop_batch(7);
+                    if items.is_empty() {
+                        thread::yield_now();
+                    }
+                    for item in items {
+                        assert_eq!(
+                            item,
+                            expected.to_string(),
+                            "SpscRingBuffer must keep the order!"
+                        );
+                        expected += 1;
+                    }
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().expect("Thread failed!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod bounded_channel {
+    use crate::structs::channels::{BoundedChannel, RecvError, SendError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    #[should_panic(expected = "Channel size must be at least 1!")]
+    fn test_split_panic() {
+        let _channel = BoundedChannel::<u8>::split(0);
+    }
+
+    #[test]
+    fn test_try_send_and_recv() {
+        let (sender, receiver) = BoundedChannel::split(2);
+        assert_eq!(
+            receiver.try_recv(),
+            Err(RecvError::Empty),
+            "Channel must be empty!"
+        );
+        sender.try_send(1).expect("Failed to send!");
+        sender.try_send(2).expect("Failed to send!");
+        assert!(sender.is_full(), "Channel must be full!");
+        assert_eq!(
+            sender.try_send(3),
+            Err(SendError::Full(3)),
+            "Full Channel must hand the item back!"
+        );
+        assert_eq!(receiver.len(), 2, "Channel length is invalid!");
+        assert_eq!(receiver.capacity(), 2, "Channel capacity is invalid!");
+        assert_eq!(receiver.try_recv(), Ok(1), "Channel must receive in order!");
+        assert_eq!(receiver.recv(), Ok(2), "Channel must receive in order!");
+        assert!(receiver.is_empty(), "Channel must be empty!");
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let (sender, receiver) = BoundedChannel::split(1);
+        let start = Instant::now();
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(30)),
+            Err(RecvError::Timeout),
+            "Empty Channel must time out!"
+        );
+        assert!(
+            start.elapsed() >= Duration::from_millis(30),
+            "Channel must wait for the timeout!"
+        );
+        sender
+            .send_timeout('a', Duration::from_millis(30))
+            .expect("Failed to send!");
+        let result = sender.send_timeout('b', Duration::from_millis(30));
+        assert_eq!(
+            result,
+            Err(SendError::Timeout('b')),
+            "Full Channel must time out!"
+        );
+        assert_eq!(
+            result.unwrap_err().into_inner(),
+            'b',
+            "Timed out item must be handed back!"
+        );
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_millis(30)),
+            Ok('a'),
+            "Channel must receive in time!"
+        );
+    }
+
+    #[test]
+    fn test_unbounded_timeouts() {
+        let (sender, receiver) = BoundedChannel::split(1);
+        assert_eq!(
+            sender.send_timeout('a', Duration::MAX),
+            Ok(()),
+            "Channel must send without a deadline!"
+        );
+        assert_eq!(
+            receiver.recv_timeout(Duration::MAX),
+            Ok('a'),
+            "Channel must receive without a deadline!"
+        );
+    }
+
+    #[test]
+    fn test_blocking() {
+        let (sender, receiver) = BoundedChannel::split(1);
+        let handle = thread::spawn(move || {
+            for item in 0..100 {
+                sender.send(item).expect("Failed to send!");
+            }
+        });
+        // The Sender blocks on the full Channel until the items are received one by one.
+        let received: Vec<i32> = receiver.iter().collect();
+        assert_eq!(
+            received,
+            (0..100).collect::<Vec<_>>(),
+            "Channel must receive every item in order!"
+        );
+        handle.join().expect("Thread failed!");
+        assert_eq!(
+            receiver.recv(),
+            Err(RecvError::Disconnected),
+            "Dropped Sender must disconnect!"
+        );
+    }
+
+    #[test]
+    fn test_close_by_sender() {
+        let (sender, receiver) = BoundedChannel::split(3);
+        sender.send(1).expect("Failed to send!");
+        sender.send(2).expect("Failed to send!");
+        sender.close();
+        assert!(
+            sender.is_disconnected(),
+            "Closed Channel must be disconnected!"
+        );
+        assert_eq!(
+            sender.send(3),
+            Err(SendError::Disconnected(3)),
+            "Closed Channel must not send!"
+        );
+        assert_eq!(receiver.recv(), Ok(1), "Sent items must still be received!");
+        assert_eq!(
+            receiver.try_recv(),
+            Ok(2),
+            "Sent items must still be received!"
+        );
+        assert_eq!(
+            receiver.recv(),
+            Err(RecvError::Disconnected),
+            "Closed Channel must disconnect!"
+        );
+        assert_eq!(
+            receiver.try_recv(),
+            Err(RecvError::Disconnected),
+            "Closed Channel must disconnect!"
+        );
+    }
+
+    #[test]
+    fn test_close_by_receiver() {
+        let (sender, receiver) = BoundedChannel::split(1);
+        sender.send(1).expect("Failed to send!");
+        let blocked = sender.clone();
+        let handle = thread::spawn(move || blocked.send(2));
+        thread::sleep(Duration::from_millis(20));
+        receiver.close();
+        // The blocked Sender must wake up instead of waiting for space forever.
+        assert_eq!(
+            handle.join().expect("Thread failed!"),
+            Err(SendError::Disconnected(2)),
+            "Sender must wake up!"
+        );
+        assert_eq!(
+            sender.try_send(3),
+            Err(SendError::Disconnected(3)),
+            "Closed Channel must not send!"
+        );
+        assert_eq!(receiver.recv(), Ok(1), "Sent items must still be received!");
+    }
+
+    #[test]
+    fn test_drop_receivers() {
+        let (sender, receiver) = BoundedChannel::split(2);
+        let other = receiver.clone();
+        drop(receiver);
+        sender.send(1).expect("Other Receiver is still alive!");
+        drop(other);
+        assert!(
+            sender.is_disconnected(),
+            "Channel without Receivers must be disconnected!"
+        );
+        assert_eq!(
+            sender.send(2),
+            Err(SendError::Disconnected(2)),
+            "Channel without Receivers must not send!"
+        );
+        assert_eq!(
+            format!("{}", SendError::Disconnected(2)),
+            "Channel is disconnected!",
+            "Error is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_drop_senders() {
+        let (sender, receiver) = BoundedChannel::split(2);
+        let other = sender.clone();
+        sender.send(1).expect("Failed to send!");
+        drop(sender);
+        assert!(!receiver.is_disconnected(), "Other Sender is still alive!");
+        other.send(2).expect("Failed to send!");
+        drop(other);
+        assert_eq!(
+            receiver.iter().collect::<Vec<_>>(),
+            vec![1, 2],
+            "Remaining items must be received!"
+        );
+        assert_eq!(
+            receiver.recv(),
+            Err(RecvError::Disconnected),
+            "Channel without Senders must disconnect!"
+        );
+    }
+
+    #[test]
+    fn test_multiple_producers_and_consumers() {
+        const PRODUCERS: usize = 4;
+        const CONSUMERS: usize = 4;
+        const ITEMS: usize = 5_000;
+        let (sender, receiver) = BoundedChannel::split(8);
+        let sum = Arc::new(AtomicUsize::new(0));
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut handles = Vec::new();
+        for producer in 0..PRODUCERS {
+            let sender = sender.clone();
+            handles.push(thread::spawn(move || {
+                for item in 0..ITEMS {
+                    sender
+                        .send(producer * ITEMS + item)
+                        .expect("Failed to send!");
+                }
+            }));
+        }
+        for _i in 0..CONSUMERS {
+            let receiver = receiver.clone();
+            let sum = sum.clone();
+            let count = count.clone();
+            handles.push(thread::spawn(move || {
+                // Items of a single producer must arrive in the order they were sent.
+                let mut last = [None; PRODUCERS];
+                for item in receiver.iter() {
+                    let producer = item / ITEMS;
+                    assert!(
+                        last[producer].is_none_or(|last| last < item),
+                        "Channel must keep the order!"
+                    );
+                    last[producer] = Some(item);
+                    sum.fetch_add(item, Ordering::SeqCst);
+                    count.fetch_add(1, Ordering::SeqCst);
+                }
+            }));
+        }
+        drop(sender);
+        drop(receiver);
+        for handle in handles {
+            handle.join().expect("Thread failed!");
+        }
+        let total = PRODUCERS * ITEMS;
+        assert_eq!(
+            count.load(Ordering::SeqCst),
+            total,
+            "Every item must be received once!"
+        );
+        assert_eq!(
+            sum.load(Ordering::SeqCst),
+            total * (total - 1) / 2,
+            "Every item must be received once!"
+        );
+    }
+}
@@ -1,7 +1,107 @@
 use crate::structs::arrays::HeapArray;
-use crate::structs::stacks::Stack;
+use crate::structs::stacks::{Stack, StackADT};
 use crate::structs::strings::HeapString;
 use crate::structs::tokens::{Associativity, Punctuation, Token};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq)]
+pub enum DelimiterError {
+    // A closing delimiter that doesn't close the last opened one. Nothing is expected when no delimiter is open.
+    Mismatched {
+        offset: usize,
+        found: char,
+        expected: Option<char>,
+    },
+    // A delimiter that is never closed. The offset points at the opening delimiter.
+    Unclosed {
+        offset: usize,
+        expected: char,
+    },
+}
+
+impl Display for DelimiterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DelimiterError::Mismatched {
+                offset,
+                found,
+                expected: Some(expected),
+            } => write!(
+                f,
+                "Unexpected '{}' at byte {}, expected '{}'!",
+                found, offset, expected
+            ),
+            DelimiterError::Mismatched {
+                offset,
+                found,
+                expected: None,
+            } => write!(
+                f,
+                "Unexpected '{}' at byte {}, nothing to close!",
+                found, offset
+            ),
+            DelimiterError::Unclosed { offset, expected } => {
+                write!(
+                    f,
+                    "Missing '{}' for the delimiter at byte {}!",
+                    expected, offset
+                )
+            }
+        }
+    }
+}
+
+impl Error for DelimiterError {}
+
+/*
+Checks that the (), [], {} and <> pairs are balanced and properly nested and that the quotes are closed.
+Everything between quotes is taken literally and a backslash escapes the next character inside of them.
+Time Complexity is O(n)
+*/
+pub fn validate_delimiters(input: &str) -> Result<(), DelimiterError> {
+    let mut stack: Stack<(Punctuation, usize)> = Stack::new(input.len());
+    let mut quote: Option<(Punctuation, usize)> = None;
+    let mut escaped = false;
+    for (offset, c) in input.char_indices() {
+        if let Some((open, _)) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if Punctuation::from(c) == open {
+                quote = None;
+            }
+            continue;
+        }
+        match Token::from(c) {
+            Token::Punctuation(punc) if punc.is_quote() => quote = Some((punc, offset)),
+            Token::Punctuation(punc) if punc.is_opening() => stack.push((punc, offset)),
+            Token::Punctuation(punc) if punc.is_closing() => match stack.pop() {
+                Some((open, _)) if open.closing() == Some(punc) => {}
+                open => {
+                    return Err(DelimiterError::Mismatched {
+                        offset,
+                        found: c,
+                        expected: open
+                            .and_then(|(open, _)| Token::Punctuation(open.closing()?).to_char()),
+                    })
+                }
+            },
+            _ => {}
+        }
+    }
+    if let Some((open, offset)) = quote.or(stack.pop()) {
+        return Err(DelimiterError::Unclosed {
+            offset,
+            expected: Token::Punctuation(open.closing().unwrap())
+                .to_char()
+                .unwrap(),
+        });
+    }
+    Ok(())
+}
 
 struct Expression {
     prefix: Option<HeapString>,
@@ -19,21 +119,26 @@ impl Expression {
     }
 
     fn infix_to_postfix(&mut self) {
-        let mut stack: Stack<Token> = Stack::new(self.infix.len());
+        self.infix_to_postfix_with(Stack::new(self.infix.len()));
+    }
+
+    // Any Stack implementation can be used for the conversion.
+    fn infix_to_postfix_with<S: StackADT<Token>>(&mut self, mut stack: S) {
+        stack.clear();
         let tokens: HeapArray<Token> = Token::tokenize(&self.infix);
         let mut postfix: HeapString = HeapString::with_capacity(self.infix.len());
         for &token in &tokens {
             match token {
-                Token::Punctuation(punc) => match punc {
-                    Punctuation::LeftParen => stack.push(token),
-                    Punctuation::RightParen => {
-                        while *stack.peek().unwrap() != Token::Punctuation(Punctuation::LeftParen) {
-                            postfix.push(stack.pop().unwrap().to_char().unwrap());
+                Token::Punctuation(punc) if punc.is_opening() => stack.push(token),
+                Token::Punctuation(punc) if punc.is_closing() => {
+                    while let Some(&top) = stack.peek() {
+                        match top {
+                            Token::Punctuation(open) if open.is_opening() => break,
+                            _ => postfix.push(stack.pop().unwrap().to_char().unwrap()),
                         }
-                        stack.pop();
                     }
-                    _ => {}
-                },
+                    stack.pop();
+                }
                 (Token::Letter(_)) => postfix.push(token.to_char().unwrap()),
                 (Token::Digit(_)) => postfix.push(token.to_char().unwrap()),
                 Token::Whitespace => postfix.push(token.to_char().unwrap()),
@@ -78,19 +183,46 @@ impl Expression {
         self.prefix.as_ref().unwrap().as_str()
     }
 
-    fn get_postfix(&mut self) -> &str {
+    fn validate(&self) -> Result<(), DelimiterError> {
+        validate_delimiters(self.infix.as_str())
+    }
+
+    fn try_get_postfix(&mut self) -> Result<&str, DelimiterError> {
         if self.postfix.is_none() {
+            self.validate()?;
             self.infix_to_postfix()
         }
-        self.postfix.as_ref().unwrap().as_str()
+        Ok(self.postfix.as_ref().unwrap().as_str())
     }
 
+    // Panics with the delimiter error when the expression is malformed.
+    fn get_postfix(&mut self) -> &str {
+        match self.try_get_postfix() {
+            Ok(postfix) => postfix,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    pub fn try_evaluate(&mut self) -> Result<u32, DelimiterError> {
+        let size = self.try_get_postfix()?.len();
+        self.evaluate_with(Stack::new(size))
+    }
+
+    // Panics with the delimiter error when the expression is malformed.
     pub fn evaluate(&mut self) -> u32 {
-        if self.postfix.is_none() {
-            self.infix_to_postfix();
-            println!("{}", self.postfix.as_ref().unwrap());
+        match self.try_evaluate() {
+            Ok(value) => value,
+            Err(e) => panic!("{}", e),
         }
-        let mut stack: Stack<Token> = Stack::new(self.postfix.as_ref().unwrap().len());
+    }
+
+    // Any Stack implementation can be used for the evaluation. Malformed delimiters are reported before evaluating.
+    pub fn evaluate_with<S: StackADT<Token>>(
+        &mut self,
+        mut stack: S,
+    ) -> Result<u32, DelimiterError> {
+        self.try_get_postfix()?;
+        stack.clear();
         let tokens: HeapArray<Token> = Token::tokenize(self.postfix.as_ref().unwrap());
         let out: u32 = 0;
         for &token in &tokens {
@@ -105,16 +237,17 @@ impl Expression {
                 _ => {}
             }
         }
-        match stack.pop() {
+        Ok(match stack.pop() {
             Some(Token::Number(n)) => n,
             _ => 0,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod expression {
-    use crate::structs::expressions::Expression;
+    use crate::structs::expressions::{DelimiterError, Expression};
+    use crate::structs::stacks::DynamicStack;
     use crate::structs::strings::HeapString;
 
     #[test]
@@ -209,6 +342,85 @@ mod expression {
         );
     }
 
+    #[test]
+    fn test_with_dynamic_stack() {
+        let mut exp = Expression::new("(a+b)*c-d^e^f");
+        exp.infix_to_postfix_with(DynamicStack::new());
+        assert_eq!(
+            exp.postfix.as_ref().unwrap().as_str(),
+            "ab+c*def^^-",
+            "Postfix representation using a Dynamic Stack is invalid!"
+        );
+        assert_eq!(
+            Expression::new("3*5+6/2-4").evaluate_with(DynamicStack::new()),
+            Ok(14),
+            "Expression evaluation using a Dynamic Stack is invalid!"
+        );
+        assert_eq!(
+            Expression::new("3*[5+6)").evaluate_with(DynamicStack::new()),
+            Err(DelimiterError::Mismatched {
+                offset: 6,
+                found: ')',
+                expected: Some(']')
+            }),
+            "Mismatched delimiters must be reported when evaluating with a Dynamic Stack!"
+        );
+    }
+
+    #[test]
+    fn test_get_postfix_with_brackets() {
+        assert_eq!(
+            Expression::new("[a+b]*{c-d}").get_postfix(),
+            "ab+cd-*".to_string(),
+            "Postfix representation is invalid for the '[a+b]*{{c-d}}' expression!"
+        );
+    }
+
+    #[test]
+    fn test_try_get_postfix() {
+        assert_eq!(
+            Expression::new("(a+b]*c").try_get_postfix(),
+            Err(DelimiterError::Mismatched {
+                offset: 4,
+                found: ']',
+                expected: Some(')')
+            }),
+            "Mismatched delimiters must be reported!"
+        );
+        assert_eq!(
+            Expression::new("a+b)").try_get_postfix(),
+            Err(DelimiterError::Mismatched {
+                offset: 3,
+                found: ')',
+                expected: None
+            }),
+            "Closing delimiter without an opening one must be reported!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing ')' for the delimiter at byte 0!")]
+    fn test_get_postfix_panic() {
+        Expression::new("(a+b").get_postfix();
+    }
+
+    #[test]
+    fn test_try_evaluate() {
+        assert_eq!(
+            Expression::new("(3*5)+6/2-4").try_evaluate(),
+            Ok(14),
+            "Expression evaluation for '(3*5)+6/2-4' is invalid!"
+        );
+        assert_eq!(
+            Expression::new("((3*5)+6").try_evaluate(),
+            Err(DelimiterError::Unclosed {
+                offset: 0,
+                expected: ')'
+            }),
+            "Unclosed delimiter must be reported!"
+        );
+    }
+
     #[test]
     fn test_evaluate() {
         assert_eq!(
@@ -218,3 +430,120 @@ mod expression {
         );
     }
 }
+
+#[cfg(test)]
+mod validate_delimiters {
+    use crate::structs::expressions::{validate_delimiters, DelimiterError};
+
+    #[test]
+    fn test_balanced() {
+        for input in [
+            "",
+            "a + b",
+            "()[]{}<>",
+            "{[(<a>)]}",
+            "f(x[1], {y: <T>})",
+            "'(' + \"]\"",
+            "\"a \\\" [\"",
+            "é(ü)",
+        ] {
+            assert_eq!(
+                validate_delimiters(input),
+                Ok(()),
+                "'{}' must be balanced!",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_mismatched() {
+        assert_eq!(
+            validate_delimiters("{[}]"),
+            Err(DelimiterError::Mismatched {
+                offset: 2,
+                found: '}',
+                expected: Some(']')
+            }),
+            "Crossed delimiters must be reported!"
+        );
+        assert_eq!(
+            validate_delimiters("<a>>"),
+            Err(DelimiterError::Mismatched {
+                offset: 3,
+                found: '>',
+                expected: None
+            }),
+            "Extra closing delimiter must be reported!"
+        );
+    }
+
+    #[test]
+    fn test_byte_offset() {
+        // 'é' takes two bytes, so the bracket is at byte 3 even though it's the third character.
+        assert_eq!(
+            validate_delimiters("(é]"),
+            Err(DelimiterError::Mismatched {
+                offset: 3,
+                found: ']',
+                expected: Some(')')
+            }),
+            "Offset must be counted in bytes!"
+        );
+    }
+
+    #[test]
+    fn test_unclosed() {
+        assert_eq!(
+            validate_delimiters("[a, (b)"),
+            Err(DelimiterError::Unclosed {
+                offset: 0,
+                expected: ']'
+            }),
+            "Unclosed delimiter must be reported!"
+        );
+        assert_eq!(
+            validate_delimiters("(\"abc)"),
+            Err(DelimiterError::Unclosed {
+                offset: 1,
+                expected: '"'
+            }),
+            "Unclosed quote must be reported before the other delimiters!"
+        );
+        assert_eq!(
+            validate_delimiters("'it\\'s"),
+            Err(DelimiterError::Unclosed {
+                offset: 0,
+                expected: '\''
+            }),
+            "Escaped quote must not close the quote!"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!(
+                "{}",
+                DelimiterError::Mismatched {
+                    offset: 2,
+                    found: '}',
+                    expected: Some(']')
+                }
+            ),
+            "Unexpected '}' at byte 2, expected ']'!",
+            "Delimiter error message is invalid!"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                DelimiterError::Unclosed {
+                    offset: 0,
+                    expected: ')'
+                }
+            ),
+            "Missing ')' for the delimiter at byte 0!",
+            "Delimiter error message is invalid!"
+        );
+    }
+}
@@ -0,0 +1,664 @@
+/*
+Undo and Redo History
+Every executed command is pushed on the undo Stack. Undoing a command moves it to the redo Stack and redoing it moves
+it back. Executing a new command forgets everything that could have been redone.
+
+- The undo Stack overwrites the oldest entry once the limit is reached, so the history is bounded.
+- Consecutive commands can be merged into one entry, e.g. typed characters are undone as a single word.
+- Commands executed in a transaction are grouped into a single entry and undone or redone together.
+- A checkpoint remembers the current state of the history, e.g. when a document is saved, and the history can be
+  reverted back to it.
+
+Every entry is itself a Stack of commands. Undoing an entry pops the commands in reverse order and pushes them into a
+new Stack, so the first command ends up on the top and redoing pops them in the original order.
+*/
+#![allow(dead_code)]
+use crate::structs::stacks::{OverflowPolicy, Stack, StackADT};
+use crate::structs::strings::HeapString;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+pub trait Command {
+    type Target;
+
+    fn apply(&mut self, target: &mut Self::Target);
+
+    fn undo(&mut self, target: &mut Self::Target);
+
+    // Tries to absorb the next command into this one. The command is handed back when the two can't be merged.
+    fn merge(&mut self, other: Self) -> Result<(), Self>
+    where
+        Self: Sized,
+    {
+        Err(other)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HistoryError {
+    NothingToUndo,
+    NothingToRedo,
+    TransactionInProgress,
+    NoTransaction,
+    UnknownCheckpoint,
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::NothingToUndo => write!(f, "Nothing to undo!"),
+            HistoryError::NothingToRedo => write!(f, "Nothing to redo!"),
+            HistoryError::TransactionInProgress => write!(f, "Transaction is still in progress!"),
+            HistoryError::NoTransaction => write!(f, "No transaction is in progress!"),
+            HistoryError::UnknownCheckpoint => write!(f, "Checkpoint is no longer in the history!"),
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+// Identifies the state of the history. The id is the id of the entry on the top of the undo Stack.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Checkpoint {
+    id: usize,
+}
+
+struct Entry<C> {
+    id: usize,
+    commands: Stack<C>,
+    // Grouped and checkpointed entries must not change anymore.
+    mergeable: bool,
+}
+
+impl<C> Entry<C> {
+    fn new(id: usize, mergeable: bool) -> Self {
+        Self {
+            id,
+            commands: Stack::with_policy(1, OverflowPolicy::Grow),
+            mergeable,
+        }
+    }
+}
+
+pub struct History<C: Command> {
+    undo_stack: Stack<Entry<C>>,
+    redo_stack: Stack<Entry<C>>,
+    transaction: Option<Entry<C>>,
+    limit: usize,
+    next_id: usize,
+    // Id of the state below the oldest entry. It moves up when the oldest entry is dropped.
+    base_id: usize,
+}
+
+impl<C: Command> History<C> {
+    pub fn new(limit: usize) -> Self {
+        if limit == 0 {
+            panic!("History limit must be at least 1!");
+        }
+        Self {
+            undo_stack: Stack::with_policy(limit, OverflowPolicy::OverwriteOldest),
+            // Entries only reach the redo Stack from the undo Stack, so it can never hold more than the limit.
+            redo_stack: Stack::new(limit),
+            transaction: None,
+            limit,
+            next_id: 1,
+            base_id: 0,
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    pub fn redo_len(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.transaction.is_none() && !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.transaction.is_none() && !self.redo_stack.is_empty()
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+
+    fn new_entry(&mut self, mergeable: bool) -> Entry<C> {
+        let entry = Entry::new(self.next_id, mergeable);
+        self.next_id += 1;
+        entry
+    }
+
+    fn push_undo(&mut self, entry: Entry<C>) {
+        // The oldest entry is dropped when the limit is reached.
+        if self.undo_stack.is_full() {
+            self.base_id = self.undo_stack.peek_at(self.limit - 1).unwrap().id;
+        }
+        self.undo_stack
+            .try_push(entry)
+            .expect("History undo stack can't overflow!");
+    }
+
+    // Applies the command and records it. The command is merged into the last entry when possible.
+    pub fn execute(&mut self, mut command: C, target: &mut C::Target) {
+        command.apply(target);
+        self.redo_stack.clear();
+
+        if let Some(transaction) = self.transaction.as_mut() {
+            transaction.commands.push(command);
+            return;
+        }
+        if let Some(entry) = self.undo_stack.top_mut() {
+            if entry.mergeable {
+                let last = entry.commands.top_mut().unwrap();
+                command = match last.merge(command) {
+                    Ok(()) => return,
+                    Err(command) => command,
+                };
+            }
+        }
+        let mut entry = self.new_entry(true);
+        entry.commands.push(command);
+        self.push_undo(entry);
+    }
+
+    // Stops the next command from being merged into the last entry.
+    pub fn seal(&mut self) {
+        if let Some(entry) = self.undo_stack.top_mut() {
+            entry.mergeable = false;
+        }
+    }
+
+    // Time Complexity is O(k) where k is the number of commands in the entry.
+    pub fn undo(&mut self, target: &mut C::Target) -> Result<(), HistoryError> {
+        if self.transaction.is_some() {
+            return Err(HistoryError::TransactionInProgress);
+        }
+        let mut entry = self.undo_stack.pop().ok_or(HistoryError::NothingToUndo)?;
+        let mut undone = Entry::new(entry.id, false);
+        while let Some(mut command) = entry.commands.pop() {
+            command.undo(target);
+            undone.commands.push(command);
+        }
+        self.redo_stack.push(undone);
+        Ok(())
+    }
+
+    // Time Complexity is O(k) where k is the number of commands in the entry.
+    pub fn redo(&mut self, target: &mut C::Target) -> Result<(), HistoryError> {
+        if self.transaction.is_some() {
+            return Err(HistoryError::TransactionInProgress);
+        }
+        let mut entry = self.redo_stack.pop().ok_or(HistoryError::NothingToRedo)?;
+        let mut redone = Entry::new(entry.id, false);
+        while let Some(mut command) = entry.commands.pop() {
+            command.apply(target);
+            redone.commands.push(command);
+        }
+        self.push_undo(redone);
+        Ok(())
+    }
+
+    // Groups all the following commands into a single entry until the transaction is committed.
+    pub fn begin_transaction(&mut self) -> Result<(), HistoryError> {
+        if self.transaction.is_some() {
+            return Err(HistoryError::TransactionInProgress);
+        }
+        self.transaction = Some(self.new_entry(false));
+        Ok(())
+    }
+
+    pub fn commit_transaction(&mut self) -> Result<(), HistoryError> {
+        let transaction = self.transaction.take().ok_or(HistoryError::NoTransaction)?;
+        if !transaction.commands.is_empty() {
+            self.push_undo(transaction);
+        }
+        Ok(())
+    }
+
+    // Undoes every command of the transaction and forgets them.
+    pub fn rollback_transaction(&mut self, target: &mut C::Target) -> Result<(), HistoryError> {
+        let mut transaction = self.transaction.take().ok_or(HistoryError::NoTransaction)?;
+        while let Some(mut command) = transaction.commands.pop() {
+            command.undo(target);
+        }
+        Ok(())
+    }
+
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.seal();
+        Checkpoint {
+            id: self.current_id(),
+        }
+    }
+
+    fn current_id(&self) -> usize {
+        self.undo_stack
+            .peek()
+            .map_or(self.base_id, |entry| entry.id)
+    }
+
+    pub fn is_at_checkpoint(&self, checkpoint: &Checkpoint) -> bool {
+        self.transaction.is_none() && self.current_id() == checkpoint.id
+    }
+
+    // Undoes or redoes entries until the history is back at the checkpoint.
+    // Time Complexity is O(n) where n is the number of commands between the current state and the checkpoint.
+    pub fn revert_to(
+        &mut self,
+        checkpoint: &Checkpoint,
+        target: &mut C::Target,
+    ) -> Result<(), HistoryError> {
+        if self.transaction.is_some() {
+            return Err(HistoryError::TransactionInProgress);
+        }
+        let in_undo = self
+            .undo_stack
+            .iter()
+            .any(|entry| entry.id == checkpoint.id);
+        let in_redo = self
+            .redo_stack
+            .iter()
+            .any(|entry| entry.id == checkpoint.id);
+        let at_base = checkpoint.id == self.base_id;
+        if !(in_undo || in_redo || at_base) {
+            return Err(HistoryError::UnknownCheckpoint);
+        }
+        while !self.is_at_checkpoint(checkpoint) {
+            match in_redo {
+                true => self.redo(target)?,
+                false => self.undo(target)?,
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.transaction = None;
+        // The target keeps its state, which is not the state of any earlier checkpoint.
+        self.base_id = self.next_id;
+        self.next_id += 1;
+    }
+}
+
+/*
+Text edits on a HeapString. The indices are byte offsets and have to be on character boundaries.
+Typing merges consecutive inserts and pressing backspace or delete merges consecutive deletions.
+*/
+pub enum TextEdit {
+    Insert {
+        index: usize,
+        text: HeapString,
+    },
+    Delete {
+        index: usize,
+        len: usize,
+        removed: HeapString,
+    },
+}
+
+impl TextEdit {
+    pub fn insert(index: usize, text: &str) -> Self {
+        TextEdit::Insert {
+            index,
+            text: HeapString::from(text),
+        }
+    }
+
+    pub fn delete(index: usize, len: usize) -> Self {
+        TextEdit::Delete {
+            index,
+            len,
+            removed: HeapString::new(),
+        }
+    }
+}
+
+impl Command for TextEdit {
+    type Target = HeapString;
+
+    fn apply(&mut self, target: &mut HeapString) {
+        match self {
+            TextEdit::Insert { index, text } => target.insert_str(*index, text.as_str()),
+            TextEdit::Delete {
+                index,
+                len,
+                removed,
+            } => *removed = target.remove_range(*index, *len),
+        }
+    }
+
+    fn undo(&mut self, target: &mut HeapString) {
+        match self {
+            TextEdit::Insert { index, text } => {
+                target.remove_range(*index, text.len());
+            }
+            TextEdit::Delete { index, removed, .. } => target.insert_str(*index, removed.as_str()),
+        }
+    }
+
+    fn merge(&mut self, other: Self) -> Result<(), Self> {
+        match (self, other) {
+            // Typing right after the previous insert.
+            (
+                TextEdit::Insert { index, text },
+                TextEdit::Insert {
+                    index: next,
+                    text: next_text,
+                },
+            ) if next == *index + text.len() => {
+                text.insert_str(text.len(), next_text.as_str());
+                Ok(())
+            }
+            // Backspace right before the previous deletion.
+            (
+                TextEdit::Delete {
+                    index,
+                    len,
+                    removed,
+                },
+                TextEdit::Delete {
+                    index: next,
+                    len: next_len,
+                    removed: next_removed,
+                },
+            ) if next + next_len == *index => {
+                removed.insert_str(0, next_removed.as_str());
+                *index = next;
+                *len += next_len;
+                Ok(())
+            }
+            // Delete key at the same position as the previous deletion.
+            (
+                TextEdit::Delete {
+                    index,
+                    len,
+                    removed,
+                },
+                TextEdit::Delete {
+                    index: next,
+                    len: next_len,
+                    removed: next_removed,
+                },
+            ) if next == *index => {
+                removed.insert_str(removed.len(), next_removed.as_str());
+                *len += next_len;
+                Ok(())
+            }
+            (_, other) => Err(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod command_history {
+    use crate::structs::history::{Command, History, HistoryError, TextEdit};
+    use crate::structs::strings::HeapString;
+
+    fn type_text(history: &mut History<TextEdit>, text: &mut HeapString, typed: &str) {
+        for c in typed.chars() {
+            let mut buffer = [0; 4];
+            history.execute(
+                TextEdit::insert(text.len(), c.encode_utf8(&mut buffer)),
+                text,
+            );
+        }
+    }
+
+    // Counts how many times the command was applied and undone.
+    struct Counter;
+
+    impl Command for Counter {
+        type Target = i32;
+
+        fn apply(&mut self, target: &mut i32) {
+            *target += 1;
+        }
+
+        fn undo(&mut self, target: &mut i32) {
+            *target -= 1;
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let history: History<TextEdit> = History::new(10);
+        assert_eq!(history.limit(), 10, "History limit is invalid!");
+        assert!(!history.can_undo(), "New History must not undo!");
+        assert!(!history.can_redo(), "New History must not redo!");
+    }
+
+    #[test]
+    #[should_panic(expected = "History limit must be at least 1!")]
+    fn test_new_panic() {
+        let _history: History<TextEdit> = History::new(0);
+    }
+
+    #[test]
+    fn test_undo_and_redo() {
+        let mut text = HeapString::from("Hello");
+        let mut history: History<TextEdit> = History::new(10);
+        history.execute(TextEdit::insert(5, " world"), &mut text);
+        history.execute(TextEdit::delete(0, 1), &mut text);
+        assert_eq!(text.as_str(), "ello world", "Commands must be applied!");
+
+        assert_eq!(history.undo(&mut text), Ok(()), "Undo must succeed!");
+        assert_eq!(text.as_str(), "Hello world", "Deletion must be undone!");
+        assert_eq!(history.undo(&mut text), Ok(()), "Undo must succeed!");
+        assert_eq!(text.as_str(), "Hello", "Insertion must be undone!");
+        assert_eq!(
+            history.undo(&mut text),
+            Err(HistoryError::NothingToUndo),
+            "Empty History must not undo!"
+        );
+
+        assert_eq!(history.redo(&mut text), Ok(()), "Redo must succeed!");
+        assert_eq!(text.as_str(), "Hello world", "Insertion must be redone!");
+        history.execute(TextEdit::insert(0, ">"), &mut text);
+        assert_eq!(
+            history.redo(&mut text),
+            Err(HistoryError::NothingToRedo),
+            "New command must clear the redo Stack!"
+        );
+        assert_eq!(text.as_str(), ">Hello world", "History text is invalid!");
+    }
+
+    #[test]
+    fn test_merge_typing() {
+        let mut text = HeapString::new();
+        let mut history: History<TextEdit> = History::new(10);
+        type_text(&mut history, &mut text, "héllo");
+        history.seal();
+        type_text(&mut history, &mut text, " wörld");
+        assert_eq!(history.undo_len(), 2, "Typed characters must be merged!");
+
+        history.undo(&mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "héllo",
+            "Merged insert must be undone at once!"
+        );
+        history.redo(&mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "héllo wörld",
+            "Merged insert must be redone at once!"
+        );
+    }
+
+    #[test]
+    fn test_merge_deletions() {
+        let mut text = HeapString::from("abcdef");
+        let mut history: History<TextEdit> = History::new(10);
+        // Backspace three times from the end.
+        for index in (3..6).rev() {
+            history.execute(TextEdit::delete(index, 1), &mut text);
+        }
+        assert_eq!(text.as_str(), "abc", "Backspaces must be applied!");
+        history.seal();
+        // Delete key twice at the start.
+        history.execute(TextEdit::delete(0, 1), &mut text);
+        history.execute(TextEdit::delete(0, 1), &mut text);
+        assert_eq!(text.as_str(), "c", "Deletions must be applied!");
+        assert_eq!(history.undo_len(), 2, "Deletions must be merged!");
+
+        history.undo(&mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "abc",
+            "Merged deletion must be undone at once!"
+        );
+        history.undo(&mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "abcdef",
+            "Merged backspaces must be undone at once!"
+        );
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut count = 0;
+        let mut history: History<Counter> = History::new(3);
+        for _i in 0..5 {
+            history.execute(Counter, &mut count);
+        }
+        assert_eq!(history.undo_len(), 3, "History must be bounded!");
+        while history.undo(&mut count).is_ok() {}
+        assert_eq!(count, 2, "Only the last commands can be undone!");
+        assert_eq!(history.redo_len(), 3, "Undone commands must be redoable!");
+    }
+
+    #[test]
+    fn test_transaction() {
+        let mut text = HeapString::from("a");
+        let mut history: History<TextEdit> = History::new(10);
+        history.begin_transaction().unwrap();
+        assert_eq!(
+            history.begin_transaction(),
+            Err(HistoryError::TransactionInProgress),
+            "Transactions can't be nested!"
+        );
+        history.execute(TextEdit::insert(0, "("), &mut text);
+        history.execute(TextEdit::insert(2, ")"), &mut text);
+        assert_eq!(
+            history.undo(&mut text),
+            Err(HistoryError::TransactionInProgress),
+            "Undo is not allowed during a transaction!"
+        );
+        history.commit_transaction().unwrap();
+        assert_eq!(
+            text.as_str(),
+            "(a)",
+            "Transaction commands must be applied!"
+        );
+        assert_eq!(history.undo_len(), 1, "Transaction must be a single entry!");
+
+        history.execute(TextEdit::insert(3, "b"), &mut text);
+        assert_eq!(
+            history.undo_len(),
+            2,
+            "Command must not merge into a transaction!"
+        );
+        history.undo(&mut text).unwrap();
+        history.undo(&mut text).unwrap();
+        assert_eq!(text.as_str(), "a", "Transaction must be undone at once!");
+        history.redo(&mut text).unwrap();
+        assert_eq!(text.as_str(), "(a)", "Transaction must be redone in order!");
+        assert_eq!(
+            history.commit_transaction(),
+            Err(HistoryError::NoTransaction),
+            "There is no transaction to commit!"
+        );
+    }
+
+    #[test]
+    fn test_rollback_transaction() {
+        let mut text = HeapString::from("abc");
+        let mut history: History<TextEdit> = History::new(10);
+        history.begin_transaction().unwrap();
+        history.execute(TextEdit::delete(0, 1), &mut text);
+        history.execute(TextEdit::insert(2, "!"), &mut text);
+        assert_eq!(
+            text.as_str(),
+            "bc!",
+            "Transaction commands must be applied!"
+        );
+        history.rollback_transaction(&mut text).unwrap();
+        assert_eq!(text.as_str(), "abc", "Transaction must be rolled back!");
+        assert!(
+            !history.can_undo(),
+            "Rolled back transaction must not be recorded!"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let mut text = HeapString::new();
+        let mut history: History<TextEdit> = History::new(10);
+        let empty = history.checkpoint();
+        type_text(&mut history, &mut text, "draft");
+        let saved = history.checkpoint();
+        assert!(
+            history.is_at_checkpoint(&saved),
+            "History must be at the checkpoint!"
+        );
+        type_text(&mut history, &mut text, " v2");
+        assert_eq!(history.undo_len(), 2, "Checkpoint must stop the merging!");
+        assert!(
+            !history.is_at_checkpoint(&saved),
+            "History must have moved on!"
+        );
+
+        history.revert_to(&saved, &mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "draft",
+            "History must revert to the checkpoint!"
+        );
+        history.revert_to(&empty, &mut text).unwrap();
+        assert_eq!(text.as_str(), "", "History must revert to the start!");
+        history.revert_to(&saved, &mut text).unwrap();
+        assert_eq!(
+            text.as_str(),
+            "draft",
+            "History must redo up to the checkpoint!"
+        );
+
+        history.execute(TextEdit::insert(0, "!"), &mut text);
+        history.undo(&mut text).unwrap();
+        history.undo(&mut text).unwrap();
+        history.execute(TextEdit::insert(0, "?"), &mut text);
+        assert_eq!(
+            history.revert_to(&saved, &mut text),
+            Err(HistoryError::UnknownCheckpoint),
+            "Overwritten checkpoint must not be found!"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_out_of_limit() {
+        let mut count = 0;
+        let mut history: History<Counter> = History::new(2);
+        let start = history.checkpoint();
+        for _i in 0..3 {
+            history.execute(Counter, &mut count);
+        }
+        assert_eq!(
+            history.revert_to(&start, &mut count),
+            Err(HistoryError::UnknownCheckpoint),
+            "Dropped checkpoint must not be found!"
+        );
+        assert_eq!(count, 3, "Failed revert must not change the target!");
+    }
+}
@@ -6,23 +6,14 @@ It won't be possible implement a Doubly Linked List without such limitations. Ho
 use crate::structs::arrays::HeapArray;
 use crate::structs::smart_ptrs::{AtomicReferenceCounter, HeapBox};
 use num::Bounded;
+use std::collections::HashMap;
 use std::fmt::{Debug, Display, format, Formatter};
+use std::marker::PhantomData;
 use std::ops::{AddAssign, Deref};
-use std::ptr;
+use std::ptr::NonNull;
+use std::{mem, ptr};
 
 pub trait LinkedListADT<T> {
-    fn head_as_ref(&self) -> Option<&NodeType<T>>;
-
-    fn head_as_mut(&mut self) -> Option<&mut NodeType<T>>;
-
-    fn tail_as_ref(&self) -> Option<&NodeType<T>> {
-        None
-    }
-
-    fn tail_as_mut(&mut self) -> Option<&mut NodeType<T>> {
-        None
-    }
-
     fn push_front(&mut self, data: T) -> ();
 
     fn push_back(&mut self, data: T) -> ();
@@ -42,6 +33,21 @@ pub trait LinkedListADT<T> {
     fn len(&self) -> usize;
 }
 
+// Head and tail access for the lists which link their elements with NodeType nodes.
+pub trait NodeListADT<T>: LinkedListADT<T> {
+    fn head_as_ref(&self) -> Option<&NodeType<T>>;
+
+    fn head_as_mut(&mut self) -> Option<&mut NodeType<T>>;
+
+    fn tail_as_ref(&self) -> Option<&NodeType<T>> {
+        None
+    }
+
+    fn tail_as_mut(&mut self) -> Option<&mut NodeType<T>> {
+        None
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum NodeType<T> {
     Singly(HeapBox<Node<T>>),
@@ -181,6 +187,23 @@ impl<T> NodeType<T> {
             }
         }
     }
+
+    fn next_link_mut(&mut self) -> &mut Option<NodeType<T>> {
+        match self {
+            NodeType::Singly(node) => &mut node.next,
+            NodeType::Doubly(node) => &mut node.next,
+        }
+    }
+
+    // Borrows the data and the next link at the same time so that the following nodes can be relinked while the
+    // current value is being compared.
+    fn data_and_next_mut(&mut self) -> (&T, &mut Option<NodeType<T>>) {
+        let node: &mut Node<T> = match self {
+            NodeType::Singly(node) => node,
+            NodeType::Doubly(node) => node,
+        };
+        (&node.data, &mut node.next)
+    }
 }
 
 #[derive(PartialEq, Clone)]
@@ -226,7 +249,7 @@ impl<T: Display> Display for Node<T> {
     }
 }
 
-struct LinkedListIterator<'a, T> {
+pub struct LinkedListIterator<'a, T> {
     current: Option<&'a NodeType<T>>,
 }
 
@@ -246,7 +269,7 @@ pub struct SinglyLinkedList<T> {
     length: usize,
 }
 
-impl<T> LinkedListADT<T> for SinglyLinkedList<T> {
+impl<T> NodeListADT<T> for SinglyLinkedList<T> {
     fn head_as_ref(&self) -> Option<&NodeType<T>> {
         self.head.as_ref()
     }
@@ -254,7 +277,9 @@ impl<T> LinkedListADT<T> for SinglyLinkedList<T> {
     fn head_as_mut(&mut self) -> Option<&mut NodeType<T>> {
         self.head.as_mut()
     }
+}
 
+impl<T> LinkedListADT<T> for SinglyLinkedList<T> {
     // Time Complexity is O(1)
     fn push_front(&mut self, data: T) -> () {
         let new_node = NodeType::Singly(HeapBox::new(Node {
@@ -274,6 +299,7 @@ impl<T> LinkedListADT<T> for SinglyLinkedList<T> {
                 previous: None,
                 data,
             })));
+            self.length += 1;
             return;
         }
         match self.head.is_some() {
@@ -379,6 +405,368 @@ impl<T> SinglyLinkedList<T> {
             current: self.head_as_ref(),
         }
     }
+
+    /*
+    NOTE: The list doesn't keep a tail, so push_back has to walk the whole list. A caller which only appends at the
+    back and removes from the front, like a Queue, can remember the last node instead and append after it. The node
+    lives in its own allocation, so the pointer stays valid until the node is popped or the list is relinked.
+    */
+    // Time Complexity is O(1)
+    pub(crate) unsafe fn push_back_after(
+        &mut self,
+        tail: Option<NonNull<Node<T>>>,
+        data: T,
+    ) -> NonNull<Node<T>> {
+        let node = NodeType::new_singly(data);
+        let node_ptr = NonNull::new(node.as_ptr() as *mut Node<T>).unwrap();
+        match tail {
+            Some(mut tail) => tail.as_mut().next = Some(node),
+            None => self.head = Some(node),
+        }
+        self.length += 1;
+        node_ptr
+    }
+}
+
+impl<T> SinglyLinkedList<T> {
+    // Time Complexity is O(n)
+    pub fn reverse(&mut self) {
+        let mut previous: Option<NodeType<T>> = None;
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next();
+            node.set_next(previous);
+            previous = Some(node);
+        }
+        self.head = previous;
+    }
+
+    // Time Complexity is O(n) and Space Complexity is O(n) because of the recursive calls.
+    pub fn reverse_recursive(&mut self) {
+        let head = self.head.take();
+        self.head = Self::reverse_nodes(head, None);
+    }
+
+    fn reverse_nodes(
+        current: Option<NodeType<T>>,
+        previous: Option<NodeType<T>>,
+    ) -> Option<NodeType<T>> {
+        match current {
+            Some(mut node) => {
+                let next = node.next();
+                node.set_next(previous);
+                Self::reverse_nodes(next, Some(node))
+            }
+            None => previous,
+        }
+    }
+
+    // Merges two sorted chains by relinking the nodes. No node is allocated or copied.
+    // Time Complexity is O(m + n)
+    fn merge_nodes(
+        mut left: Option<NodeType<T>>,
+        mut right: Option<NodeType<T>>,
+    ) -> Option<NodeType<T>>
+    where
+        T: PartialOrd,
+    {
+        let mut head: Option<NodeType<T>> = None;
+        let mut tail = &mut head;
+        loop {
+            let source = match (&left, &right) {
+                (Some(l), Some(r)) => {
+                    if r.data_as_ref() < l.data_as_ref() {
+                        &mut right
+                    } else {
+                        &mut left
+                    }
+                }
+                _ => {
+                    *tail = left.or(right);
+                    break;
+                }
+            };
+            let mut node = source.take().unwrap();
+            *source = node.next();
+            tail = tail.insert(node).next_link_mut();
+        }
+        head
+    }
+
+    // Both lists must already be sorted. The other list is consumed and its nodes are relinked into this one.
+    // Time Complexity is O(m + n)
+    pub fn merge_sorted(&mut self, mut other: SinglyLinkedList<T>)
+    where
+        T: PartialOrd,
+    {
+        self.head = Self::merge_nodes(self.head.take(), other.head.take());
+        self.length += other.length;
+        other.length = 0;
+    }
+
+    fn sort_nodes(head: Option<NodeType<T>>, length: usize) -> Option<NodeType<T>>
+    where
+        T: PartialOrd,
+    {
+        if length <= 1 {
+            return head;
+        }
+        let mut left = head;
+        let mut current = left.as_mut();
+        for _i in 0..length / 2 - 1 {
+            current = current.unwrap().next_as_mut();
+        }
+        let right = current.unwrap().next();
+        Self::merge_nodes(
+            Self::sort_nodes(left.take(), length / 2),
+            Self::sort_nodes(right, length - length / 2),
+        )
+    }
+
+    // Stable merge sort that only relinks the nodes.
+    // Time Complexity is O(n log n)
+    pub fn merge_sort(&mut self)
+    where
+        T: PartialOrd,
+    {
+        self.head = Self::sort_nodes(self.head.take(), self.length);
+    }
+
+    // Only removes consecutive duplicates, so the list must be sorted for all duplicates to be removed.
+    // Time Complexity is O(n)
+    pub fn remove_sorted_duplicates(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut removed: usize = 0;
+        let mut current = self.head.as_mut();
+        while let Some(node) = current {
+            let (data, next) = node.data_and_next_mut();
+            while next.as_ref().is_some_and(|n| n.data_as_ref() == data) {
+                let mut duplicate = next.take().unwrap();
+                *next = duplicate.next();
+                removed += 1;
+            }
+            current = next.as_mut();
+        }
+        self.length -= removed;
+    }
+
+    // Keeps the first occurrence of every value.
+    // Time Complexity is O(n^2)
+    pub fn remove_duplicates(&mut self)
+    where
+        T: PartialEq,
+    {
+        let mut removed: usize = 0;
+        let mut current = self.head.as_mut();
+        while let Some(node) = current {
+            let (data, next) = node.data_and_next_mut();
+            let mut runner = &mut *next;
+            while runner.is_some() {
+                if runner.as_ref().unwrap().data_as_ref() == data {
+                    let mut duplicate = runner.take().unwrap();
+                    *runner = duplicate.next();
+                    removed += 1;
+                } else {
+                    runner = runner.as_mut().unwrap().next_link_mut();
+                }
+            }
+            current = next.as_mut();
+        }
+        self.length -= removed;
+    }
+
+    // Slow and fast pointers. Returns the first of the two middle values for lists with even length.
+    // Time Complexity is O(n)
+    pub fn find_middle(&self) -> Option<&T> {
+        let mut slow = self.head_as_ref();
+        let mut fast = self.head_as_ref().and_then(|node| node.next_as_ref());
+        while let Some(node) = fast.and_then(|node| node.next_as_ref()) {
+            fast = node.next_as_ref();
+            slow = slow.and_then(|node| node.next_as_ref());
+        }
+        slow.map(|node| node.data_as_ref())
+    }
+
+    // The last value is at index 0 from the end. The leading pointer is moved n nodes ahead so that the trailing
+    // pointer lands on the target when the leading one reaches the end.
+    // Time Complexity is O(n)
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        let mut lead = self.head_as_ref();
+        for _i in 0..n {
+            lead = lead?.next_as_ref();
+        }
+        let mut trail = self.head_as_ref();
+        while let Some(next) = lead?.next_as_ref() {
+            lead = Some(next);
+            trail = trail.and_then(|node| node.next_as_ref());
+        }
+        trail.map(|node| node.data_as_ref())
+    }
+
+    // Keeps the first `index` values and returns the remaining ones as a new list.
+    // Time Complexity is O(n)
+    pub fn split_at(&mut self, index: usize) -> SinglyLinkedList<T> {
+        if index > self.length {
+            panic!("Index out of bounds!");
+        }
+        let mut other = SinglyLinkedList::new();
+        if index == 0 {
+            other.head = self.head.take();
+        } else {
+            let mut current = self.head_as_mut();
+            for _i in 0..index - 1 {
+                current = current.unwrap().next_as_mut();
+            }
+            other.head = current.unwrap().next();
+        }
+        other.length = self.length - index;
+        self.length = index;
+        other
+    }
+
+    // Time Complexity is O(n) since we have to walk to the end of this list first.
+    pub fn concat(&mut self, mut other: SinglyLinkedList<T>) {
+        let mut tail = &mut self.head;
+        while tail.is_some() {
+            tail = tail.as_mut().unwrap().next_link_mut();
+        }
+        *tail = other.head.take();
+        self.length += other.length;
+        other.length = 0;
+    }
+
+    // Snapshot of the node addresses for the raw-link debugging mode.
+    // Time Complexity is O(n)
+    pub fn raw_links(&self) -> RawLinks<T> {
+        let mut nodes: HeapArray<*const Node<T>> = HeapArray::with_capacity(self.length);
+        let mut current = self.head_as_ref();
+        while let Some(node) = current {
+            nodes.push(node.as_ptr());
+            current = node.next_as_ref();
+        }
+        RawLinks::new(nodes)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/*
+Raw-link debugging mode
+The links of a list are copied as raw node addresses and they are never dereferenced. The owned list stays intact
+while the links in here can be rewired freely, e.g. pointing the tail back into the list, to reproduce a corrupted
+list and run cycle detection on it.
+*/
+pub struct RawLinks<T> {
+    nodes: HeapArray<*const Node<T>>,
+    links: HashMap<*const Node<T>, *const Node<T>>,
+}
+
+impl<T> RawLinks<T> {
+    fn new(nodes: HeapArray<*const Node<T>>) -> Self {
+        let mut links = HashMap::with_capacity(nodes.get_len());
+        for i in 0..nodes.get_len() {
+            let next = match i + 1 < nodes.get_len() {
+                true => nodes[i + 1],
+                false => ptr::null(),
+            };
+            links.insert(nodes[i], next);
+        }
+        Self { nodes, links }
+    }
+
+    fn head(&self) -> Option<*const Node<T>> {
+        self.nodes.get_optional(0).copied()
+    }
+
+    fn next(&self, node: *const Node<T>) -> Option<*const Node<T>> {
+        self.links
+            .get(&node)
+            .copied()
+            .filter(|next| !next.is_null())
+    }
+
+    // Points the node at index `from` to the node at index `to`. None makes `from` the end of the chain.
+    pub fn relink(&mut self, from: usize, to: Option<usize>) {
+        let len = self.nodes.get_len();
+        if from >= len || to.is_some_and(|to| to >= len) {
+            panic!("Index out of bounds!");
+        }
+        let target = match to {
+            Some(to) => self.nodes[to],
+            None => ptr::null(),
+        };
+        self.links.insert(self.nodes[from], target);
+    }
+
+    // Floyd's tortoise and hare. Returns the index where the cycle starts and its length.
+    // Time Complexity is O(n) and Space Complexity is O(1)
+    pub fn floyd_cycle_detection(&self) -> Option<Cycle> {
+        let head = self.head()?;
+        let mut tortoise = head;
+        let mut hare = head;
+        loop {
+            tortoise = self.next(tortoise)?;
+            hare = self.next(self.next(hare)?)?;
+            if tortoise == hare {
+                break;
+            }
+        }
+
+        let mut start: usize = 0;
+        tortoise = head;
+        while tortoise != hare {
+            tortoise = self.next(tortoise)?;
+            hare = self.next(hare)?;
+            start += 1;
+        }
+
+        let mut length: usize = 1;
+        hare = self.next(tortoise)?;
+        while tortoise != hare {
+            hare = self.next(hare)?;
+            length += 1;
+        }
+        Some(Cycle { start, length })
+    }
+
+    // Brent's algorithm teleports the tortoise to the hare at every power of two. It finds the cycle length first
+    // and needs fewer link lookups than Floyd's algorithm.
+    // Time Complexity is O(n) and Space Complexity is O(1)
+    pub fn brent_cycle_detection(&self) -> Option<Cycle> {
+        let head = self.head()?;
+        let mut power: usize = 1;
+        let mut length: usize = 1;
+        let mut tortoise = head;
+        let mut hare = self.next(head)?;
+        while tortoise != hare {
+            if power == length {
+                tortoise = hare;
+                power *= 2;
+                length = 0;
+            }
+            hare = self.next(hare)?;
+            length += 1;
+        }
+
+        tortoise = head;
+        hare = head;
+        for _i in 0..length {
+            hare = self.next(hare)?;
+        }
+        let mut start: usize = 0;
+        while tortoise != hare {
+            tortoise = self.next(tortoise)?;
+            hare = self.next(hare)?;
+            start += 1;
+        }
+        Some(Cycle { start, length })
+    }
 }
 
 impl<T: Display> Display for SinglyLinkedList<T> {
@@ -412,9 +800,15 @@ impl<T> DoublyLinkedList<T> {
             length: 0,
         }
     }
+
+    pub fn iter(&self) -> LinkedListIterator<'_, T> {
+        LinkedListIterator {
+            current: self.head.as_ref(),
+        }
+    }
 }
 
-impl<T> LinkedListADT<T> for DoublyLinkedList<T>
+impl<T> NodeListADT<T> for DoublyLinkedList<T>
 where
     T: Clone,
 {
@@ -433,7 +827,12 @@ where
     fn tail_as_mut(&mut self) -> Option<&mut NodeType<T>> {
         self.tail.as_mut()
     }
+}
 
+impl<T> LinkedListADT<T> for DoublyLinkedList<T>
+where
+    T: Clone,
+{
     fn push_front(&mut self, data: T) -> () {
         let mut new_node = NodeType::new_doubly(data);
 
@@ -661,575 +1060,2252 @@ impl<T> Drop for DoublyLinkedList<T> {
     }
 }
 
-#[cfg(test)]
-mod node {
-    use crate::structs::linked_lists::{NodeType, Node};
+pub struct CircularLinkedListIterator<'a, T> {
+    current: Option<&'a NodeType<T>>,
+    remaining: usize,
+}
 
-    #[test]
-    fn test_next_as_ref() {
-        let mut node = NodeType::new_singly(1u8);
-        assert_eq!(
-            node.next_as_ref().is_none(),
-            true,
-            "Next node must be None!"
-        );
+impl<'a, T> Iterator for CircularLinkedListIterator<'a, T> {
+    type Item = &'a T;
 
-        node.set_next(Some(NodeType::new_singly(2u8)));
-        assert_eq!(
-            node.next_as_ref().is_some(),
-            true,
-            "Next node must NOT be None!"
-        );
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.current.map(|node| {
+            self.current = node.next_as_ref();
+            self.remaining -= 1;
+            node.data_as_ref()
+        })
     }
+}
 
-    #[test]
-    fn test_next_as_mut() {
-        let mut node = NodeType::new_singly(1u8);
+// Keeps going around the ring forever. It only stops if the list is empty.
+pub struct CycleIterator<'a, T> {
+    current: Option<&'a NodeType<T>>,
+}
 
-        assert_eq!(
-            node.next_as_mut().is_none(),
-            true,
-            "Next node must be None!"
-        );
+impl<'a, T> Iterator for CycleIterator<'a, T> {
+    type Item = &'a T;
 
-        node.set_next(Some(NodeType::new_singly(2u8)));
-        assert_eq!(
-            node.next_as_mut().is_some(),
-            true,
-            "Next node must NOT be None!"
-        );
-        assert_eq!(
-            node.next_as_ref().unwrap().data_as_ref(),
-            &2u8,
-            "Next node has invalid value!"
-        );
-        let mut val = node.next_as_mut().unwrap().data_as_mut();
-        *val = 3u8;
-        assert_eq!(
-            node.next_as_ref().unwrap().data_as_ref(),
-            &3u8,
-            "Next node has invalid value!"
-        );
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| {
+            self.current = node.next_as_ref();
+            node.data_as_ref()
+        })
     }
+}
 
-    #[test]
-    fn test_as_ref() {
-        let node: Node<u8> = Node {
-            next: None,
-            previous: None,
-            data: 1u8,
-        };
+/*
+NOTE:
+The nodes form a ring where every node owns the next one and the tail owns the head. The list itself only keeps
+a raw pointer to the tail because the head is always the next node of the tail. This way, there is exactly one
+owner for every node and both push_front and push_back are O(1).
+*/
+pub struct CircularSinglyLinkedList<T> {
+    tail: Option<NonNull<Node<T>>>,
+    length: usize,
+}
 
-        assert_eq!(node.as_ref(), &1u8, "Node has invalid value!");
+impl<T> CircularSinglyLinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            tail: None,
+            length: 0,
+        }
     }
 
-    #[test]
-    fn test_as_mut() {
-        let mut node: Node<u8> = Node {
-            next: None,
-            previous: None,
-            data: 1u8,
-        };
+    pub fn iter(&self) -> CircularLinkedListIterator<'_, T> {
+        CircularLinkedListIterator {
+            current: self.head_as_ref(),
+            remaining: self.length,
+        }
+    }
 
-        assert_eq!(node.as_mut(), &mut 1u8, "Node has invalid value!");
+    pub fn cycle(&self) -> CycleIterator<'_, T> {
+        CycleIterator {
+            current: self.head_as_ref(),
+        }
     }
 
-    #[test]
-    fn test_set_next() {
-        let mut node = NodeType::new_singly(1u8);
+    pub fn front(&self) -> Option<&T> {
+        self.head_as_ref().map(|node| node.data_as_ref())
+    }
 
-        node.set_next(Some(NodeType::new_singly(2u8)));
-        assert_eq!(
-            node.next_as_ref().is_some(),
-            true,
-            "Failed to set the next link for the node!"
-        );
-        assert_eq!(
-            node.next_as_ref().unwrap().data_as_ref(),
-            &2u8,
-            "Invalid next link value found for the node!"
-        );
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe { &(*tail.as_ptr()).data })
     }
 
-    #[test]
-    fn test_display_trait() {
-        let node: Node<u8> = Node {
+    // Links a new node right after the tail which makes it the new head.
+    // Time Complexity is O(1)
+    fn link_after_tail(&mut self, data: T) -> NonNull<Node<T>> {
+        let mut new_node = HeapBox::new(Node {
             next: None,
             previous: None,
-            data: 1u8,
-        };
+            data,
+        });
+        let new_ptr = NonNull::new(new_node.as_ptr()).unwrap();
+        match self.tail {
+            Some(tail) => {
+                let tail = unsafe { &mut *tail.as_ptr() };
+                new_node.next = tail.next.take();
+                tail.next = Some(NodeType::Singly(new_node));
+            }
+            None => {
+                // A single node ring where the node owns itself.
+                let leaked = HeapBox::leak(new_node);
+                unsafe {
+                    (*leaked.as_ptr()).next = Some(NodeType::Singly(HeapBox::unleak(leaked)));
+                }
+                self.tail = Some(leaked);
+            }
+        }
+        self.length += 1;
+        new_ptr
+    }
 
-        assert_eq!(format!("{node}"), "1", "Node has invalid value!");
+    // Moves the head to the back of the list k times. Nodes are not relinked, only the tail moves forward.
+    // Time Complexity is O(k)
+    pub fn rotate_left(&mut self, k: usize) {
+        if self.length == 0 {
+            return;
+        }
+        let mut tail = self.tail.unwrap();
+        for _i in 0..k % self.length {
+            let next = unsafe { (*tail.as_ptr()).next.as_ref().unwrap().as_ptr() };
+            tail = NonNull::new(next as *mut Node<T>).unwrap();
+        }
+        self.tail = Some(tail);
     }
-}
 
-#[cfg(test)]
-mod linked_list_iterator {
-    use crate::structs::linked_lists::{LinkedListIterator, NodeType, Node};
-    use crate::structs::smart_ptrs::HeapBox;
+    // Singly linked nodes can only move forward so rotating right is a rotation left by the remaining steps.
+    // Time Complexity is O(n)
+    pub fn rotate_right(&mut self, k: usize) {
+        if self.length == 0 {
+            return;
+        }
+        self.rotate_left(self.length - k % self.length);
+    }
 
-    #[test]
-    fn test_next() {
-        let mut node = NodeType::new_singly(1u8);
-        let mut iter = LinkedListIterator {
-            current: Some(&node),
-        };
-        assert_eq!(
-            iter.next(),
-            Some(&1u8),
-            "Singly Linked List Iterator returned invalid value!!"
-        );
-        assert_eq!(
-            iter.next(),
-            None,
-            "Singly Linked List Iterator returned invalid value!!"
-        );
+    // Josephus problem: n people stand in a circle and every k-th person is eliminated until one survives.
+    // The people are numbered from 1 to n and the number of the survivor is returned.
+    // Time Complexity is O(n * k)
+    pub fn josephus(people: usize, step: usize) -> usize {
+        if people == 0 || step == 0 {
+            panic!("Josephus problem needs at least one person and a non-zero step!");
+        }
+        let mut ring: CircularSinglyLinkedList<usize> = CircularSinglyLinkedList::new();
+        for person in 1..=people {
+            ring.push_back(person);
+        }
+        while ring.len() > 1 {
+            ring.rotate_left(step - 1);
+            ring.pop_front();
+        }
+        ring.pop_front().unwrap()
     }
 }
 
-#[cfg(test)]
-mod singly_linked_list {
-    use crate::structs::linked_lists::{LinkedListADT, SinglyLinkedList};
+impl<T> NodeListADT<T> for CircularSinglyLinkedList<T> {
+    fn head_as_ref(&self) -> Option<&NodeType<T>> {
+        self.tail
+            .and_then(|tail| unsafe { (*tail.as_ptr()).next.as_ref() })
+    }
 
-    #[test]
-    fn test_new() {
-        let ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        assert_eq!(
-            ll.head, None,
-            "Singly Linked List has invalid initial Head!"
-        )
+    fn head_as_mut(&mut self) -> Option<&mut NodeType<T>> {
+        self.tail
+            .and_then(|tail| unsafe { (*tail.as_ptr()).next.as_mut() })
     }
+}
 
-    #[test]
-    fn test_head_as_ref() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        assert_eq!(
-            ll.head_as_ref(),
-            None,
-            "Singly Linked List has invalid initial Head reference!"
-        );
-        ll.push_front(1);
-        assert_eq!(
-            ll.head_as_ref().is_some(),
-            true,
-            "Linked List has None Head after push!"
-        )
+impl<T> LinkedListADT<T> for CircularSinglyLinkedList<T> {
+    // Time Complexity is O(1)
+    fn push_front(&mut self, data: T) {
+        self.link_after_tail(data);
     }
 
-    #[test]
-    fn test_head_as_mut() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        assert_eq!(
-            ll.head_as_mut(),
-            None,
-            "Singly Linked List has invalid initial Head mutable reference!"
-        );
-        ll.push_front(1);
-        assert_eq!(
-            ll.head_as_ref().is_some(),
-            true,
-            "Linked List has None Head after push!"
-        )
+    // Time Complexity is O(1)
+    fn push_back(&mut self, data: T) {
+        let new_tail = self.link_after_tail(data);
+        self.tail = Some(new_tail);
     }
 
-    #[test]
-    fn test_push_back() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+    // Time Complexity is O(1)
+    fn pop_front(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.tail {
+            Some(tail) => {
+                let tail = unsafe { &mut *tail.as_ptr() };
+                let mut head = tail.next.take().unwrap();
+                tail.next = head.next();
+                self.length -= 1;
+                if self.length == 0 {
+                    self.tail = None;
+                }
+                Some(head.data())
+            }
+            None => None,
+        }
+    }
 
-        ll.push_back(1u8);
-        assert_eq!(
-            ll.head.is_some(),
-            true,
-            "Singly Linked List Head must not be None!"
-        );
-        assert_eq!(
-            ll.head.as_ref().unwrap().data_as_ref(),
-            &1u8,
-            "Singly Linked List Head has invalid value!"
-        );
+    // Time Complexity is O(n) because we have to walk around the ring to find the node before the tail.
+    fn pop_back(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.length <= 1 {
+            return self.pop_front();
+        }
+        let mut previous = self.tail.unwrap().as_ptr();
+        for _i in 0..self.length - 1 {
+            previous = unsafe { (*previous).next.as_ref().unwrap().as_ptr() as *mut Node<T> };
+        }
+        let previous_node = unsafe { &mut *previous };
+        let mut old_tail = previous_node.next.take().unwrap();
+        previous_node.next = old_tail.next();
+        self.tail = NonNull::new(previous);
+        self.length -= 1;
+        Some(old_tail.data())
+    }
 
-        ll.push_back(2u8);
-        assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Singly Linked List second Node must not be None!"
-        );
-        assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &2u8,
-            "Singly Linked List second Node has invalid value!"
-        );
-        assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .is_none(),
-            true,
-            "Singly Linked List second Node next must be None!"
-        );
+    fn peek(&self, index: usize) -> &T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let mut current = self.head_as_ref();
+        for _i in 0..index {
+            current = current.unwrap().next_as_ref();
+        }
+        current.unwrap().data_as_ref()
     }
 
-    #[test]
-    fn test_push_front() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+    fn peek_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let mut current = self.head_as_mut();
+        for _i in 0..index {
+            current = current.unwrap().next_as_mut();
+        }
+        current.unwrap().data_as_mut()
+    }
 
-        ll.push_front(1u8);
-        assert_eq!(
-            ll.head.is_some(),
-            true,
-            "Singly Linked List Head must not be None!"
-        );
-        assert_eq!(
-            ll.head.as_ref().unwrap().data_as_ref(),
-            &1u8,
-            "Singly Linked List Head has invalid value!"
-        );
+    fn len(&self) -> usize {
+        self.length
+    }
+}
 
-        ll.push_front(2u8);
-        assert_eq!(
-            ll.head.as_ref().unwrap().data_as_ref(),
-            &2u8,
-            "Singly Linked List first Node has invalid value!"
-        );
-        assert_eq!(
-            ll.head.as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Singly Linked List second Node must not be None!"
-        );
-        assert_eq!(
-            ll.head
-                .as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &1u8,
-            "Singly Linked List second Node has invalid value!"
-        );
-        assert_eq!(
-            ll.head
-                .as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .is_none(),
-            true,
-            "Singly Linked List second Node next must be None!"
-        );
+impl<T: Display> Display for CircularSinglyLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.length == 0 {
+            write!(f, "None")?;
+        }
+        for (i, data) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", data)?;
+        }
+        Ok(())
     }
+}
 
-    #[test]
-    fn test_pop_front() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        assert_eq!(
-            ll.pop_front().is_none(),
-            true,
-            "Empty Singly Linked List pop should return None!"
-        );
+impl<T> Drop for CircularSinglyLinkedList<T> {
+    fn drop(&mut self) {
+        // Break the ring at the tail first and then drop the nodes one by one to avoid recursive drops.
+        let mut current = match self.tail.take() {
+            Some(tail) => unsafe { (*tail.as_ptr()).next.take() },
+            None => None,
+        };
+        while let Some(mut node) = current {
+            current = node.next();
+        }
+    }
+}
 
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        ll.push_front(3u8);
+/*
+NOTE:
+Same as the Doubly Linked List, the nodes are shared using the Reference Counter. The tail is always the previous
+node of the head so we only have to keep track of the head. The ring is broken on drop so that the reference counts
+can reach zero.
+*/
+pub struct CircularDoublyLinkedList<T> {
+    head: Option<NodeType<T>>,
+    length: usize,
+}
 
-        assert_eq!(
-            ll.pop_front().unwrap(),
-            3u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 2,
-            "Singly Linked List has invalid length after pop!"
-        );
-        assert_eq!(
-            ll.pop_front().unwrap(),
-            2u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 1,
-            "Singly Linked List has invalid length after pop!"
-        );
-        assert_eq!(
-            ll.pop_front().unwrap(),
-            1u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 0,
-            "Singly Linked List has invalid length after pop!"
-        );
+impl<T> CircularDoublyLinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            length: 0,
+        }
     }
 
-    #[test]
-    fn test_pop_back() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        assert_eq!(
-            ll.pop_back().is_none(),
-            true,
-            "Empty Singly Linked List pop should return None!"
-        );
-
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        ll.push_front(3u8);
+    pub fn iter(&self) -> CircularLinkedListIterator<'_, T> {
+        CircularLinkedListIterator {
+            current: self.head.as_ref(),
+            remaining: self.length,
+        }
+    }
 
-        assert_eq!(
-            ll.pop_back().unwrap(),
-            1u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 2,
-            "Singly Linked List has invalid length after pop!"
-        );
-        assert_eq!(
-            ll.pop_back().unwrap(),
-            2u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 1,
-            "Singly Linked List has invalid length after pop!"
-        );
-        assert_eq!(
-            ll.pop_back().unwrap(),
-            3u8,
-            "Singly Linked List pop returns invalid value!"
-        );
-        assert_eq!(
-            ll.length, 0,
-            "Singly Linked List has invalid length after pop!"
-        );
+    pub fn cycle(&self) -> CycleIterator<'_, T> {
+        CycleIterator {
+            current: self.head.as_ref(),
+        }
     }
 
-    #[test]
-    #[should_panic(expected = "Index out of bounds!")]
-    fn test_peak_panic() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.peek(0);
+    // Time Complexity is O(k)
+    pub fn rotate_left(&mut self, k: usize)
+    where
+        T: Clone,
+    {
+        if self.length == 0 {
+            return;
+        }
+        for _i in 0..k % self.length {
+            self.head = self.head_as_ref().unwrap().next_as_ref().cloned();
+        }
     }
 
-    #[test]
-    fn test_peek() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        assert_eq!(ll.peek(0), &2u8, "Singly Linked List has invalid value!");
-        assert_eq!(ll.peek(1), &1u8, "Singly Linked List has invalid value!");
+    // Time Complexity is O(k)
+    pub fn rotate_right(&mut self, k: usize)
+    where
+        T: Clone,
+    {
+        if self.length == 0 {
+            return;
+        }
+        for _i in 0..k % self.length {
+            self.head = self.head_as_ref().unwrap().previous_as_ref().cloned();
+        }
     }
+}
 
-    #[test]
-    #[should_panic(expected = "Index out of bounds!")]
-    fn test_peak_mut_panic() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.peek_mut(0);
+impl<T> NodeListADT<T> for CircularDoublyLinkedList<T>
+where
+    T: Clone,
+{
+    fn head_as_ref(&self) -> Option<&NodeType<T>> {
+        self.head.as_ref()
     }
 
-    #[test]
-    fn test_peek_mut() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        assert_eq!(
-            ll.peek_mut(0),
-            &mut 2u8,
-            "Singly Linked List has invalid value!"
-        );
-        *ll.peek_mut(0) = 3;
-        assert_eq!(
-            ll.peek_mut(0),
-            &mut 3u8,
-            "Singly Linked List has invalid value!"
-        );
-        assert_eq!(
-            ll.peek_mut(1),
-            &mut 1u8,
-            "Singly Linked List has invalid value!"
-        );
+    fn head_as_mut(&mut self) -> Option<&mut NodeType<T>> {
+        self.head.as_mut()
     }
 
-    #[test]
-    fn test_len() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        assert_eq!(ll.len(), 2, "Singly Linked List has invalid length!");
-        ll.pop_front();
-        assert_eq!(ll.len(), 1, "Singly Linked List has invalid length!")
+    fn tail_as_ref(&self) -> Option<&NodeType<T>> {
+        self.head.as_ref().and_then(|head| head.previous_as_ref())
     }
 
-    #[test]
-    fn test_iter() {
-        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
-        ll.push_front(1u8);
-        ll.push_front(2u8);
-        let mut iter = ll.iter();
-        assert_eq!(
-            iter.next(),
-            Some(&2u8),
-            "Singly Linked List Iterator returned invalid value!!"
-        );
-        assert_eq!(
-            iter.next(),
-            Some(&1u8),
-            "Singly Linked List Iterator returned invalid value!!"
-        );
-        assert_eq!(
-            iter.next(),
-            None,
-            "Singly Linked List Iterator returned invalid value!!"
-        );
+    fn tail_as_mut(&mut self) -> Option<&mut NodeType<T>> {
+        self.head.as_mut().and_then(|head| head.previous_as_mut())
     }
 }
 
-#[cfg(test)]
-mod doubly_linked_list {
-    use crate::structs::arrays::HeapArray;
-    use crate::structs::linked_lists::{DoublyLinkedList, LinkedListADT, NodeType};
-
-    #[test]
-    fn test_new() {
-        let ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        assert_eq!(
-            ll.head, None,
-            "Doubly Linked List has invalid initial Head!"
-        )
+impl<T> LinkedListADT<T> for CircularDoublyLinkedList<T>
+where
+    T: Clone,
+{
+    // Time Complexity is O(1)
+    fn push_front(&mut self, data: T) {
+        let mut new_node = NodeType::new_doubly(data);
+        match self.head.take() {
+            Some(mut head) => {
+                let mut tail = head.previous_as_ref().unwrap().clone();
+                new_node.set_next(Some(head.clone()));
+                new_node.set_previous(Some(tail.clone()));
+                tail.set_next(Some(new_node.clone()));
+                head.set_previous(Some(new_node.clone()));
+            }
+            None => {
+                new_node.set_next(Some(new_node.clone()));
+                new_node.set_previous(Some(new_node.clone()));
+            }
+        }
+        self.head = Some(new_node);
+        self.length += 1;
     }
 
-    #[test]
-    fn test_head_as_ref() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        assert_eq!(
-            ll.head_as_ref(),
-            None,
-            "Linked List has invalid initial Head!"
-        );
-        ll.push_front(1);
-        assert_eq!(
-            ll.head_as_ref().is_some(),
-            true,
-            "Linked List has None Head after push!"
-        )
+    // Time Complexity is O(1)
+    fn push_back(&mut self, data: T) {
+        self.push_front(data);
+        self.rotate_left(1);
     }
 
-    #[test]
-    fn test_tail_as_ref() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        assert_eq!(
-            ll.tail_as_ref(),
-            None,
-            "Linked List has invalid initial Tail!"
-        );
-        ll.push_front(1);
-        assert_eq!(
-            ll.tail_as_ref().is_some(),
-            true,
-            "Linked List has None Tail after push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &1,
-            "Linked List has invalid Tail value after push!"
-        );
-        ll.push_front(2);
-        assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &1,
-            "Linked List has invalid Tail value after push!"
-        );
+    // Time Complexity is O(1)
+    fn pop_front(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.head.take() {
+            Some(mut head) => {
+                let data_copy = head.data();
+                let mut next = head.next().unwrap();
+                let mut previous = head.previous().unwrap();
+                self.length -= 1;
+                if self.length > 0 {
+                    next.set_previous(Some(previous.clone()));
+                    previous.set_next(Some(next.clone()));
+                    self.head = Some(next);
+                }
+                Some(data_copy)
+            }
+            None => None,
+        }
     }
 
-    #[test]
-    fn test_push_front() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+    // Time Complexity is O(1)
+    fn pop_back(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.rotate_right(1);
+        self.pop_front()
+    }
 
+    // Walks from whichever end of the ring is closer to the index.
+    // Time Complexity is O(n/2)
+    fn peek(&self, index: usize) -> &T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let mut current = self.head_as_ref();
+        if index <= self.length / 2 {
+            for _i in 0..index {
+                current = current.unwrap().next_as_ref();
+            }
+        } else {
+            for _i in index..self.length {
+                current = current.unwrap().previous_as_ref();
+            }
+        }
+        current.unwrap().data_as_ref()
+    }
+
+    fn peek_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let length = self.length;
+        let mut current = self.head_as_mut();
+        if index <= length / 2 {
+            for _i in 0..index {
+                current = current.unwrap().next_as_mut();
+            }
+        } else {
+            for _i in index..length {
+                current = current.unwrap().previous_as_mut();
+            }
+        }
+        current.unwrap().data_as_mut()
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<T: Display> Display for CircularDoublyLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.length == 0 {
+            write!(f, "None")?;
+        }
+        for (i, data) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", data)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for CircularDoublyLinkedList<T> {
+    fn drop(&mut self) {
+        // Unlink both directions of every node so that the reference counts drop to zero.
+        let mut current = self.head.take();
+        for _i in 0..self.length {
+            match current {
+                Some(mut node) => {
+                    node.previous();
+                    current = node.next();
+                }
+                None => return,
+            }
+        }
+    }
+}
+
+/*
+NOTE:
+An Unrolled Linked List keeps a small HeapArray block of elements in every node instead of a single element. It needs
+far fewer allocations and pointers than the other lists and the elements of a block sit next to each other in memory,
+so walking the list is much friendlier to the CPU cache.
+Every block except the last one is kept at least half full. A full block is split in two on insert and a block that
+drops under half is refilled from or merged with the next block on delete.
+The elements must be Copy because they are copied between the blocks when a block is split or merged.
+*/
+struct UnrolledNode<T> {
+    elements: HeapArray<T>,
+    next: Option<HeapBox<UnrolledNode<T>>>,
+}
+
+pub struct UnrolledLinkedList<T> {
+    head: Option<HeapBox<UnrolledNode<T>>>,
+    tail: Option<NonNull<UnrolledNode<T>>>,
+    length: usize,
+    block_capacity: usize,
+}
+
+impl<T: Copy> UnrolledLinkedList<T> {
+    pub fn new() -> Self {
+        Self::with_block_capacity(16)
+    }
+
+    pub fn with_block_capacity(block_capacity: usize) -> Self {
+        if block_capacity < 2 {
+            panic!("Block capacity must be at least 2!");
+        }
+        Self {
+            head: None,
+            tail: None,
+            length: 0,
+            block_capacity,
+        }
+    }
+
+    pub fn block_capacity(&self) -> usize {
+        self.block_capacity
+    }
+
+    // Time Complexity is O(n / block capacity)
+    pub fn block_count(&self) -> usize {
+        let mut count: usize = 0;
+        let mut current = self.head.as_deref();
+        while let Some(block) = current {
+            count += 1;
+            current = block.next.as_deref();
+        }
+        count
+    }
+
+    pub fn iter(&self) -> UnrolledLinkedListIterator<'_, T> {
+        UnrolledLinkedListIterator {
+            block: self.head.as_deref(),
+            index: 0,
+        }
+    }
+
+    fn new_block(&self) -> HeapBox<UnrolledNode<T>> {
+        HeapBox::new(UnrolledNode {
+            elements: HeapArray::with_capacity(self.block_capacity),
+            next: None,
+        })
+    }
+
+    // Returns the block that holds the index and the offset of the index inside of that block.
+    // An index equal to the length points right after the last element of the tail block.
+    // Time Complexity is O(n / block capacity)
+    fn locate(&self, index: usize) -> (&UnrolledNode<T>, usize) {
+        let mut offset = index;
+        let mut current = self.head.as_deref().unwrap();
+        while offset >= current.elements.get_len() {
+            match current.next.as_deref() {
+                Some(next) => {
+                    offset -= current.elements.get_len();
+                    current = next;
+                }
+                None => break,
+            }
+        }
+        (current, offset)
+    }
+
+    // Same as locate, but the block pointer comes from the HeapBox of a mutably borrowed list, so it may be written.
+    fn locate_mut(&mut self, index: usize) -> (*mut UnrolledNode<T>, usize) {
+        let mut offset = index;
+        let mut current = self.head.as_mut().unwrap().as_ptr();
+        unsafe {
+            while offset >= (*current).elements.get_len() && (*current).next.is_some() {
+                offset -= (*current).elements.get_len();
+                current = (*current).next.as_mut().unwrap().as_ptr();
+            }
+        }
+        (current, offset)
+    }
+
+    // Moves the upper half of the block into a new block right after it.
+    fn split(&mut self, block: *mut UnrolledNode<T>) {
+        let mut new_block = self.new_block();
+        let block = unsafe { &mut *block };
+        let half = block.elements.get_len() / 2;
+        for i in half..block.elements.get_len() {
+            new_block.elements.push(*block.elements.get(i));
+        }
+        while block.elements.get_len() > half {
+            block.elements.pop();
+        }
+        new_block.next = block.next.take();
+        if new_block.next.is_none() {
+            self.tail = Some(NonNull::new(new_block.as_ptr()).unwrap());
+        }
+        block.next = Some(new_block);
+    }
+
+    // Time Complexity is O(n / block capacity + block capacity)
+    pub fn insert(&mut self, index: usize, data: T) {
+        if index > self.length {
+            panic!("Index out of bounds!");
+        }
+        if self.head.is_none() {
+            let block = self.new_block();
+            self.tail = NonNull::new(block.as_ptr());
+            self.head = Some(block);
+        }
+        let (mut block, mut offset) = self.locate_mut(index);
+        unsafe {
+            if (*block).elements.get_len() == self.block_capacity {
+                self.split(block);
+                let left_len = (*block).elements.get_len();
+                if offset > left_len {
+                    offset -= left_len;
+                    block = (*block).next.as_mut().unwrap().as_ptr();
+                }
+            }
+            (*block).elements.insert(offset, data);
+        }
+        self.length += 1;
+    }
+
+    // Time Complexity is O(n / block capacity + block capacity)
+    pub fn remove(&mut self, index: usize) -> T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let mut previous: *mut UnrolledNode<T> = ptr::null_mut();
+        let mut offset = index;
+        let mut current = self.head.as_mut().unwrap().as_ptr();
+        unsafe {
+            while offset >= (*current).elements.get_len() {
+                offset -= (*current).elements.get_len();
+                previous = current;
+                current = (*current).next.as_mut().unwrap().as_ptr();
+            }
+        }
+        let block = unsafe { &mut *current };
+        let data = block.elements.delete(offset);
+        self.length -= 1;
+
+        if block.elements.get_len() == 0 {
+            // Half of a capacity under 4 is a single element, so any block can become empty. The empty block is
+            // unlinked from the previous block or the head, and the tail moves back when it was the last block.
+            let next = block.next.take();
+            if previous.is_null() {
+                self.head = next;
+                if self.head.is_none() {
+                    self.tail = None;
+                }
+            } else {
+                unsafe {
+                    (*previous).next = next;
+                    if (*previous).next.is_none() {
+                        self.tail = NonNull::new(previous);
+                    }
+                }
+            }
+            return data;
+        }
+        if block.elements.get_len() < self.block_capacity / 2 && block.next.is_some() {
+            let next = block.next.as_mut().unwrap();
+            if block.elements.get_len() + next.elements.get_len() <= self.block_capacity {
+                // Merge the next block into this one.
+                let mut next = block.next.take().unwrap();
+                for i in 0..next.elements.get_len() {
+                    block.elements.push(*next.elements.get(i));
+                }
+                block.next = next.next.take();
+                if block.next.is_none() {
+                    self.tail = NonNull::new(current);
+                }
+            } else {
+                // Borrow the first element of the next block.
+                let borrowed = next.elements.delete(0);
+                block.elements.push(borrowed);
+            }
+        }
+        data
+    }
+}
+
+impl<T: Copy> LinkedListADT<T> for UnrolledLinkedList<T> {
+    // Time Complexity is O(block capacity)
+    fn push_front(&mut self, data: T) {
+        self.insert(0, data);
+    }
+
+    // Time Complexity is O(1)
+    // A full tail block is not split but followed by a new block, so the blocks stay full on sequential pushes.
+    fn push_back(&mut self, data: T) {
+        let tail_is_full = match self.tail {
+            Some(tail) => unsafe { (*tail.as_ptr()).elements.get_len() == self.block_capacity },
+            None => true,
+        };
+        if tail_is_full {
+            let mut block = self.new_block();
+            let block_ptr = NonNull::new(block.as_ptr());
+            block.elements.push(data);
+            match self.tail {
+                Some(tail) => unsafe { (*tail.as_ptr()).next = Some(block) },
+                None => self.head = Some(block),
+            }
+            self.tail = block_ptr;
+        } else {
+            unsafe { (*self.tail.unwrap().as_ptr()).elements.push(data) };
+        }
+        self.length += 1;
+    }
+
+    // Time Complexity is O(block capacity)
+    fn pop_front(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.length {
+            0 => None,
+            _ => Some(self.remove(0)),
+        }
+    }
+
+    // Time Complexity is O(n / block capacity) because the block before the tail might be needed.
+    fn pop_back(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        match self.length {
+            0 => None,
+            _ => Some(self.remove(self.length - 1)),
+        }
+    }
+
+    fn peek(&self, index: usize) -> &T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let (block, offset) = self.locate(index);
+        block.elements.get(offset)
+    }
+
+    fn peek_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let (block, offset) = self.locate_mut(index);
+        unsafe { &mut (&mut (*block).elements)[offset] }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<T: Copy + Display> Display for UnrolledLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.length == 0 {
+            write!(f, "None")?;
+        }
+        for (i, data) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", data)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for UnrolledLinkedList<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut block) = current {
+            current = block.next.take();
+        }
+    }
+}
+
+pub struct UnrolledLinkedListIterator<'a, T> {
+    block: Option<&'a UnrolledNode<T>>,
+    index: usize,
+}
+
+impl<'a, T> Iterator for UnrolledLinkedListIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(block) = self.block {
+            if self.index < block.elements.get_len() {
+                self.index += 1;
+                return Some(block.elements.get(self.index - 1));
+            }
+            self.block = block.next.as_deref();
+            self.index = 0;
+        }
+        None
+    }
+}
+
+/*
+NOTE:
+A XOR Linked List is a Doubly Linked List that stores a single link per node. The link is the XOR of the addresses of
+the previous and the next node, so either neighbour can be recovered while walking from the other one. It saves one
+pointer per node at the cost of only being able to walk the list from one of its ends.
+The addresses are hidden from the compiler, so the list has to manage the nodes with raw pointers.
+*/
+struct XorNode<T> {
+    data: T,
+    both: usize,
+}
+
+pub struct XorLinkedList<T> {
+    head: *mut XorNode<T>,
+    tail: *mut XorNode<T>,
+    length: usize,
+}
+
+impl<T> XorLinkedList<T> {
+    pub fn new() -> Self {
+        Self {
+            head: ptr::null_mut(),
+            tail: ptr::null_mut(),
+            length: 0,
+        }
+    }
+
+    fn xor(a: *mut XorNode<T>, b: *mut XorNode<T>) -> *mut XorNode<T> {
+        (a as usize ^ b as usize) as *mut XorNode<T>
+    }
+
+    pub fn iter(&self) -> XorLinkedListIterator<'_, T> {
+        XorLinkedListIterator {
+            previous: ptr::null_mut(),
+            current: self.head,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_rev(&self) -> XorLinkedListIterator<'_, T> {
+        XorLinkedListIterator {
+            previous: ptr::null_mut(),
+            current: self.tail,
+            marker: PhantomData,
+        }
+    }
+
+    // Walking the list backwards is the same as walking it forwards from the tail, so swapping the ends is enough.
+    // Time Complexity is O(1)
+    pub fn reverse(&mut self) {
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    // Links a new node in front of the given end and returns it as the new end.
+    fn push_at(end: *mut XorNode<T>, data: T) -> *mut XorNode<T> {
+        let node = HeapBox::leak(HeapBox::new(XorNode {
+            data,
+            both: end as usize,
+        }))
+        .as_ptr();
+        if !end.is_null() {
+            unsafe { (*end).both ^= node as usize };
+        }
+        node
+    }
+
+    // Unlinks the given end and returns its data together with the new end.
+    fn pop_at(end: *mut XorNode<T>) -> (T, *mut XorNode<T>) {
+        let next = unsafe { (*end).both as *mut XorNode<T> };
+        if !next.is_null() {
+            unsafe { (*next).both ^= end as usize };
+        }
+        let node = HeapBox::into_inner(HeapBox::unleak(NonNull::new(end).unwrap()));
+        (node.data, next)
+    }
+
+    // Walks from the nearer end of the list.
+    // Time Complexity is O(n)
+    fn node_at(&self, index: usize) -> *mut XorNode<T> {
+        if index >= self.length {
+            panic!("Index out of bounds!");
+        }
+        let (mut current, steps) = match index < self.length / 2 {
+            true => (self.head, index),
+            false => (self.tail, self.length - 1 - index),
+        };
+        let mut previous: *mut XorNode<T> = ptr::null_mut();
+        for _i in 0..steps {
+            let next = Self::xor(previous, unsafe { (*current).both as *mut XorNode<T> });
+            previous = current;
+            current = next;
+        }
+        current
+    }
+}
+
+impl<T> LinkedListADT<T> for XorLinkedList<T> {
+    // Time Complexity is O(1)
+    fn push_front(&mut self, data: T) {
+        self.head = Self::push_at(self.head, data);
+        if self.tail.is_null() {
+            self.tail = self.head;
+        }
+        self.length += 1;
+    }
+
+    // Time Complexity is O(1)
+    fn push_back(&mut self, data: T) {
+        self.tail = Self::push_at(self.tail, data);
+        if self.head.is_null() {
+            self.head = self.tail;
+        }
+        self.length += 1;
+    }
+
+    // Time Complexity is O(1)
+    fn pop_front(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.head.is_null() {
+            return None;
+        }
+        let (data, next) = Self::pop_at(self.head);
+        self.head = next;
+        if next.is_null() {
+            self.tail = ptr::null_mut();
+        }
+        self.length -= 1;
+        Some(data)
+    }
+
+    // Time Complexity is O(1)
+    fn pop_back(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        if self.tail.is_null() {
+            return None;
+        }
+        let (data, next) = Self::pop_at(self.tail);
+        self.tail = next;
+        if next.is_null() {
+            self.head = ptr::null_mut();
+        }
+        self.length -= 1;
+        Some(data)
+    }
+
+    fn peek(&self, index: usize) -> &T {
+        unsafe { &(*self.node_at(index)).data }
+    }
+
+    fn peek_mut(&mut self, index: usize) -> &mut T {
+        unsafe { &mut (*self.node_at(index)).data }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+}
+
+impl<T: Display> Display for XorLinkedList<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.length == 0 {
+            write!(f, "None")?;
+        }
+        for (i, data) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, " -> ")?;
+            }
+            write!(f, "{}", data)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Drop for XorLinkedList<T> {
+    fn drop(&mut self) {
+        while !self.head.is_null() {
+            let (_data, next) = Self::pop_at(self.head);
+            self.head = next;
+        }
+    }
+}
+
+pub struct XorLinkedListIterator<'a, T> {
+    previous: *mut XorNode<T>,
+    current: *mut XorNode<T>,
+    marker: PhantomData<&'a XorNode<T>>,
+}
+
+impl<'a, T> Iterator for XorLinkedListIterator<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_null() {
+            return None;
+        }
+        let node = unsafe { &*self.current };
+        let next = XorLinkedList::xor(self.previous, node.both as *mut XorNode<T>);
+        self.previous = self.current;
+        self.current = next;
+        Some(&node.data)
+    }
+}
+
+#[cfg(test)]
+mod node {
+    use crate::structs::linked_lists::{NodeType, Node};
+
+    #[test]
+    fn test_next_as_ref() {
+        let mut node = NodeType::new_singly(1u8);
+        assert_eq!(
+            node.next_as_ref().is_none(),
+            true,
+            "Next node must be None!"
+        );
+
+        node.set_next(Some(NodeType::new_singly(2u8)));
+        assert_eq!(
+            node.next_as_ref().is_some(),
+            true,
+            "Next node must NOT be None!"
+        );
+    }
+
+    #[test]
+    fn test_next_as_mut() {
+        let mut node = NodeType::new_singly(1u8);
+
+        assert_eq!(
+            node.next_as_mut().is_none(),
+            true,
+            "Next node must be None!"
+        );
+
+        node.set_next(Some(NodeType::new_singly(2u8)));
+        assert_eq!(
+            node.next_as_mut().is_some(),
+            true,
+            "Next node must NOT be None!"
+        );
+        assert_eq!(
+            node.next_as_ref().unwrap().data_as_ref(),
+            &2u8,
+            "Next node has invalid value!"
+        );
+        let mut val = node.next_as_mut().unwrap().data_as_mut();
+        *val = 3u8;
+        assert_eq!(
+            node.next_as_ref().unwrap().data_as_ref(),
+            &3u8,
+            "Next node has invalid value!"
+        );
+    }
+
+    #[test]
+    fn test_as_ref() {
+        let node: Node<u8> = Node {
+            next: None,
+            previous: None,
+            data: 1u8,
+        };
+
+        assert_eq!(node.as_ref(), &1u8, "Node has invalid value!");
+    }
+
+    #[test]
+    fn test_as_mut() {
+        let mut node: Node<u8> = Node {
+            next: None,
+            previous: None,
+            data: 1u8,
+        };
+
+        assert_eq!(node.as_mut(), &mut 1u8, "Node has invalid value!");
+    }
+
+    #[test]
+    fn test_set_next() {
+        let mut node = NodeType::new_singly(1u8);
+
+        node.set_next(Some(NodeType::new_singly(2u8)));
+        assert_eq!(
+            node.next_as_ref().is_some(),
+            true,
+            "Failed to set the next link for the node!"
+        );
+        assert_eq!(
+            node.next_as_ref().unwrap().data_as_ref(),
+            &2u8,
+            "Invalid next link value found for the node!"
+        );
+    }
+
+    #[test]
+    fn test_display_trait() {
+        let node: Node<u8> = Node {
+            next: None,
+            previous: None,
+            data: 1u8,
+        };
+
+        assert_eq!(format!("{node}"), "1", "Node has invalid value!");
+    }
+}
+
+#[cfg(test)]
+mod linked_list_iterator {
+    use crate::structs::linked_lists::{LinkedListIterator, NodeType, Node};
+    use crate::structs::smart_ptrs::HeapBox;
+
+    #[test]
+    fn test_next() {
+        let mut node = NodeType::new_singly(1u8);
+        let mut iter = LinkedListIterator {
+            current: Some(&node),
+        };
+        assert_eq!(
+            iter.next(),
+            Some(&1u8),
+            "Singly Linked List Iterator returned invalid value!!"
+        );
+        assert_eq!(
+            iter.next(),
+            None,
+            "Singly Linked List Iterator returned invalid value!!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod singly_linked_list {
+    use crate::structs::linked_lists::{LinkedListADT, NodeListADT, SinglyLinkedList};
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_new() {
+        let ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        assert_eq!(
+            ll.head, None,
+            "Singly Linked List has invalid initial Head!"
+        )
+    }
+
+    #[test]
+    fn test_head_as_ref() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        assert_eq!(
+            ll.head_as_ref(),
+            None,
+            "Singly Linked List has invalid initial Head reference!"
+        );
+        ll.push_front(1);
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Linked List has None Head after push!"
+        )
+    }
+
+    #[test]
+    fn test_head_as_mut() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        assert_eq!(
+            ll.head_as_mut(),
+            None,
+            "Singly Linked List has invalid initial Head mutable reference!"
+        );
+        ll.push_front(1);
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Linked List has None Head after push!"
+        )
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+
+        ll.push_back(1u8);
+        assert_eq!(
+            ll.head.is_some(),
+            true,
+            "Singly Linked List Head must not be None!"
+        );
+        assert_eq!(
+            ll.head.as_ref().unwrap().data_as_ref(),
+            &1u8,
+            "Singly Linked List Head has invalid value!"
+        );
+
+        ll.push_back(2u8);
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Singly Linked List second Node must not be None!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &2u8,
+            "Singly Linked List second Node has invalid value!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .is_none(),
+            true,
+            "Singly Linked List second Node next must be None!"
+        );
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+
+        ll.push_front(1u8);
+        assert_eq!(
+            ll.head.is_some(),
+            true,
+            "Singly Linked List Head must not be None!"
+        );
+        assert_eq!(
+            ll.head.as_ref().unwrap().data_as_ref(),
+            &1u8,
+            "Singly Linked List Head has invalid value!"
+        );
+
+        ll.push_front(2u8);
+        assert_eq!(
+            ll.head.as_ref().unwrap().data_as_ref(),
+            &2u8,
+            "Singly Linked List first Node has invalid value!"
+        );
+        assert_eq!(
+            ll.head.as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Singly Linked List second Node must not be None!"
+        );
+        assert_eq!(
+            ll.head
+                .as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &1u8,
+            "Singly Linked List second Node has invalid value!"
+        );
+        assert_eq!(
+            ll.head
+                .as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .is_none(),
+            true,
+            "Singly Linked List second Node next must be None!"
+        );
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        assert_eq!(
+            ll.pop_front().is_none(),
+            true,
+            "Empty Singly Linked List pop should return None!"
+        );
+
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        ll.push_front(3u8);
+
+        assert_eq!(
+            ll.pop_front().unwrap(),
+            3u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 2,
+            "Singly Linked List has invalid length after pop!"
+        );
+        assert_eq!(
+            ll.pop_front().unwrap(),
+            2u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 1,
+            "Singly Linked List has invalid length after pop!"
+        );
+        assert_eq!(
+            ll.pop_front().unwrap(),
+            1u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 0,
+            "Singly Linked List has invalid length after pop!"
+        );
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        assert_eq!(
+            ll.pop_back().is_none(),
+            true,
+            "Empty Singly Linked List pop should return None!"
+        );
+
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        ll.push_front(3u8);
+
+        assert_eq!(
+            ll.pop_back().unwrap(),
+            1u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 2,
+            "Singly Linked List has invalid length after pop!"
+        );
+        assert_eq!(
+            ll.pop_back().unwrap(),
+            2u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 1,
+            "Singly Linked List has invalid length after pop!"
+        );
+        assert_eq!(
+            ll.pop_back().unwrap(),
+            3u8,
+            "Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.length, 0,
+            "Singly Linked List has invalid length after pop!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peak_panic() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.peek(0);
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        assert_eq!(ll.peek(0), &2u8, "Singly Linked List has invalid value!");
+        assert_eq!(ll.peek(1), &1u8, "Singly Linked List has invalid value!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peak_mut_panic() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.peek_mut(0);
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        assert_eq!(
+            ll.peek_mut(0),
+            &mut 2u8,
+            "Singly Linked List has invalid value!"
+        );
+        *ll.peek_mut(0) = 3;
+        assert_eq!(
+            ll.peek_mut(0),
+            &mut 3u8,
+            "Singly Linked List has invalid value!"
+        );
+        assert_eq!(
+            ll.peek_mut(1),
+            &mut 1u8,
+            "Singly Linked List has invalid value!"
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        assert_eq!(ll.len(), 2, "Singly Linked List has invalid length!");
+        ll.pop_front();
+        assert_eq!(ll.len(), 1, "Singly Linked List has invalid length!")
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.push_front(1u8);
+        ll.push_front(2u8);
+        let mut iter = ll.iter();
+        assert_eq!(
+            iter.next(),
+            Some(&2u8),
+            "Singly Linked List Iterator returned invalid value!!"
+        );
+        assert_eq!(
+            iter.next(),
+            Some(&1u8),
+            "Singly Linked List Iterator returned invalid value!!"
+        );
+        assert_eq!(
+            iter.next(),
+            None,
+            "Singly Linked List Iterator returned invalid value!!"
+        );
+    }
+
+    fn from_slice(values: &[u8]) -> SinglyLinkedList<u8> {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        for &value in values {
+            ll.push_back(value);
+        }
+        ll
+    }
+
+    fn to_vec(ll: &SinglyLinkedList<u8>) -> Vec<u8> {
+        ll.iter().copied().collect()
+    }
+
+    fn random_vec(len: usize, max: u8) -> Vec<u8> {
+        let mut rng = thread_rng();
+        (0..len).map(|_| rng.gen_range(0..=max)).collect()
+    }
+
+    #[test]
+    fn test_push_back_length() {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        ll.push_back(1);
+        assert_eq!(
+            ll.len(),
+            1,
+            "Singly Linked List has invalid length after first push!"
+        );
+        ll.push_back(2);
+        assert_eq!(
+            ll.len(),
+            2,
+            "Singly Linked List has invalid length after push!"
+        );
+    }
+
+    #[test]
+    fn test_reverse() {
+        for len in 0..20 {
+            let mut expected = random_vec(len, 100);
+            let mut ll = from_slice(&expected);
+            ll.reverse();
+            expected.reverse();
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List reverse is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                len,
+                "Singly Linked List has invalid length after reverse!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reverse_recursive() {
+        for len in 0..20 {
+            let mut expected = random_vec(len, 100);
+            let mut ll = from_slice(&expected);
+            ll.reverse_recursive();
+            expected.reverse();
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List recursive reverse is invalid!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        for (m, n) in [(0, 0), (0, 3), (3, 0), (5, 7), (10, 2)] {
+            let mut left = random_vec(m, 20);
+            let mut right = random_vec(n, 20);
+            left.sort();
+            right.sort();
+            let mut ll = from_slice(&left);
+            ll.merge_sorted(from_slice(&right));
+            left.extend(right);
+            left.sort();
+            assert_eq!(
+                to_vec(&ll),
+                left,
+                "Singly Linked List sorted merge is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                m + n,
+                "Singly Linked List has invalid length after merge!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_merge_sort() {
+        for len in 0..50 {
+            let mut expected = random_vec(len, 30);
+            let mut ll = from_slice(&expected);
+            ll.merge_sort();
+            expected.sort();
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List merge sort is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                len,
+                "Singly Linked List has invalid length after sort!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_remove_sorted_duplicates() {
+        for len in 0..30 {
+            let mut expected = random_vec(len, 5);
+            expected.sort();
+            let mut ll = from_slice(&expected);
+            ll.remove_sorted_duplicates();
+            expected.dedup();
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List sorted deduplication is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                expected.len(),
+                "Singly Linked List has invalid length!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_remove_duplicates() {
+        for len in 0..30 {
+            let values = random_vec(len, 5);
+            let mut expected: Vec<u8> = Vec::new();
+            for &value in &values {
+                if !expected.contains(&value) {
+                    expected.push(value);
+                }
+            }
+            let mut ll = from_slice(&values);
+            ll.remove_duplicates();
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List deduplication is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                expected.len(),
+                "Singly Linked List has invalid length!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_middle() {
+        assert_eq!(
+            SinglyLinkedList::<u8>::new().find_middle(),
+            None,
+            "Empty list has no middle!"
+        );
+        for len in 1..20 {
+            let values = random_vec(len, 100);
+            let ll = from_slice(&values);
+            assert_eq!(
+                ll.find_middle(),
+                Some(&values[(len - 1) / 2]),
+                "Singly Linked List middle is invalid!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_nth_from_end() {
+        for len in 0..10 {
+            let values = random_vec(len, 100);
+            let ll = from_slice(&values);
+            for n in 0..len + 2 {
+                assert_eq!(
+                    ll.nth_from_end(n),
+                    values.iter().rev().nth(n),
+                    "Singly Linked List nth value from the end is invalid!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_at() {
+        for len in 0..10 {
+            for index in 0..=len {
+                let mut expected = random_vec(len, 100);
+                let mut ll = from_slice(&expected);
+                let other = ll.split_at(index);
+                let expected_other = expected.split_off(index);
+                assert_eq!(
+                    to_vec(&ll),
+                    expected,
+                    "Singly Linked List split is invalid!"
+                );
+                assert_eq!(to_vec(&other), expected_other, "Split off list is invalid!");
+                assert_eq!(
+                    ll.len(),
+                    index,
+                    "Singly Linked List has invalid length after split!"
+                );
+                assert_eq!(
+                    other.len(),
+                    len - index,
+                    "Split off list has invalid length!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_split_at_panic() {
+        let mut ll = from_slice(&[1, 2]);
+        ll.split_at(3);
+    }
+
+    #[test]
+    fn test_concat() {
+        for (m, n) in [(0, 0), (0, 3), (3, 0), (4, 5)] {
+            let mut expected = random_vec(m, 100);
+            let other = random_vec(n, 100);
+            let mut ll = from_slice(&expected);
+            ll.concat(from_slice(&other));
+            expected.extend(other);
+            assert_eq!(
+                to_vec(&ll),
+                expected,
+                "Singly Linked List concatenation is invalid!"
+            );
+            assert_eq!(
+                ll.len(),
+                m + n,
+                "Singly Linked List has invalid length after concat!"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod doubly_linked_list {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::linked_lists::{DoublyLinkedList, LinkedListADT, NodeType};
+    use crate::structs::linked_lists::NodeListADT;
+
+    #[test]
+    fn test_new() {
+        let ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        assert_eq!(
+            ll.head, None,
+            "Doubly Linked List has invalid initial Head!"
+        )
+    }
+
+    #[test]
+    fn test_head_as_ref() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        assert_eq!(
+            ll.head_as_ref(),
+            None,
+            "Linked List has invalid initial Head!"
+        );
+        ll.push_front(1);
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Linked List has None Head after push!"
+        )
+    }
+
+    #[test]
+    fn test_tail_as_ref() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        assert_eq!(
+            ll.tail_as_ref(),
+            None,
+            "Linked List has invalid initial Tail!"
+        );
+        ll.push_front(1);
+        assert_eq!(
+            ll.tail_as_ref().is_some(),
+            true,
+            "Linked List has None Tail after push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Linked List has invalid Tail value after push!"
+        );
+        ll.push_front(2);
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Linked List has invalid Tail value after push!"
+        );
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+
+        ll.push_front(5);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "5".to_string(),
+        //     "Doubly Linked List is invalid after first front push!"
+        // );
+        assert_eq!(
+            ll.length, 1,
+            "Doubly Linked List has invalid length after front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Head after first front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Head after first front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Head has invalid previous Node after first front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Head has invalid next Node after first front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Tail after first front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Tail after first front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Tail has invalid previous Node after first front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Tail has invalid next Node after first front push!"
+        );
+
+        ll.push_front(10);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "10 -> 5".to_string(),
+        //     "Doubly Linked List is invalid after second front push!"
+        // );
+        assert_eq!(
+            ll.length, 2,
+            "Doubly Linked List has invalid length after push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Head after second front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &10,
+            "Doubly Linked List has invalid Head after second front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Head has invalid previous Node after second front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Head has invalid None next Node after second front push!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &5,
+            "Head has invalid next Node after second front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Tail after second front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Tail after second front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
+            true,
+            "Tail has invalid None previous Node after second front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &10,
+            "Tail has invalid previous Node after second front push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Tail has invalid next Node after second front push!"
+        );
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+
+        ll.push_back(5);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "5".to_string(),
+        //     "Linked List is invalid after first back push!"
+        // );
+        assert_eq!(
+            ll.length, 1,
+            "Doubly Linked List has invalid length after back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Head after first back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Head after first back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Head has invalid previous Node after first back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Head has invalid next Node after first back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Tail after first back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Tail after first back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Tail has invalid previous Node after first back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Tail has invalid next Node after first back push!"
+        );
+
+        ll.push_back(10);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "5 -> 10".to_string(),
+        //     "Doubly Linked List is invalid after second back push!"
+        // );
+        assert_eq!(
+            ll.length, 2,
+            "Doubly Linked List has invalid length after push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Head after second back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Head after second back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
+            true,
+            "Head has invalid previous Node after second back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Head has invalid None next Node after second back push!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &10,
+            "Head has invalid next Node after second back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().is_some(),
+            true,
+            "Doubly Linked List has invalid None Tail after second back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &10,
+            "Doubly Linked List has invalid Tail after second back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
+            true,
+            "Tail has invalid None previous Node after second back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &5,
+            "Tail has invalid previous Node after second back push!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Tail has invalid next Node after second back push!"
+        );
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
         ll.push_front(5);
+        ll.push_front(10);
+        ll.push_front(15);
+
+        ll.pop_front();
         // assert_eq!(
         //     format!("{}", ll),
-        //     "5".to_string(),
-        //     "Doubly Linked List is invalid after first front push!"
+        //     "10 -> 5".to_string(),
+        //     "Doubly Linked List is invalid after first front pop!"
         // );
         assert_eq!(
-            ll.length, 1,
-            "Doubly Linked List has invalid length after front push!"
+            ll.length, 2,
+            "Doubly Linked List has invalid length after pop!"
         );
         assert_eq!(
-            ll.head_as_ref().is_some(),
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &10,
+            "Doubly Linked List has invalid Head after first front pop!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Doubly Linked List has invalid None Head after first front push!"
+            "Head has invalid previous Node after first front pop!"
+        );
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Head has invalid None next Node after first front pop!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &5,
+            "Head has invalid next Node after first front pop!"
+        );
+
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &5,
+            "Doubly Linked List has invalid Tail after front pop!"
+        );
+
+        ll.pop_front();
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "5".to_string(),
+        //     "Doubly Linked List is invalid after second front pop!"
+        // );
+        assert_eq!(
+            ll.length, 1,
+            "Doubly Linked List has invalid length after second front pop!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().data_as_ref(),
             &5,
-            "Doubly Linked List has invalid Head after first front push!"
+            "Doubly Linked List has invalid Head after second front pop!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Head has invalid previous Node after first front push!"
+            "Head has invalid previous Node after second front pop!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().next_as_ref().is_none(),
             true,
-            "Head has invalid next Node after first front push!"
+            "Head has invalid None next Node after second front pop!"
+        );
+
+        ll.pop_front();
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "None",
+        //     "Doubly Linked List is invalid after last front pop!"
+        // );
+        assert_eq!(
+            ll.length, 0,
+            "Doubly Linked List has invalid length after last front pop!"
         );
         assert_eq!(
-            ll.tail_as_ref().is_some(),
+            ll.head_as_ref().is_none(),
             true,
-            "Doubly Linked List has invalid None Tail after first front push!"
+            "Doubly Linked List has invalid Head after last front pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().is_none(),
+            true,
+            "Doubly Linked List has invalid Tail after last front pop!"
+        )
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.push_front(5);
+        ll.push_front(10);
+        ll.push_front(15);
+
+        ll.pop_back();
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "15 -> 10".to_string(),
+        //     "Doubly Linked List is invalid after first back pop!"
+        // );
+        assert_eq!(
+            ll.length, 2,
+            "Doubly Linked List has invalid length after pop!"
         );
         assert_eq!(
             ll.tail_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Tail after first front push!"
+            &10,
+            "Doubly Linked List has invalid Tail after first back pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            true,
+            "Tail has invalid next Node after first back pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
+            true,
+            "Tail has invalid None previous Node after first back pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &15,
+            "Tail has invalid previous Node after first back pop!"
+        );
+
+        assert_eq!(
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &15,
+            "Doubly Linked List has invalid Head after back pop!"
+        );
+
+        ll.pop_back();
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "15".to_string(),
+        //     "Doubly Linked List is invalid after second back pop!"
+        // );
+        assert_eq!(
+            ll.length, 1,
+            "Doubly Linked List has invalid length after second back pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &15,
+            "Doubly Linked List has invalid Tail after second back pop!"
         );
         assert_eq!(
             ll.tail_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Tail has invalid previous Node after first front push!"
+            "Tail has invalid None previous Node after second back pop!"
         );
         assert_eq!(
             ll.tail_as_ref().unwrap().next_as_ref().is_none(),
             true,
-            "Tail has invalid next Node after first front push!"
+            "Tail has invalid next Node after second back pop!"
         );
 
-        ll.push_front(10);
+        ll.pop_back();
         // assert_eq!(
         //     format!("{}", ll),
-        //     "10 -> 5".to_string(),
-        //     "Doubly Linked List is invalid after second front push!"
+        //     "None",
+        //     "Doubly Linked List is invalid after last back pop!"
         // );
         assert_eq!(
-            ll.length, 2,
-            "Doubly Linked List has invalid length after push!"
+            ll.length, 0,
+            "Doubly Linked List has invalid length after last back pop!"
         );
         assert_eq!(
-            ll.head_as_ref().is_some(),
+            ll.head_as_ref().is_none(),
             true,
-            "Doubly Linked List has invalid None Head after second front push!"
+            "Doubly Linked List has invalid Head after last back pop!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &10,
-            "Doubly Linked List has invalid Head after second front push!"
+            ll.tail_as_ref().is_none(),
+            true,
+            "Doubly Linked List has invalid Tail after last back pop!"
+        )
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.push_front(5);
+        assert_eq!(ll.length, 1, "Linked List has invalid length after insert!");
+
+        ll.insert(0, 7);
+        assert_eq!(ll.length, 2, "Linked List has invalid length after insert!");
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            true,
+            "Doubly Linked List new insert has invalid next node!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &5,
+            "Doubly Linked List new insert has invalid next node value!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &7,
+            "Doubly Linked List shifted node after insert has invalid previous node value!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Head has invalid previous Node after second front push!"
+            "Doubly Linked List new insert has invalid previous node!"
+        );
+
+        ll.insert(1, 6);
+        assert_eq!(ll.length, 3, "Linked List has invalid length after insert!");
+        assert_eq!(
+            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
+            &6,
+            "Doubly Linked List newly inserted node's previous node is pointing to an invalid value!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .is_some(),
             true,
-            "Head has invalid None next Node after second front push!"
+            "Doubly Linked List new insert has invalid previous node!"
+        );
+        assert_eq!(
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .previous_as_ref()
+                .unwrap()
+                .data_as_ref(),
+            &7,
+            "Doubly Linked List new insert has invalid previous node value!"
         );
         assert_eq!(
             ll.head_as_ref()
@@ -1237,23 +3313,29 @@ mod doubly_linked_list {
                 .next_as_ref()
                 .unwrap()
                 .data_as_ref(),
-            &5,
-            "Head has invalid next Node after second front push!"
+            &6,
+            "Doubly Linked List new insert has invalid value!"
         );
         assert_eq!(
-            ll.tail_as_ref().is_some(),
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .is_some(),
             true,
-            "Doubly Linked List has invalid None Tail after second front push!"
+            "Doubly Linked List new insert has invalid next node!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
+            ll.head_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .next_as_ref()
+                .unwrap()
+                .data_as_ref(),
             &5,
-            "Doubly Linked List has invalid Tail after second front push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
-            true,
-            "Tail has invalid None previous Node after second front push!"
+            "Doubly Linked List new insert has invalid next node value!"
         );
         assert_eq!(
             ll.tail_as_ref()
@@ -1261,172 +3343,504 @@ mod doubly_linked_list {
                 .previous_as_ref()
                 .unwrap()
                 .data_as_ref(),
-            &10,
-            "Tail has invalid previous Node after second front push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Tail has invalid next Node after second front push!"
+            &6,
+            "Doubly Linked List newly inserted node's next node is pointing to an invalid value!"
         );
     }
 
     #[test]
-    fn test_push_back() {
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_insert_panic() {
         let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.insert(5, 5);
+    }
 
-        ll.push_back(5);
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "5".to_string(),
-        //     "Linked List is invalid after first back push!"
-        // );
-        assert_eq!(
-            ll.length, 1,
-            "Doubly Linked List has invalid length after back push!"
-        );
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_delete_panic() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.delete(5);
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        // ll.push_front(5);
+        ll.push_front(10);
+        ll.push_front(15);
+        ll.push_front(20);
+        ll.push_front(25);
+        ll.delete(0);
+        assert_eq!(ll.length, 3, "Linked List has invalid length after delete!");
         assert_eq!(
             ll.head_as_ref().is_some(),
             true,
-            "Doubly Linked List has invalid None Head after first back push!"
+            "Doubly Linked List must have a new head after head delete!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Head after first back push!"
+            &20,
+            "Doubly Linked List new head has invalid data after delete!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Head has invalid previous Node after first back push!"
-        );
-        assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Head has invalid next Node after first back push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().is_some(),
-            true,
-            "Doubly Linked List has invalid None Tail after first back push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Tail after first back push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Tail has invalid previous Node after first back push!"
+            "Doubly Linked List new head must not have any previous node!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
+            ll.head_as_ref().unwrap().next_as_ref().is_some(),
             true,
-            "Tail has invalid next Node after first back push!"
+            "Doubly Linked List new head must have next node here!"
         );
-
-        ll.push_back(10);
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "5 -> 10".to_string(),
-        //     "Doubly Linked List is invalid after second back push!"
-        // );
         assert_eq!(
-            ll.length, 2,
-            "Doubly Linked List has invalid length after push!"
+            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
+            &15,
+            "Doubly Linked List new head must have next node here!"
         );
         assert_eq!(
-            ll.head_as_ref().is_some(),
-            true,
-            "Doubly Linked List has invalid None Head after second back push!"
+            ll.head_as_ref().unwrap().next_as_ref().unwrap().previous_as_ref().unwrap().data_as_ref(),
+            &20,
+            "Doubly Linked List new head next has an invalid previous node!"
         );
+        println!("{:?}", ll);
+        ll.delete(1);
+        println!("{:?}", ll);
+        assert_eq!(ll.length, 2, "Linked List has invalid length after delete!");
         assert_eq!(
             ll.head_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Head after second back push!"
+            &20,
+            "Doubly Linked List must have the same head delete!"
         );
         assert_eq!(
             ll.head_as_ref().unwrap().previous_as_ref().is_none(),
             true,
-            "Head has invalid previous Node after second back push!"
-        );
-        assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Head has invalid None next Node after second back push!"
+            "Doubly Linked List Head must not have a previous Node!"
         );
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .data_as_ref(),
+            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
             &10,
-            "Head has invalid next Node after second back push!"
-        );
-        assert_eq!(
-            ll.tail_as_ref().is_some(),
-            true,
-            "Doubly Linked List has invalid None Tail after second back push!"
+            "Doubly Linked List Head has invalid next node!"
         );
+        println!("{}", ll);
         assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
+            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
             &10,
-            "Doubly Linked List has invalid Tail after second back push!"
+            "Doubly Linked List head must have new node after delete!"
         );
+
+        // assert_eq!(
+        //     ll.head_as_ref()
+        //         .unwrap()
+        //         .next_as_ref()
+        //         .unwrap()
+        //         .data_as_ref(),
+        //     &5,
+        //     "Doubly Linked List new insert has invalid next node value!"
+        // );
+
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "20 -> 15 -> 10 -> 5".to_string(),
+        //     "Linked List is invalid after first index deletion!"
+        // );
+        // assert_eq!(
+        //     ll.head_as_ref().unwrap().data,
+        //     20,
+        //     "Linked List has invalid Head after first index deletion!"
+        // );
+        //
+        // ll.delete(3);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "20 -> 15 -> 10".to_string(),
+        //     "Linked List is invalid after last index deletion!"
+        // );
+        // assert_eq!(ll.length, 3, "Linked List has invalid length after delete!");
+        // assert_eq!(
+        //     ll.tail_as_ref().unwrap().data,
+        //     10,
+        //     "Linked List has invalid Tail after last index deletion!"
+        // );
+        //
+        // ll.delete(2);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "20 -> 15".to_string(),
+        //     "Linked List is invalid after middle index deletion!"
+        // );
+        // assert_eq!(ll.length, 2, "Linked List has invalid length after delete!");
+        // assert_eq!(
+        //     ll.tail_as_ref().unwrap().data,
+        //     15,
+        //     "Linked List has invalid Tail after middle deletion!"
+        // );
+        //
+        // ll.delete(1);
+        // ll.delete(0);
+        // assert_eq!(
+        //     format!("{}", ll),
+        //     "None".to_string(),
+        //     "Linked List is invalid after all indices deletion!"
+        // );
+        // assert_eq!(ll.length, 0, "Linked List has invalid length after delete!");
+        // assert_eq!(
+        //     ll.head_as_ref().is_none(),
+        //     true,
+        //     "Linked List has invalid Head for empty List!"
+        // );
+        // assert_eq!(
+        //     ll.tail_as_ref().is_none(),
+        //     true,
+        //     "Linked List has invalid Tail for empty List!"
+        // );
+    }
+    //
+    // #[test]
+    // #[should_panic(expected = "Index out of bounds!")]
+    // fn test_delete_panic() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.delete(0);
+    // }
+
+    // #[test]
+    // fn test_swap() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.push_front(1);
+    //     ll.push_front(2);
+    //     ll.push_front(3);
+    //     ll.push_front(4);
+    //
+    //     assert_eq!(
+    //         ll.swap(5, 10).unwrap_err(),
+    //         "Index out of bounds!",
+    //         "Linked List invalid indices exception failed!"
+    //     );
+    //
+    //     ll.swap(1, 2).expect("Failed to swap Nodes");
+    //     assert_eq!(
+    //         format!("{}", ll),
+    //         "4 -> 2 -> 3 -> 1".to_string(),
+    //         "Linked List is invalid after swapping middle Nodes!"
+    //     );
+    //
+    //     ll.swap(2, 3).expect("Failed to swap Nodes");
+    //     assert_eq!(
+    //         format!("{}", ll),
+    //         "4 -> 2 -> 1 -> 3".to_string(),
+    //         "Linked List is invalid after swapping last Nodes!"
+    //     );
+    //
+    //     ll.swap(0, 1).expect("Failed to swap Nodes");
+    //     assert_eq!(
+    //         format!("{}", ll),
+    //         "2 -> 4 -> 1 -> 3".to_string(),
+    //         "Linked List is invalid after swapping initial Nodes!"
+    //     );
+    // }
+
+    #[test]
+    fn test_len() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.push_front(5);
+        ll.push_front(10);
+        assert_eq!(ll.len(), 2, "Doubly Linked List invalid nodes length!");
+        ll.pop_front();
+        assert_eq!(ll.len(), 1, "Doubly Linked List invalid nodes length!")
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.push_front(5);
+        ll.push_front(10);
         assert_eq!(
-            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
-            true,
-            "Tail has invalid None previous Node after second back push!"
+            ll.peek(0),
+            &10u8,
+            "Doubly Linked List invalid value at 0 index!"
         );
         assert_eq!(
-            ll.tail_as_ref()
-                .unwrap()
-                .previous_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &5,
-            "Tail has invalid previous Node after second back push!"
+            ll.peek(1),
+            &5u8,
+            "Doubly Linked List invalid value at 1 index!"
         );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peek_panic() {
+        let ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.peek(0);
+    }
+
+    #[test]
+    fn test_peek_mut() {
+        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        ll.push_front(10);
         assert_eq!(
-            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Tail has invalid next Node after second back push!"
+            ll.peek_mut(0),
+            &mut 10u8,
+            "Doubly Linked List invalid head value!"
         );
     }
 
     #[test]
-    fn test_pop_front() {
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peek_mut_panic() {
         let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(5);
-        ll.push_front(10);
-        ll.push_front(15);
+        ll.peek_mut(0);
+    }
 
-        ll.pop_front();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "10 -> 5".to_string(),
-        //     "Doubly Linked List is invalid after first front pop!"
-        // );
+    // #[test]
+    // fn test_sum() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     assert_eq!(ll.sum(), 15, "Invalid Doubly Linked List sum!");
+    // }
+    //
+    // #[test]
+    // fn test_max() {
+    //     let mut ll: LinkedList<i8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     ll.push_front(2);
+    //     ll.push_front(-5);
+    //     assert_eq!(ll.max(), 10, "Invalid Doubly Linked List max value!");
+    // }
+    //
+    // #[test]
+    // fn test_min() {
+    //     let mut ll: LinkedList<i8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     ll.push_front(2);
+    //     ll.push_front(-5);
+    //     assert_eq!(ll.min(), -5, "Invalid Doubly Linked List min value!");
+    // }
+    //
+    // #[test]
+    // fn test_linear_search() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     ll.push_front(2);
+    //     assert_eq!(
+    //         ll.linear_search(10),
+    //         ll.head_as_ref().as_ref().unwrap().next.as_ref(),
+    //         "Invalid Linked List node for searchable value!"
+    //     );
+    //     assert_eq!(
+    //         ll.linear_search(11),
+    //         None,
+    //         "Invalid Linked List node for unsearchable value!"
+    //     );
+    // }
+    //
+    // #[test]
+    // fn test_move_to_head_search() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     ll.push_front(2);
+    //
+    //     let node_1 = ll.linear_search(5).unwrap().clone();
+    //     let node_2 = ll.linear_search(10).unwrap().clone();
+    //     let node_3 = ll.linear_search(2).unwrap().clone();
+    //     // println!("Node 1 (5) = {:?}", node_1);
+    //     // println!("Node 2 (10) = {:?}", node_2);
+    //     // println!("Node 3 (2) = {:?}", node_3);
+    //
+    //     // Original List: 2 -> 10 -> 5
+    //     // Moved List: 10 -> 2 -> 5
+    //
+    //     // Try moving some in between Node
+    //     assert_eq!(
+    //         *ll.move_to_head_search(10).unwrap(),
+    //         node_2,
+    //         "Failed to return the found Node!"
+    //     );
+    //     assert_eq!(
+    //         ll.head_previous_as_ref().is_none(),
+    //         true,
+    //         "Moved Node has invalid previous Node set!"
+    //     );
+    //     assert_eq!(
+    //         *ll.head_next_as_ref().unwrap(),
+    //         node_3,
+    //         "Moved Node has invalid next Node set!"
+    //     );
+    //     assert_eq!(
+    //         *node_3.next_as_ref().unwrap(),
+    //         node_1,
+    //         "Old previous Node has invalid adjusted next Node set!"
+    //     );
+    //     assert_eq!(
+    //         *node_1.previous_as_ref().unwrap(),
+    //         node_3,
+    //         "Old next Node has invalid adjusted previous Node set!"
+    //     );
+    //
+    //     // Try moving the already moved Node or head Node.
+    //     assert_eq!(
+    //         *ll.move_to_head_search(10).unwrap(),
+    //         node_2,
+    //         "Failed to return the found Node!"
+    //     );
+    //     assert_eq!(
+    //         ll.head_previous_as_ref().is_none(),
+    //         true,
+    //         "Unmoved Node has invalid previous Node set!"
+    //     );
+    //     assert_eq!(
+    //         *ll.head_next_as_ref().unwrap(),
+    //         node_3,
+    //         "Unmoved Node has invalid next Node set!"
+    //     );
+    //     println!("{}", ll);
+    //     // Try moving the last Node
+    //     println!("F Node = {:?}", node_2.next_as_ref().unwrap());
+    //     assert_eq!(
+    //         *ll.move_to_head_search(5).unwrap(),
+    //         node_1,
+    //         "Failed to return the found last Node!"
+    //     );
+    //     assert_eq!(
+    //         node_3.next_as_ref().is_none(),
+    //         true,
+    //         "Last moved Node has invalid next Node set!"
+    //     );
+    // }
+    //
+    // #[test]
+    // fn test_display_trait() {
+    //     let mut ll: LinkedList<u8> = LinkedList::new();
+    //     ll.push_front(5);
+    //     ll.push_front(10);
+    //     assert_eq!(
+    //         format!("{}", ll),
+    //         format!("10 -> 5"),
+    //         "Linked List has invalid Display trait!"
+    //     );
+    // }
+    //
+    // #[test]
+    // fn test_from_trait_heap_array() {
+    //     let arr: HeapArray<u8> = HeapArray::values(&[1, 2, 3]);
+    //     let ll: LinkedList<u8> = LinkedList::from(arr);
+    //     assert_eq!(
+    //         format!("{}", ll),
+    //         "1 -> 2 -> 3".to_string(),
+    //         "Linked List is invalid after conversion from Heap Array!"
+    //     );
+    // }
+}
+
+#[cfg(test)]
+mod raw_links {
+    use crate::structs::linked_lists::{Cycle, LinkedListADT, SinglyLinkedList};
+
+    fn list(len: u8) -> SinglyLinkedList<u8> {
+        let mut ll: SinglyLinkedList<u8> = SinglyLinkedList::new();
+        for i in 0..len {
+            ll.push_back(i);
+        }
+        ll
+    }
+
+    #[test]
+    fn test_no_cycle() {
+        for len in 0..10 {
+            let links = list(len).raw_links();
+            assert_eq!(
+                links.floyd_cycle_detection(),
+                None,
+                "Floyd found a cycle in a valid list!"
+            );
+            assert_eq!(
+                links.brent_cycle_detection(),
+                None,
+                "Brent found a cycle in a valid list!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        for len in 1..15usize {
+            let ll = list(len as u8);
+            for start in 0..len {
+                let mut links = ll.raw_links();
+                links.relink(len - 1, Some(start));
+                let expected = Some(Cycle {
+                    start,
+                    length: len - start,
+                });
+                assert_eq!(
+                    links.floyd_cycle_detection(),
+                    expected,
+                    "Floyd cycle detection is invalid!"
+                );
+                assert_eq!(
+                    links.brent_cycle_detection(),
+                    expected,
+                    "Brent cycle detection is invalid!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_relink_to_end() {
+        let ll = list(5);
+        let mut links = ll.raw_links();
+        links.relink(4, Some(1));
+        links.relink(2, None);
         assert_eq!(
-            ll.length, 2,
-            "Doubly Linked List has invalid length after pop!"
+            links.floyd_cycle_detection(),
+            None,
+            "Cut list must not have a cycle!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &10,
-            "Doubly Linked List has invalid Head after first front pop!"
+            links.brent_cycle_detection(),
+            None,
+            "Cut list must not have a cycle!"
         );
-        assert_eq!(
-            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Head has invalid previous Node after first front pop!"
+        assert_eq!(ll.len(), 5, "Raw links must not modify the list!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_relink_panic() {
+        let mut links = list(2).raw_links();
+        links.relink(0, Some(2));
+    }
+}
+
+#[cfg(test)]
+mod circular_singly_linked_list {
+    use crate::structs::linked_lists::{CircularSinglyLinkedList, LinkedListADT, NodeListADT};
+
+    #[test]
+    fn test_new() {
+        let ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        assert!(
+            ll.tail.is_none(),
+            "Circular Singly Linked List has invalid initial Tail!"
+        );
+        assert_eq!(
+            ll.length, 0,
+            "Circular Singly Linked List has invalid initial length!"
         );
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        ll.push_front(1);
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Head has invalid None next Node after first front pop!"
+            ll.head_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Circular Singly Linked List has invalid Head after push!"
         );
         assert_eq!(
             ll.head_as_ref()
@@ -1434,651 +3848,937 @@ mod doubly_linked_list {
                 .next_as_ref()
                 .unwrap()
                 .data_as_ref(),
-            &5,
-            "Head has invalid next Node after first front pop!"
+            &1,
+            "Single Node must link back to itself!"
         );
-
+        ll.push_front(2);
+        ll.push_front(3);
         assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Tail after front pop!"
+            format!("{}", ll),
+            "3 -> 2 -> 1",
+            "Circular Singly Linked List is invalid after front pushes!"
+        );
+        assert_eq!(
+            ll.back(),
+            Some(&1),
+            "Circular Singly Linked List has invalid Tail!"
+        );
+        assert_eq!(
+            ll.len(),
+            3,
+            "Circular Singly Linked List has invalid length!"
         );
+    }
 
-        ll.pop_front();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "5".to_string(),
-        //     "Doubly Linked List is invalid after second front pop!"
-        // );
+    #[test]
+    fn test_push_back() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
         assert_eq!(
-            ll.length, 1,
-            "Doubly Linked List has invalid length after second front pop!"
+            format!("{}", ll),
+            "1 -> 2 -> 3",
+            "Circular Singly Linked List is invalid after back pushes!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &5,
-            "Doubly Linked List has invalid Head after second front pop!"
+            ll.front(),
+            Some(&1),
+            "Circular Singly Linked List has invalid Head!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Head has invalid previous Node after second front pop!"
+            ll.back(),
+            Some(&3),
+            "Circular Singly Linked List has invalid Tail!"
         );
+        let tail = ll
+            .head_as_ref()
+            .unwrap()
+            .next_as_ref()
+            .unwrap()
+            .next_as_ref()
+            .unwrap();
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Head has invalid None next Node after second front pop!"
+            tail.next_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Tail must link back to the Head!"
         );
+    }
 
-        ll.pop_front();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "None",
-        //     "Doubly Linked List is invalid after last front pop!"
-        // );
+    #[test]
+    fn test_pop_front() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        assert_eq!(ll.pop_front(), None, "Empty list pop should return None!");
+        ll.push_back(1);
+        ll.push_back(2);
         assert_eq!(
-            ll.length, 0,
-            "Doubly Linked List has invalid length after last front pop!"
+            ll.pop_front(),
+            Some(1),
+            "Circular Singly Linked List pop returns invalid value!"
         );
         assert_eq!(
+            ll.front(),
+            Some(&2),
+            "Circular Singly Linked List has invalid Head!"
+        );
+        assert_eq!(
+            ll.back(),
+            Some(&2),
+            "Circular Singly Linked List has invalid Tail!"
+        );
+        assert_eq!(
+            ll.pop_front(),
+            Some(2),
+            "Circular Singly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.len(),
+            0,
+            "Circular Singly Linked List has invalid length after pop!"
+        );
+        assert!(
             ll.head_as_ref().is_none(),
-            true,
-            "Doubly Linked List has invalid Head after last front pop!"
+            "Circular Singly Linked List Head must be None!"
         );
+        ll.push_back(3);
         assert_eq!(
-            ll.tail_as_ref().is_none(),
-            true,
-            "Doubly Linked List has invalid Tail after last front pop!"
-        )
+            format!("{}", ll),
+            "3",
+            "Circular Singly Linked List is invalid after reuse!"
+        );
     }
 
     #[test]
     fn test_pop_back() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(5);
-        ll.push_front(10);
-        ll.push_front(15);
-
-        ll.pop_back();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "15 -> 10".to_string(),
-        //     "Doubly Linked List is invalid after first back pop!"
-        // );
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        assert_eq!(ll.pop_back(), None, "Empty list pop should return None!");
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
         assert_eq!(
-            ll.length, 2,
-            "Doubly Linked List has invalid length after pop!"
+            ll.pop_back(),
+            Some(3),
+            "Circular Singly Linked List pop returns invalid value!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &10,
-            "Doubly Linked List has invalid Tail after first back pop!"
+            ll.back(),
+            Some(&2),
+            "Circular Singly Linked List has invalid Tail!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Tail has invalid next Node after first back pop!"
+            format!("{}", ll),
+            "1 -> 2",
+            "Circular Singly Linked List is invalid after pop!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().previous_as_ref().is_some(),
-            true,
-            "Tail has invalid None previous Node after first back pop!"
+            ll.pop_back(),
+            Some(2),
+            "Circular Singly Linked List pop returns invalid value!"
         );
         assert_eq!(
-            ll.tail_as_ref()
-                .unwrap()
-                .previous_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &15,
-            "Tail has invalid previous Node after first back pop!"
+            ll.pop_back(),
+            Some(1),
+            "Circular Singly Linked List pop returns invalid value!"
         );
+        assert_eq!(ll.pop_back(), None, "Empty list pop should return None!");
+    }
 
+    #[test]
+    fn test_rotate_left() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        ll.rotate_left(2);
+        for i in 1..=4 {
+            ll.push_back(i);
+        }
+        ll.rotate_left(1);
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &15,
-            "Doubly Linked List has invalid Head after back pop!"
+            format!("{}", ll),
+            "2 -> 3 -> 4 -> 1",
+            "Invalid list after left rotation!"
         );
-
-        ll.pop_back();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "15".to_string(),
-        //     "Doubly Linked List is invalid after second back pop!"
-        // );
+        ll.rotate_left(6);
         assert_eq!(
-            ll.length, 1,
-            "Doubly Linked List has invalid length after second back pop!"
+            format!("{}", ll),
+            "4 -> 1 -> 2 -> 3",
+            "Invalid list after left rotation!"
         );
         assert_eq!(
-            ll.tail_as_ref().unwrap().data_as_ref(),
-            &15,
-            "Doubly Linked List has invalid Tail after second back pop!"
+            ll.back(),
+            Some(&3),
+            "Circular Singly Linked List has invalid Tail!"
         );
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        for i in 1..=4 {
+            ll.push_back(i);
+        }
+        ll.rotate_right(1);
         assert_eq!(
-            ll.tail_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Tail has invalid None previous Node after second back pop!"
+            format!("{}", ll),
+            "4 -> 1 -> 2 -> 3",
+            "Invalid list after right rotation!"
         );
+        ll.rotate_right(4);
         assert_eq!(
-            ll.tail_as_ref().unwrap().next_as_ref().is_none(),
-            true,
-            "Tail has invalid next Node after second back pop!"
+            format!("{}", ll),
+            "4 -> 1 -> 2 -> 3",
+            "Invalid list after full rotation!"
         );
+    }
 
-        ll.pop_back();
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "None",
-        //     "Doubly Linked List is invalid after last back pop!"
-        // );
+    #[test]
+    fn test_peek() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        ll.push_back(1);
+        ll.push_back(2);
         assert_eq!(
-            ll.length, 0,
-            "Doubly Linked List has invalid length after last back pop!"
+            ll.peek(0),
+            &1,
+            "Circular Singly Linked List has invalid value!"
         );
         assert_eq!(
-            ll.head_as_ref().is_none(),
-            true,
-            "Doubly Linked List has invalid Head after last back pop!"
+            ll.peek(1),
+            &2,
+            "Circular Singly Linked List has invalid value!"
         );
+        *ll.peek_mut(1) = 5;
         assert_eq!(
-            ll.tail_as_ref().is_none(),
-            true,
-            "Doubly Linked List has invalid Tail after last back pop!"
-        )
+            ll.peek(1),
+            &5,
+            "Circular Singly Linked List has invalid value!"
+        );
     }
 
     #[test]
-    fn test_insert() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(5);
-        assert_eq!(ll.length, 1, "Linked List has invalid length after insert!");
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peek_panic() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
+        ll.push_back(1);
+        ll.peek(1);
+    }
 
-        ll.insert(0, 7);
-        assert_eq!(ll.length, 2, "Linked List has invalid length after insert!");
+    #[test]
+    fn test_iter() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Doubly Linked List new insert has invalid next node!"
+            ll.iter().next(),
+            None,
+            "Empty list iterator must return None!"
         );
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
+        let lap: Vec<u8> = ll.iter().copied().collect();
+        assert_eq!(lap, vec![1, 2, 3], "Iterator must stop after one lap!");
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut ll: CircularSinglyLinkedList<u8> = CircularSinglyLinkedList::new();
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &5,
-            "Doubly Linked List new insert has invalid next node value!"
+            ll.cycle().next(),
+            None,
+            "Empty list cycle must return None!"
         );
+        ll.push_back(1);
+        ll.push_back(2);
+        let laps: Vec<u8> = ll.cycle().take(5).copied().collect();
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .previous_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &7,
-            "Doubly Linked List shifted node after insert has invalid previous node value!"
+            laps,
+            vec![1, 2, 1, 2, 1],
+            "Cycle iterator must wrap around!"
         );
+    }
+
+    #[test]
+    fn test_josephus() {
         assert_eq!(
-            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Doubly Linked List new insert has invalid previous node!"
+            CircularSinglyLinkedList::<usize>::josephus(1, 3),
+            1,
+            "Invalid Josephus survivor!"
         );
-
-        ll.insert(1, 6);
-        assert_eq!(ll.length, 3, "Linked List has invalid length after insert!");
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
-            &6,
-            "Doubly Linked List newly inserted node's previous node is pointing to an invalid value!"
+            CircularSinglyLinkedList::<usize>::josephus(5, 2),
+            3,
+            "Invalid Josephus survivor!"
         );
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .previous_as_ref()
-                .is_some(),
-            true,
-            "Doubly Linked List new insert has invalid previous node!"
+            CircularSinglyLinkedList::<usize>::josephus(7, 3),
+            4,
+            "Invalid Josephus survivor!"
         );
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .previous_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &7,
-            "Doubly Linked List new insert has invalid previous node value!"
+            CircularSinglyLinkedList::<usize>::josephus(41, 3),
+            31,
+            "Invalid Josephus survivor!"
+        );
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut ll: CircularSinglyLinkedList<String> = CircularSinglyLinkedList::new();
+        for i in 0..1000 {
+            ll.push_back(i.to_string());
+        }
+        drop(ll);
+    }
+}
+
+#[cfg(test)]
+mod circular_doubly_linked_list {
+    use crate::structs::linked_lists::{CircularDoublyLinkedList, LinkedListADT, NodeListADT};
+
+    #[test]
+    fn test_new() {
+        let ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        assert!(
+            ll.head.is_none(),
+            "Circular Doubly Linked List has invalid initial Head!"
         );
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .data_as_ref(),
-            &6,
-            "Doubly Linked List new insert has invalid value!"
+            ll.length, 0,
+            "Circular Doubly Linked List has invalid initial length!"
+        );
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        ll.push_front(1);
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Single Node must be both Head and Tail!"
         );
+        ll.push_front(2);
+        ll.push_front(3);
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .unwrap()
-                .next_as_ref()
-                .is_some(),
-            true,
-            "Doubly Linked List new insert has invalid next node!"
+            format!("{}", ll),
+            "3 -> 2 -> 1",
+            "Circular Doubly Linked List is invalid after front pushes!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &1,
+            "Circular Doubly Linked List has invalid Tail!"
         );
         assert_eq!(
-            ll.head_as_ref()
-                .unwrap()
-                .next_as_ref()
+            ll.tail_as_ref()
                 .unwrap()
                 .next_as_ref()
                 .unwrap()
                 .data_as_ref(),
-            &5,
-            "Doubly Linked List new insert has invalid next node value!"
+            &3,
+            "Tail must link forward to the Head!"
+        );
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
+        assert_eq!(
+            format!("{}", ll),
+            "1 -> 2 -> 3",
+            "Circular Doubly Linked List is invalid after back pushes!"
         );
         assert_eq!(
-            ll.tail_as_ref()
+            ll.head_as_ref()
                 .unwrap()
                 .previous_as_ref()
                 .unwrap()
                 .data_as_ref(),
-            &6,
-            "Doubly Linked List newly inserted node's next node is pointing to an invalid value!"
+            &3,
+            "Head must link backward to the Tail!"
         );
     }
 
     #[test]
-    #[should_panic(expected = "Index out of bounds!")]
-    fn test_insert_panic() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.insert(5, 5);
+    fn test_pop_front() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        assert_eq!(ll.pop_front(), None, "Empty list pop should return None!");
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
+        assert_eq!(
+            ll.pop_front(),
+            Some(1),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            format!("{}", ll),
+            "2 -> 3",
+            "Circular Doubly Linked List is invalid after pop!"
+        );
+        assert_eq!(
+            ll.tail_as_ref().unwrap().data_as_ref(),
+            &3,
+            "Circular Doubly Linked List has invalid Tail!"
+        );
+        assert_eq!(
+            ll.pop_front(),
+            Some(2),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.pop_front(),
+            Some(3),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.len(),
+            0,
+            "Circular Doubly Linked List has invalid length!"
+        );
+        assert!(
+            ll.head_as_ref().is_none(),
+            "Circular Doubly Linked List Head must be None!"
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Index out of bounds!")]
-    fn test_delete_panic() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.delete(5);
+    fn test_pop_back() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        assert_eq!(ll.pop_back(), None, "Empty list pop should return None!");
+        ll.push_back(1);
+        ll.push_back(2);
+        ll.push_back(3);
+        assert_eq!(
+            ll.pop_back(),
+            Some(3),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            format!("{}", ll),
+            "1 -> 2",
+            "Circular Doubly Linked List is invalid after pop!"
+        );
+        assert_eq!(
+            ll.pop_back(),
+            Some(2),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(
+            ll.pop_back(),
+            Some(1),
+            "Circular Doubly Linked List pop returns invalid value!"
+        );
+        assert_eq!(ll.pop_back(), None, "Empty list pop should return None!");
     }
 
     #[test]
-    fn test_delete() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        // ll.push_front(5);
-        ll.push_front(10);
-        ll.push_front(15);
-        ll.push_front(20);
-        ll.push_front(25);
-        ll.delete(0);
-        assert_eq!(ll.length, 3, "Linked List has invalid length after delete!");
+    fn test_rotate() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        for i in 1..=4 {
+            ll.push_back(i);
+        }
+        ll.rotate_left(1);
         assert_eq!(
-            ll.head_as_ref().is_some(),
-            true,
-            "Doubly Linked List must have a new head after head delete!"
+            format!("{}", ll),
+            "2 -> 3 -> 4 -> 1",
+            "Invalid list after left rotation!"
         );
+        ll.rotate_right(2);
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &20,
-            "Doubly Linked List new head has invalid data after delete!"
+            format!("{}", ll),
+            "4 -> 1 -> 2 -> 3",
+            "Invalid list after right rotation!"
         );
+        ll.rotate_right(8);
         assert_eq!(
-            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Doubly Linked List new head must not have any previous node!"
+            format!("{}", ll),
+            "4 -> 1 -> 2 -> 3",
+            "Invalid list after full rotations!"
         );
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        for i in 1..=5 {
+            ll.push_back(i);
+        }
+        for i in 0..5 {
+            assert_eq!(
+                ll.peek(i),
+                &(i as u8 + 1),
+                "Circular Doubly Linked List has invalid value!"
+            );
+        }
+        *ll.peek_mut(4) = 10;
+        *ll.peek_mut(1) = 20;
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().is_some(),
-            true,
-            "Doubly Linked List new head must have next node here!"
+            format!("{}", ll),
+            "1 -> 20 -> 3 -> 4 -> 10",
+            "Circular Doubly Linked List has invalid values!"
         );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_peek_panic() {
+        let ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        ll.peek(0);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut ll: CircularDoublyLinkedList<u8> = CircularDoublyLinkedList::new();
+        ll.push_back(1);
+        ll.push_back(2);
+        let lap: Vec<u8> = ll.iter().copied().collect();
+        assert_eq!(lap, vec![1, 2], "Iterator must stop after one lap!");
+        let laps: Vec<u8> = ll.cycle().take(5).copied().collect();
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
-            &15,
-            "Doubly Linked List new head must have next node here!"
+            laps,
+            vec![1, 2, 1, 2, 1],
+            "Cycle iterator must wrap around!"
+        );
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut ll: CircularDoublyLinkedList<String> = CircularDoublyLinkedList::new();
+        for i in 0..1000 {
+            ll.push_back(i.to_string());
+        }
+        drop(ll);
+    }
+}
+
+#[cfg(test)]
+mod unrolled_linked_list {
+    use crate::structs::linked_lists::{LinkedListADT, UnrolledLinkedList};
+    use rand::{thread_rng, Rng};
+
+    fn to_vec(ll: &UnrolledLinkedList<u32>) -> Vec<u32> {
+        ll.iter().copied().collect()
+    }
+
+    // Every block except the last one must be at least half full.
+    fn assert_blocks(ll: &UnrolledLinkedList<u32>) {
+        let mut current = ll.head.as_deref();
+        let mut count: usize = 0;
+        while let Some(block) = current {
+            assert!(
+                block.elements.get_len() > 0,
+                "Unrolled Linked List must not keep empty blocks!"
+            );
+            if block.next.is_some() {
+                assert!(
+                    block.elements.get_len() >= ll.block_capacity() / 2,
+                    "Unrolled Linked List block is less than half full!"
+                );
+            } else {
+                assert_eq!(
+                    ll.tail.unwrap().as_ptr() as *const _,
+                    block as *const _,
+                    "Unrolled Linked List has invalid Tail!"
+                );
+            }
+            count += block.elements.get_len();
+            current = block.next.as_deref();
+        }
+        assert_eq!(count, ll.len(), "Unrolled Linked List has invalid length!");
+    }
+
+    #[test]
+    fn test_new() {
+        let ll: UnrolledLinkedList<u32> = UnrolledLinkedList::new();
+        assert!(
+            ll.head.is_none(),
+            "Unrolled Linked List has invalid initial Head!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().unwrap().previous_as_ref().unwrap().data_as_ref(),
-            &20,
-            "Doubly Linked List new head next has an invalid previous node!"
+            ll.len(),
+            0,
+            "Unrolled Linked List has invalid initial length!"
         );
-        println!("{:?}", ll);
-        ll.delete(1);
-        println!("{:?}", ll);
-        assert_eq!(ll.length, 2, "Linked List has invalid length after delete!");
         assert_eq!(
-            ll.head_as_ref().unwrap().data_as_ref(),
-            &20,
-            "Doubly Linked List must have the same head delete!"
+            ll.block_capacity(),
+            16,
+            "Unrolled Linked List has invalid block capacity!"
         );
         assert_eq!(
-            ll.head_as_ref().unwrap().previous_as_ref().is_none(),
-            true,
-            "Doubly Linked List Head must not have a previous Node!"
+            format!("{}", ll),
+            "None",
+            "Empty Unrolled Linked List is invalid!"
         );
+    }
+
+    #[test]
+    #[should_panic(expected = "Block capacity must be at least 2!")]
+    fn test_with_block_capacity_panic() {
+        let _ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(1);
+    }
+
+    #[test]
+    fn test_push_back() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(4);
+        for i in 0..10 {
+            ll.push_back(i);
+        }
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
-            &10,
-            "Doubly Linked List Head has invalid next node!"
+            to_vec(&ll),
+            (0..10).collect::<Vec<u32>>(),
+            "Unrolled Linked List is invalid!"
         );
-        println!("{}", ll);
         assert_eq!(
-            ll.head_as_ref().unwrap().next_as_ref().unwrap().data_as_ref(),
-            &10,
-            "Doubly Linked List head must have new node after delete!"
+            ll.block_count(),
+            3,
+            "Sequential pushes must fill the blocks!"
         );
+        assert_blocks(&ll);
+    }
 
-        // assert_eq!(
-        //     ll.head_as_ref()
-        //         .unwrap()
-        //         .next_as_ref()
-        //         .unwrap()
-        //         .data_as_ref(),
-        //     &5,
-        //     "Doubly Linked List new insert has invalid next node value!"
-        // );
+    #[test]
+    fn test_push_front() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(4);
+        for i in 0..10 {
+            ll.push_front(i);
+        }
+        assert_eq!(
+            format!("{}", ll),
+            "9 -> 8 -> 7 -> 6 -> 5 -> 4 -> 3 -> 2 -> 1 -> 0",
+            "Unrolled Linked List is invalid after front pushes!"
+        );
+        assert_blocks(&ll);
+    }
 
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "20 -> 15 -> 10 -> 5".to_string(),
-        //     "Linked List is invalid after first index deletion!"
-        // );
-        // assert_eq!(
-        //     ll.head_as_ref().unwrap().data,
-        //     20,
-        //     "Linked List has invalid Head after first index deletion!"
-        // );
-        //
-        // ll.delete(3);
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "20 -> 15 -> 10".to_string(),
-        //     "Linked List is invalid after last index deletion!"
-        // );
-        // assert_eq!(ll.length, 3, "Linked List has invalid length after delete!");
-        // assert_eq!(
-        //     ll.tail_as_ref().unwrap().data,
-        //     10,
-        //     "Linked List has invalid Tail after last index deletion!"
-        // );
-        //
-        // ll.delete(2);
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "20 -> 15".to_string(),
-        //     "Linked List is invalid after middle index deletion!"
-        // );
-        // assert_eq!(ll.length, 2, "Linked List has invalid length after delete!");
-        // assert_eq!(
-        //     ll.tail_as_ref().unwrap().data,
-        //     15,
-        //     "Linked List has invalid Tail after middle deletion!"
-        // );
-        //
-        // ll.delete(1);
-        // ll.delete(0);
-        // assert_eq!(
-        //     format!("{}", ll),
-        //     "None".to_string(),
-        //     "Linked List is invalid after all indices deletion!"
-        // );
-        // assert_eq!(ll.length, 0, "Linked List has invalid length after delete!");
-        // assert_eq!(
-        //     ll.head_as_ref().is_none(),
-        //     true,
-        //     "Linked List has invalid Head for empty List!"
-        // );
-        // assert_eq!(
-        //     ll.tail_as_ref().is_none(),
-        //     true,
-        //     "Linked List has invalid Tail for empty List!"
-        // );
+    #[test]
+    fn test_insert_and_remove() {
+        let mut rng = thread_rng();
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(5);
+        let mut expected: Vec<u32> = Vec::new();
+        for _i in 0..2000 {
+            if expected.is_empty() || rng.gen_bool(0.6) {
+                let index = rng.gen_range(0..=expected.len());
+                let value = rng.gen();
+                ll.insert(index, value);
+                expected.insert(index, value);
+            } else {
+                let index = rng.gen_range(0..expected.len());
+                assert_eq!(
+                    ll.remove(index),
+                    expected.remove(index),
+                    "Unrolled Linked List removed an invalid element!"
+                );
+            }
+            assert_blocks(&ll);
+        }
+        assert_eq!(to_vec(&ll), expected, "Unrolled Linked List is invalid!");
     }
-    //
-    // #[test]
-    // #[should_panic(expected = "Index out of bounds!")]
-    // fn test_delete_panic() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.delete(0);
-    // }
 
-    // #[test]
-    // fn test_swap() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.push_front(1);
-    //     ll.push_front(2);
-    //     ll.push_front(3);
-    //     ll.push_front(4);
-    //
-    //     assert_eq!(
-    //         ll.swap(5, 10).unwrap_err(),
-    //         "Index out of bounds!",
-    //         "Linked List invalid indices exception failed!"
-    //     );
-    //
-    //     ll.swap(1, 2).expect("Failed to swap Nodes");
-    //     assert_eq!(
-    //         format!("{}", ll),
-    //         "4 -> 2 -> 3 -> 1".to_string(),
-    //         "Linked List is invalid after swapping middle Nodes!"
-    //     );
-    //
-    //     ll.swap(2, 3).expect("Failed to swap Nodes");
-    //     assert_eq!(
-    //         format!("{}", ll),
-    //         "4 -> 2 -> 1 -> 3".to_string(),
-    //         "Linked List is invalid after swapping last Nodes!"
-    //     );
-    //
-    //     ll.swap(0, 1).expect("Failed to swap Nodes");
-    //     assert_eq!(
-    //         format!("{}", ll),
-    //         "2 -> 4 -> 1 -> 3".to_string(),
-    //         "Linked List is invalid after swapping initial Nodes!"
-    //     );
-    // }
+    #[test]
+    fn test_remove_middle_block() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(2);
+        for value in 1..=6 {
+            ll.push_back(value);
+        }
+        ll.remove(2);
+        ll.remove(2);
+        assert_blocks(&ll);
+        assert_eq!(
+            to_vec(&ll),
+            vec![1, 2, 5, 6],
+            "Empty middle block must be unlinked!"
+        );
+        ll.remove(3);
+        ll.remove(2);
+        assert_blocks(&ll);
+        assert_eq!(
+            to_vec(&ll),
+            vec![1, 2],
+            "Empty tail block must be unlinked!"
+        );
+    }
 
     #[test]
-    fn test_len() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(5);
-        ll.push_front(10);
-        assert_eq!(ll.len(), 2, "Doubly Linked List invalid nodes length!");
-        ll.pop_front();
-        assert_eq!(ll.len(), 1, "Doubly Linked List invalid nodes length!")
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_insert_panic() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::new();
+        ll.insert(1, 1);
     }
 
     #[test]
-    fn test_peek() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(5);
-        ll.push_front(10);
+    fn test_pop() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(3);
+        for i in 0..7 {
+            ll.push_back(i);
+        }
         assert_eq!(
-            ll.peek(0),
-            &10u8,
-            "Doubly Linked List invalid value at 0 index!"
+            ll.pop_front(),
+            Some(0),
+            "Unrolled Linked List pop front is invalid!"
         );
         assert_eq!(
-            ll.peek(1),
-            &5u8,
-            "Doubly Linked List invalid value at 1 index!"
+            ll.pop_back(),
+            Some(6),
+            "Unrolled Linked List pop back is invalid!"
+        );
+        assert_blocks(&ll);
+        for i in 1..6 {
+            assert_eq!(
+                ll.pop_front(),
+                Some(i),
+                "Unrolled Linked List pop front is invalid!"
+            );
+        }
+        assert_eq!(
+            ll.pop_back(),
+            None,
+            "Empty Unrolled Linked List must return None!"
+        );
+        assert!(
+            ll.tail.is_none(),
+            "Empty Unrolled Linked List must not have a Tail!"
+        );
+        ll.push_back(9);
+        assert_eq!(
+            ll.peek(0),
+            &9,
+            "Unrolled Linked List must be reusable after emptying!"
         );
     }
 
+    #[test]
+    fn test_peek() {
+        let mut ll: UnrolledLinkedList<u32> = UnrolledLinkedList::with_block_capacity(4);
+        for i in 0..10 {
+            ll.push_back(i);
+        }
+        for i in 0..10 {
+            assert_eq!(
+                ll.peek(i),
+                &(i as u32),
+                "Unrolled Linked List peek is invalid!"
+            );
+        }
+        *ll.peek_mut(5) = 50;
+        assert_eq!(ll.peek(5), &50, "Unrolled Linked List peek mut is invalid!");
+        assert_eq!(ll.len(), 10, "Peek mut must not change the length!");
+    }
+
     #[test]
     #[should_panic(expected = "Index out of bounds!")]
     fn test_peek_panic() {
-        let ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
+        let ll: UnrolledLinkedList<u32> = UnrolledLinkedList::new();
         ll.peek(0);
     }
+}
+
+#[cfg(test)]
+mod xor_linked_list {
+    use crate::structs::linked_lists::{LinkedListADT, XorLinkedList};
+    use rand::{thread_rng, Rng};
+    use std::collections::VecDeque;
 
     #[test]
-    fn test_peek_mut() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.push_front(10);
+    fn test_new() {
+        let ll: XorLinkedList<u32> = XorLinkedList::new();
+        assert!(
+            ll.head.is_null(),
+            "XOR Linked List has invalid initial Head!"
+        );
+        assert!(
+            ll.tail.is_null(),
+            "XOR Linked List has invalid initial Tail!"
+        );
+        assert_eq!(ll.len(), 0, "XOR Linked List has invalid initial length!");
         assert_eq!(
-            ll.peek_mut(0),
-            &mut 10u8,
-            "Doubly Linked List invalid head value!"
+            format!("{}", ll),
+            "None",
+            "Empty XOR Linked List is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_push() {
+        let mut ll: XorLinkedList<u32> = XorLinkedList::new();
+        ll.push_back(2);
+        ll.push_front(1);
+        ll.push_back(3);
+        assert_eq!(
+            format!("{}", ll),
+            "1 -> 2 -> 3",
+            "XOR Linked List is invalid!"
+        );
+        assert_eq!(
+            ll.iter_rev().copied().collect::<Vec<u32>>(),
+            vec![3, 2, 1],
+            "XOR Linked List is invalid when walked backwards!"
+        );
+    }
+
+    #[test]
+    fn test_pop() {
+        let mut rng = thread_rng();
+        let mut ll: XorLinkedList<u32> = XorLinkedList::new();
+        let mut expected: VecDeque<u32> = VecDeque::new();
+        for _i in 0..1000 {
+            let value = rng.gen();
+            match rng.gen_range(0..4) {
+                0 => {
+                    ll.push_front(value);
+                    expected.push_front(value);
+                }
+                1 => {
+                    ll.push_back(value);
+                    expected.push_back(value);
+                }
+                2 => assert_eq!(
+                    ll.pop_front(),
+                    expected.pop_front(),
+                    "XOR Linked List pop front is invalid!"
+                ),
+                _ => assert_eq!(
+                    ll.pop_back(),
+                    expected.pop_back(),
+                    "XOR Linked List pop back is invalid!"
+                ),
+            }
+            assert_eq!(
+                ll.len(),
+                expected.len(),
+                "XOR Linked List has invalid length!"
+            );
+        }
+        assert_eq!(
+            ll.iter().copied().collect::<Vec<u32>>(),
+            expected.into_iter().collect::<Vec<u32>>(),
+            "XOR Linked List is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut ll: XorLinkedList<u32> = XorLinkedList::new();
+        for i in 1..=4 {
+            ll.push_back(i);
+        }
+        ll.reverse();
+        assert_eq!(
+            format!("{}", ll),
+            "4 -> 3 -> 2 -> 1",
+            "XOR Linked List is invalid after reverse!"
+        );
+        ll.push_back(0);
+        assert_eq!(
+            ll.pop_front(),
+            Some(4),
+            "XOR Linked List has invalid Head after reverse!"
         );
+        assert_eq!(
+            format!("{}", ll),
+            "3 -> 2 -> 1 -> 0",
+            "XOR Linked List is invalid after reverse!"
+        );
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut ll: XorLinkedList<u32> = XorLinkedList::new();
+        for i in 0..9 {
+            ll.push_back(i);
+        }
+        for i in 0..9 {
+            assert_eq!(ll.peek(i), &(i as u32), "XOR Linked List peek is invalid!");
+        }
+        *ll.peek_mut(7) = 70;
+        assert_eq!(ll.peek(7), &70, "XOR Linked List peek mut is invalid!");
     }
 
     #[test]
     #[should_panic(expected = "Index out of bounds!")]
-    fn test_peek_mut_panic() {
-        let mut ll: DoublyLinkedList<u8> = DoublyLinkedList::new();
-        ll.peek_mut(0);
+    fn test_peek_panic() {
+        let ll: XorLinkedList<u32> = XorLinkedList::new();
+        ll.peek(0);
     }
 
-    // #[test]
-    // fn test_sum() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     assert_eq!(ll.sum(), 15, "Invalid Doubly Linked List sum!");
-    // }
-    //
-    // #[test]
-    // fn test_max() {
-    //     let mut ll: LinkedList<i8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     ll.push_front(2);
-    //     ll.push_front(-5);
-    //     assert_eq!(ll.max(), 10, "Invalid Doubly Linked List max value!");
-    // }
-    //
-    // #[test]
-    // fn test_min() {
-    //     let mut ll: LinkedList<i8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     ll.push_front(2);
-    //     ll.push_front(-5);
-    //     assert_eq!(ll.min(), -5, "Invalid Doubly Linked List min value!");
-    // }
-    //
-    // #[test]
-    // fn test_linear_search() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     ll.push_front(2);
-    //     assert_eq!(
-    //         ll.linear_search(10),
-    //         ll.head_as_ref().as_ref().unwrap().next.as_ref(),
-    //         "Invalid Linked List node for searchable value!"
-    //     );
-    //     assert_eq!(
-    //         ll.linear_search(11),
-    //         None,
-    //         "Invalid Linked List node for unsearchable value!"
-    //     );
-    // }
-    //
-    // #[test]
-    // fn test_move_to_head_search() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     ll.push_front(2);
-    //
-    //     let node_1 = ll.linear_search(5).unwrap().clone();
-    //     let node_2 = ll.linear_search(10).unwrap().clone();
-    //     let node_3 = ll.linear_search(2).unwrap().clone();
-    //     // println!("Node 1 (5) = {:?}", node_1);
-    //     // println!("Node 2 (10) = {:?}", node_2);
-    //     // println!("Node 3 (2) = {:?}", node_3);
-    //
-    //     // Original List: 2 -> 10 -> 5
-    //     // Moved List: 10 -> 2 -> 5
-    //
-    //     // Try moving some in between Node
-    //     assert_eq!(
-    //         *ll.move_to_head_search(10).unwrap(),
-    //         node_2,
-    //         "Failed to return the found Node!"
-    //     );
-    //     assert_eq!(
-    //         ll.head_previous_as_ref().is_none(),
-    //         true,
-    //         "Moved Node has invalid previous Node set!"
-    //     );
-    //     assert_eq!(
-    //         *ll.head_next_as_ref().unwrap(),
-    //         node_3,
-    //         "Moved Node has invalid next Node set!"
-    //     );
-    //     assert_eq!(
-    //         *node_3.next_as_ref().unwrap(),
-    //         node_1,
-    //         "Old previous Node has invalid adjusted next Node set!"
-    //     );
-    //     assert_eq!(
-    //         *node_1.previous_as_ref().unwrap(),
-    //         node_3,
-    //         "Old next Node has invalid adjusted previous Node set!"
-    //     );
-    //
-    //     // Try moving the already moved Node or head Node.
-    //     assert_eq!(
-    //         *ll.move_to_head_search(10).unwrap(),
-    //         node_2,
-    //         "Failed to return the found Node!"
-    //     );
-    //     assert_eq!(
-    //         ll.head_previous_as_ref().is_none(),
-    //         true,
-    //         "Unmoved Node has invalid previous Node set!"
-    //     );
-    //     assert_eq!(
-    //         *ll.head_next_as_ref().unwrap(),
-    //         node_3,
-    //         "Unmoved Node has invalid next Node set!"
-    //     );
-    //     println!("{}", ll);
-    //     // Try moving the last Node
-    //     println!("F Node = {:?}", node_2.next_as_ref().unwrap());
-    //     assert_eq!(
-    //         *ll.move_to_head_search(5).unwrap(),
-    //         node_1,
-    //         "Failed to return the found last Node!"
-    //     );
-    //     assert_eq!(
-    //         node_3.next_as_ref().is_none(),
-    //         true,
-    //         "Last moved Node has invalid next Node set!"
-    //     );
-    // }
-    //
-    // #[test]
-    // fn test_display_trait() {
-    //     let mut ll: LinkedList<u8> = LinkedList::new();
-    //     ll.push_front(5);
-    //     ll.push_front(10);
-    //     assert_eq!(
-    //         format!("{}", ll),
-    //         format!("10 -> 5"),
-    //         "Linked List has invalid Display trait!"
-    //     );
-    // }
-    //
-    // #[test]
-    // fn test_from_trait_heap_array() {
-    //     let arr: HeapArray<u8> = HeapArray::values(&[1, 2, 3]);
-    //     let ll: LinkedList<u8> = LinkedList::from(arr);
-    //     assert_eq!(
-    //         format!("{}", ll),
-    //         "1 -> 2 -> 3".to_string(),
-    //         "Linked List is invalid after conversion from Heap Array!"
-    //     );
-    // }
+    #[test]
+    fn test_drop() {
+        let mut ll: XorLinkedList<String> = XorLinkedList::new();
+        for i in 0..100 {
+            ll.push_back(i.to_string());
+        }
+        assert_eq!(ll.peek(50), "50", "XOR Linked List peek is invalid!");
+        drop(ll);
+    }
+}
+
+// Compares how fast the lists can be walked. Run with `cargo test linked_list_benchmark -- --ignored --nocapture`.
+#[cfg(test)]
+mod linked_list_benchmark {
+    use crate::structs::linked_lists::{
+        DoublyLinkedList, LinkedListADT, NodeListADT, SinglyLinkedList, UnrolledLinkedList,
+        XorLinkedList,
+    };
+    use std::time::Instant;
+
+    const ELEMENTS: u64 = 20_000;
+    const ROUNDS: usize = 100;
+
+    fn report(name: &str, start: Instant, sum: u64) {
+        assert_eq!(
+            sum,
+            ROUNDS as u64 * ELEMENTS * (ELEMENTS - 1) / 2,
+            "{} has an invalid sum!",
+            name
+        );
+        println!("{:<24} {:?}", name, start.elapsed());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_traversal() {
+        let mut singly: SinglyLinkedList<u64> = SinglyLinkedList::new();
+        let mut doubly: DoublyLinkedList<u64> = DoublyLinkedList::new();
+        let mut unrolled: UnrolledLinkedList<u64> = UnrolledLinkedList::with_block_capacity(64);
+        let mut xor: XorLinkedList<u64> = XorLinkedList::new();
+        for i in (0..ELEMENTS).rev() {
+            singly.push_front(i);
+            doubly.push_front(i);
+        }
+        for i in 0..ELEMENTS {
+            unrolled.push_back(i);
+            xor.push_back(i);
+        }
+
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _i in 0..ROUNDS {
+            sum += singly.iter().sum::<u64>();
+        }
+        report("Singly Linked List", start, sum);
+
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _i in 0..ROUNDS {
+            let mut current = doubly.head_as_ref();
+            while let Some(node) = current {
+                sum += node.data_as_ref();
+                current = node.next_as_ref();
+            }
+        }
+        report("Doubly Linked List", start, sum);
+
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _i in 0..ROUNDS {
+            sum += unrolled.iter().sum::<u64>();
+        }
+        report("Unrolled Linked List", start, sum);
+
+        let start = Instant::now();
+        let mut sum: u64 = 0;
+        for _i in 0..ROUNDS {
+            sum += xor.iter().sum::<u64>();
+        }
+        report("XOR Linked List", start, sum);
+    }
 }
@@ -1,9 +1,28 @@
 use crate::structs::arrays::HeapArray;
 use crate::structs::matrices::MatrixType::{
-    Dense, Diagonal, LowerTriangular, Sparse, Toeplitz, Tridiagonal, UpperTriangular,
+    Banded, BlockDiagonal, Dense, Diagonal, LowerTriangular, Sparse, Symmetric, Toeplitz,
+    Tridiagonal, UpperTriangular,
 };
+use crate::structs::smart_ptrs::HeapBox;
+use num::Num;
+use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
+use std::io;
+use std::io::{BufRead, Lines, Write};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Range, Sub};
+use std::str::FromStr;
+
+/*
+NOTE:
+Matrix elements are numbers with a zero and a one, which covers i32, i64, f32, f64, num::Complex and num::Rational.
+Zero is the value every representation leaves out, e.g. the elements outside of a triangle or a Sparse Matrix, and
+the arithmetic only needs the operators which num::Num brings along.
+*/
+pub trait Numeric: Num + Copy {}
+
+impl<T: Num + Copy> Numeric for T {}
 
 enum MatrixType<T>
 where
@@ -16,6 +35,506 @@ where
     Toeplitz(ToeplitzMatrix<T>),
     Tridiagonal(TridiagonalMatrix<T>),
     Sparse(SparseMatrix<T>),
+    Symmetric(SymmetricMatrix<T>),
+    Banded(BandedMatrix<T>),
+    BlockDiagonal(BlockDiagonalMatrix<T>),
+}
+
+impl<T: PartialEq> MatrixType<T> {
+    fn empty(kind: MatrixKind, rows: usize, columns: usize) -> Self
+    where
+        T: Numeric,
+    {
+        match kind {
+            MatrixKind::Dense => Dense(DenseMatrix::new(rows, columns)),
+            MatrixKind::Diagonal => Diagonal(DiagonalMatrix::new(rows)),
+            MatrixKind::UpperTriangular => UpperTriangular(UpperTraingularMatrix::new(rows)),
+            MatrixKind::LowerTriangular => LowerTriangular(LowerTriangularMatrix::new(rows)),
+            MatrixKind::Toeplitz => Toeplitz(ToeplitzMatrix::new(rows)),
+            MatrixKind::Tridiagonal => Tridiagonal(TridiagonalMatrix::new(rows)),
+            MatrixKind::Sparse => Sparse(SparseMatrix::new(rows, columns)),
+            MatrixKind::Symmetric => Symmetric(SymmetricMatrix::new(rows)),
+            MatrixKind::Banded { lower, upper } => Banded(BandedMatrix::new(rows, lower, upper)),
+            MatrixKind::BlockDiagonal { size } => {
+                BlockDiagonal(BlockDiagonalMatrix::new(rows, size))
+            }
+        }
+    }
+
+    // The elements must be sorted in row major order and fit the kind. A Sparse Matrix only keeps room for them.
+    fn from_sorted(
+        kind: MatrixKind,
+        rows: usize,
+        columns: usize,
+        elements: &[(usize, usize, T)],
+    ) -> Self
+    where
+        T: Numeric,
+    {
+        match kind {
+            MatrixKind::Sparse => Sparse(SparseMatrix::from_sorted(rows, columns, elements)),
+            kind => {
+                let mut matrix_type = Self::empty(kind, rows, columns);
+                for &(row, col, val) in elements {
+                    matrix_type.set(row, col, val);
+                }
+                matrix_type
+            }
+        }
+    }
+
+    fn kind(&self) -> MatrixKind {
+        match self {
+            Dense(_) => MatrixKind::Dense,
+            Diagonal(_) => MatrixKind::Diagonal,
+            UpperTriangular(_) => MatrixKind::UpperTriangular,
+            LowerTriangular(_) => MatrixKind::LowerTriangular,
+            Toeplitz(_) => MatrixKind::Toeplitz,
+            Tridiagonal(_) => MatrixKind::Tridiagonal,
+            Sparse(_) => MatrixKind::Sparse,
+            Symmetric(_) => MatrixKind::Symmetric,
+            Banded(matrix) => MatrixKind::Banded {
+                lower: matrix.lower,
+                upper: matrix.upper,
+            },
+            BlockDiagonal(matrix) => MatrixKind::BlockDiagonal {
+                size: matrix.block_size,
+            },
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        match self {
+            Dense(matrix) => matrix.get(row, col),
+            UpperTriangular(matrix) => matrix.get(row, col),
+            LowerTriangular(matrix) => matrix.get(row, col),
+            Diagonal(matrix) => matrix.get(row, col),
+            Toeplitz(matrix) => matrix.get(row, col),
+            Tridiagonal(matrix) => matrix.get(row, col),
+            Sparse(matrix) => matrix.get(row, col),
+            Symmetric(matrix) => matrix.get(row, col),
+            Banded(matrix) => matrix.get(row, col),
+            BlockDiagonal(matrix) => matrix.get(row, col),
+        }
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        match self {
+            Dense(matrix) => matrix.set(row, col, val),
+            UpperTriangular(matrix) => matrix.set(row, col, val),
+            LowerTriangular(matrix) => matrix.set(row, col, val),
+            Diagonal(matrix) => matrix.set(row, col, val),
+            Toeplitz(matrix) => matrix.set(row, col, val),
+            Tridiagonal(matrix) => matrix.set(row, col, val),
+            Sparse(matrix) => matrix.set(row, col, val),
+            Symmetric(matrix) => matrix.set(row, col, val),
+            Banded(matrix) => matrix.set(row, col, val),
+            BlockDiagonal(matrix) => matrix.set(row, col, val),
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        match self {
+            Dense(matrix) => matrix.get_mut(row, col),
+            UpperTriangular(matrix) => matrix.get_mut(row, col),
+            LowerTriangular(matrix) => matrix.get_mut(row, col),
+            Diagonal(matrix) => matrix.get_mut(row, col),
+            Toeplitz(matrix) => matrix.get_mut(row, col),
+            Tridiagonal(matrix) => matrix.get_mut(row, col),
+            Sparse(matrix) => matrix.get_mut(row, col),
+            Symmetric(matrix) => matrix.get_mut(row, col),
+            Banded(matrix) => matrix.get_mut(row, col),
+            BlockDiagonal(matrix) => matrix.get_mut(row, col),
+        }
+    }
+
+    // Checks if setting the value changes exactly the element at the row and column.
+    fn can_hold(&self, row: usize, col: usize, val: &T) -> bool
+    where
+        T: Numeric,
+    {
+        match self {
+            Dense(_) => true,
+            Diagonal(_) => row == col || val.is_zero(),
+            UpperTriangular(_) => row <= col || val.is_zero(),
+            LowerTriangular(_) => row >= col || val.is_zero(),
+            Tridiagonal(_) => row.abs_diff(col) <= 1 || val.is_zero(),
+            // Every element of a diagonal shares one value, so only the corners can change on their own.
+            Toeplitz(matrix) => {
+                val == matrix.get(row, col) || row.abs_diff(col) + 1 == matrix.dimension
+            }
+            Sparse(matrix) => {
+                val.is_zero()
+                    || matrix.position(row, col).is_some()
+                    || matrix.nonzero_count
+                        < SparseMatrix::<T>::array_size(matrix.rows, matrix.columns)
+            }
+            // Both triangles share the value, so only the diagonal changes on its own.
+            Symmetric(matrix) => row == col || val == matrix.get(row, col),
+            Banded(matrix) => matrix.in_band(row, col) || val.is_zero(),
+            BlockDiagonal(matrix) => matrix.in_block(row, col) || val.is_zero(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixKind {
+    Dense,
+    Diagonal,
+    UpperTriangular,
+    LowerTriangular,
+    Toeplitz,
+    Tridiagonal,
+    Sparse,
+    Symmetric,
+    // Number of diagonals below and above the main one.
+    Banded { lower: usize, upper: usize },
+    // Square blocks along the diagonal, the last one takes the remaining rows when they don't divide evenly.
+    BlockDiagonal { size: usize },
+}
+
+impl MatrixKind {
+    fn is_square(&self) -> bool {
+        !matches!(self, MatrixKind::Dense | MatrixKind::Sparse)
+    }
+
+    // Positions a representation of the kind stores in row major order. A Toeplitz Matrix only needs its first row
+    // and column, while the non-zero elements of a Sparse Matrix can be anywhere.
+    fn positions(&self, rows: usize, columns: usize) -> Vec<(usize, usize)> {
+        let n = rows;
+        match *self {
+            MatrixKind::Dense | MatrixKind::Sparse | MatrixKind::Symmetric => (0..rows)
+                .flat_map(|i| (0..columns).map(move |j| (i, j)))
+                .collect(),
+            MatrixKind::Diagonal => (0..n).map(|i| (i, i)).collect(),
+            MatrixKind::UpperTriangular => {
+                (0..n).flat_map(|i| (i..n).map(move |j| (i, j))).collect()
+            }
+            MatrixKind::LowerTriangular => (0..n)
+                .flat_map(|i| (0..i + 1).map(move |j| (i, j)))
+                .collect(),
+            MatrixKind::Tridiagonal => (0..n)
+                .flat_map(|i| (i.saturating_sub(1)..(i + 2).min(n)).map(move |j| (i, j)))
+                .collect(),
+            MatrixKind::Toeplitz => (0..n)
+                .flat_map(|i| {
+                    let end = if i == 0 { n } else { 1 };
+                    (0..end).map(move |j| (i, j))
+                })
+                .collect(),
+            MatrixKind::Banded { lower, upper } => (0..n)
+                .flat_map(|i| {
+                    (i.saturating_sub(lower)..(i + upper + 1).min(n)).map(move |j| (i, j))
+                })
+                .collect(),
+            MatrixKind::BlockDiagonal { size } => (0..n)
+                .flat_map(|i| {
+                    let start = i / size * size;
+                    (start..(start + size).min(n)).map(move |j| (i, j))
+                })
+                .collect(),
+        }
+    }
+
+    fn transposed(&self) -> MatrixKind {
+        match self {
+            MatrixKind::UpperTriangular => MatrixKind::LowerTriangular,
+            MatrixKind::LowerTriangular => MatrixKind::UpperTriangular,
+            MatrixKind::Banded { lower, upper } => MatrixKind::Banded {
+                lower: *upper,
+                upper: *lower,
+            },
+            kind => *kind,
+        }
+    }
+
+    // Diagonals below and above the main one which a kind can hold, if it is a band.
+    fn bandwidths(&self) -> Option<(usize, usize)> {
+        match self {
+            MatrixKind::Diagonal => Some((0, 0)),
+            MatrixKind::Tridiagonal => Some((1, 1)),
+            MatrixKind::Banded { lower, upper } => Some((*lower, *upper)),
+            _ => None,
+        }
+    }
+
+    // Kind of a sum, which is zero wherever both matrices are zero.
+    fn sum(&self, other: MatrixKind) -> MatrixKind {
+        match (*self, other) {
+            (a, b) if a == b => a,
+            (MatrixKind::Banded { .. }, kind) | (kind, MatrixKind::Banded { .. })
+                if kind.bandwidths().is_some() =>
+            {
+                let (a, b) = (self.bandwidths().unwrap(), other.bandwidths().unwrap());
+                MatrixKind::Banded {
+                    lower: a.0.max(b.0),
+                    upper: a.1.max(b.1),
+                }
+            }
+            (MatrixKind::Diagonal, kind) | (kind, MatrixKind::Diagonal)
+                if matches!(
+                    kind,
+                    MatrixKind::UpperTriangular
+                        | MatrixKind::LowerTriangular
+                        | MatrixKind::Tridiagonal
+                ) =>
+            {
+                kind
+            }
+            (MatrixKind::Diagonal, kind) | (kind, MatrixKind::Diagonal)
+                if matches!(
+                    kind,
+                    MatrixKind::Symmetric | MatrixKind::BlockDiagonal { .. }
+                ) =>
+            {
+                kind
+            }
+            _ => MatrixKind::Dense,
+        }
+    }
+
+    // Kind of an element-wise product, which is zero wherever one of the matrices is zero.
+    fn product(&self, other: MatrixKind) -> MatrixKind {
+        match (*self, other) {
+            (a, b) if a == b => a,
+            (MatrixKind::Diagonal, _) | (_, MatrixKind::Diagonal) => MatrixKind::Diagonal,
+            (MatrixKind::Sparse, _) | (_, MatrixKind::Sparse) => MatrixKind::Sparse,
+            (MatrixKind::Banded { .. }, kind) | (kind, MatrixKind::Banded { .. })
+                if kind.bandwidths().is_some() =>
+            {
+                let (a, b) = (self.bandwidths().unwrap(), other.bandwidths().unwrap());
+                MatrixKind::Banded {
+                    lower: a.0.min(b.0),
+                    upper: a.1.min(b.1),
+                }
+            }
+            (MatrixKind::UpperTriangular, MatrixKind::LowerTriangular)
+            | (MatrixKind::LowerTriangular, MatrixKind::UpperTriangular) => MatrixKind::Diagonal,
+            _ => MatrixKind::Dense,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixError {
+    NotSquare(MatrixKind),
+    DoesNotFit(MatrixKind),
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    // Column without a pivot.
+    Singular(usize),
+    IndexOutOfBounds {
+        index: (usize, usize),
+        dimensions: (usize, usize),
+    },
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::NotSquare(kind) => write!(f, "{:?} Matrix must be square!", kind),
+            MatrixError::DoesNotFit(kind) => {
+                write!(f, "Matrix values don't fit a {:?} Matrix!", kind)
+            }
+            MatrixError::DimensionMismatch { expected, found } => write!(
+                f,
+                "Expected a {}x{} Matrix, found a {}x{} Matrix!",
+                expected.0, expected.1, found.0, found.1
+            ),
+            MatrixError::Singular(column) => {
+                write!(f, "Matrix is singular, column {} has no pivot!", column)
+            }
+            MatrixError::IndexOutOfBounds { index, dimensions } => write!(
+                f,
+                "Index ({}, {}) is out of bounds for a {}x{} Matrix!",
+                index.0, index.1, dimensions.0, dimensions.1
+            ),
+        }
+    }
+}
+
+impl Error for MatrixError {}
+
+// Shape of the values of a Matrix, collected in a single pass over all elements.
+struct Structure {
+    rows: usize,
+    columns: usize,
+    nonzero: usize,
+    diagonal: bool,
+    upper_triangular: bool,
+    lower_triangular: bool,
+    tridiagonal: bool,
+    toeplitz: bool,
+    symmetric: bool,
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    // Number of non-zero elements whose row and column lie on both sides of the cut before each index.
+    crossings: Vec<usize>,
+    // Number of non-zero elements which end before each cut, only needed until finish.
+    ends: Vec<usize>,
+}
+
+impl Structure {
+    fn new(rows: usize, columns: usize) -> Self {
+        let n = rows.max(columns);
+        Structure {
+            rows,
+            columns,
+            nonzero: 0,
+            diagonal: true,
+            upper_triangular: true,
+            lower_triangular: true,
+            tridiagonal: true,
+            toeplitz: true,
+            symmetric: rows == columns,
+            lower_bandwidth: 0,
+            upper_bandwidth: 0,
+            crossings: vec![0; n + 1],
+            ends: vec![0; n + 1],
+        }
+    }
+
+    // An element crosses the cuts after its smaller index up to its larger one, counted like a difference array.
+    fn add(&mut self, i: usize, j: usize) {
+        self.nonzero += 1;
+        self.diagonal &= i == j;
+        self.upper_triangular &= i <= j;
+        self.lower_triangular &= i >= j;
+        self.tridiagonal &= i.abs_diff(j) <= 1;
+        self.lower_bandwidth = self.lower_bandwidth.max(i.saturating_sub(j));
+        self.upper_bandwidth = self.upper_bandwidth.max(j.saturating_sub(i));
+        self.crossings[i.min(j) + 1] += 1;
+        self.ends[i.max(j) + 1] += 1;
+    }
+
+    // Sums up the difference array once every non-zero element is added.
+    fn finish(mut self) -> Self {
+        let mut crossing = 0;
+        for (starts, ends) in self.crossings.iter_mut().zip(&self.ends) {
+            crossing = crossing + *starts - ends;
+            *starts = crossing;
+        }
+        self
+    }
+
+    /*
+    Structure of non-zero elements sorted in row major order, without a Matrix to scan. Every diagonal of a Toeplitz
+    Matrix is either empty or full of one value and every element of a Symmetric Matrix finds its mirror with a Binary
+    Search. Time Complexity is O(k log k + n) for k elements.
+    */
+    fn from_elements<T: Numeric>(
+        rows: usize,
+        columns: usize,
+        elements: &[(usize, usize, T)],
+    ) -> Self {
+        let mut structure = Structure::new(rows, columns);
+        // The first value and the element count of every diagonal, the main one sits at the index columns.
+        let mut diagonals: Vec<Option<(T, usize)>> = vec![None; rows + columns];
+        for &(i, j, val) in elements {
+            structure.add(i, j);
+            match &mut diagonals[i + columns - j] {
+                Some((first, count)) => {
+                    structure.toeplitz &= *first == val;
+                    *count += 1;
+                }
+                diagonal => *diagonal = Some((val, 1)),
+            }
+            if structure.symmetric && i != j {
+                let mirror = elements.binary_search_by_key(&(j, i), |&(row, col, _)| (row, col));
+                structure.symmetric = mirror.is_ok_and(|index| elements[index].2 == val);
+            }
+        }
+        structure.toeplitz &= diagonals.iter().enumerate().all(|(index, diagonal)| {
+            diagonal.is_none_or(|(_, count)| count == rows.saturating_sub(index.abs_diff(columns)))
+        });
+        structure.finish()
+    }
+
+    // The kind which fits the values with the least storage.
+    fn best(&self) -> MatrixKind {
+        self.candidates()
+            .into_iter()
+            .filter(|kind| self.fits(*kind))
+            .min_by_key(|kind| self.storage(*kind))
+            .unwrap_or(MatrixKind::Dense)
+    }
+
+    // Kinds in the order optimize prefers them when they need the same storage. The band and the blocks are the
+    // narrowest ones which fit the values.
+    fn candidates(&self) -> [MatrixKind; 10] {
+        [
+            MatrixKind::Diagonal,
+            MatrixKind::Toeplitz,
+            MatrixKind::Tridiagonal,
+            MatrixKind::UpperTriangular,
+            MatrixKind::LowerTriangular,
+            MatrixKind::Symmetric,
+            MatrixKind::Sparse,
+            MatrixKind::Dense,
+            MatrixKind::Banded {
+                lower: self.lower_bandwidth,
+                upper: self.upper_bandwidth,
+            },
+            MatrixKind::BlockDiagonal {
+                size: self.block_size(),
+            },
+        ]
+    }
+
+    // Blocks fit when no non-zero element crosses the cut after a block.
+    fn fits_blocks(&self, size: usize) -> bool {
+        size > 0
+            && (size..self.rows)
+                .step_by(size)
+                .all(|cut| self.crossings[cut] == 0)
+    }
+
+    // Time Complexity is O(n log n) as a block size of b checks n / b cuts.
+    fn block_size(&self) -> usize {
+        (1..self.rows)
+            .find(|size| self.fits_blocks(*size))
+            .unwrap_or(self.rows.max(1))
+    }
+
+    fn fits(&self, kind: MatrixKind) -> bool {
+        if kind.is_square() && self.rows != self.columns {
+            return false;
+        }
+        match kind {
+            MatrixKind::Dense => true,
+            MatrixKind::Diagonal => self.diagonal,
+            MatrixKind::UpperTriangular => self.upper_triangular,
+            MatrixKind::LowerTriangular => self.lower_triangular,
+            MatrixKind::Toeplitz => self.toeplitz,
+            MatrixKind::Tridiagonal => self.tridiagonal,
+            MatrixKind::Sparse => {
+                self.nonzero <= SparseMatrix::<u8>::array_size(self.rows, self.columns)
+            }
+            MatrixKind::Symmetric => self.symmetric,
+            MatrixKind::Banded { lower, upper } => {
+                self.lower_bandwidth <= lower && self.upper_bandwidth <= upper
+            }
+            MatrixKind::BlockDiagonal { size } => self.fits_blocks(size),
+        }
+    }
+
+    // Number of stored values. A Sparse Matrix stores a row, a column and a value for every non-zero element.
+    fn storage(&self, kind: MatrixKind) -> usize {
+        let n = self.rows;
+        match kind {
+            MatrixKind::Dense => self.rows * self.columns,
+            MatrixKind::Diagonal => n,
+            MatrixKind::UpperTriangular | MatrixKind::LowerTriangular => n * (n + 1) / 2,
+            MatrixKind::Toeplitz => (2 * n).saturating_sub(1),
+            MatrixKind::Tridiagonal => (3 * n).saturating_sub(2),
+            MatrixKind::Sparse => 3 * self.nonzero,
+            MatrixKind::Symmetric => n * (n + 1) / 2,
+            MatrixKind::Banded { lower, upper } => BandedMatrix::<u8>::band_size(n, lower, upper),
+            MatrixKind::BlockDiagonal { size } => BlockDiagonalMatrix::<u8>::blocks_size(n, size),
+        }
+    }
 }
 
 pub struct Matrix<T>
@@ -25,124 +544,189 @@ where
     rows: usize,
     columns: usize,
     matrix_type: MatrixType<T>,
-    default_value: T,
     auto_adjust: bool,
 }
 
 impl<T: PartialEq> Matrix<T> {
     pub fn new(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
         Matrix {
             rows: row,
             columns: col,
             matrix_type: Dense(DenseMatrix::new(row, col)),
-            default_value: T::default(),
             auto_adjust: false,
         }
     }
 
     pub fn new_diagonal(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        Matrix {
-            rows: row,
-            columns: col,
-            matrix_type: Diagonal(DiagonalMatrix::new(row)),
-            default_value: T::default(),
-            auto_adjust: false,
-        }
+        Self::with_kind(MatrixKind::Diagonal, row, col)
     }
 
     pub fn new_upper_triangular(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        Matrix {
-            rows: row,
-            columns: col,
-            matrix_type: UpperTriangular(UpperTraingularMatrix::new(row)),
-            default_value: T::default(),
-            auto_adjust: false,
-        }
+        Self::with_kind(MatrixKind::UpperTriangular, row, col)
     }
 
     pub fn new_lower_triangular(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        Matrix {
-            rows: row,
-            columns: col,
-            matrix_type: LowerTriangular(LowerTriangularMatrix::new(row)),
-            default_value: T::default(),
-            auto_adjust: false,
-        }
+        Self::with_kind(MatrixKind::LowerTriangular, row, col)
     }
 
     pub fn new_toeplitz(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        Matrix {
-            rows: row,
-            columns: col,
-            matrix_type: Toeplitz(ToeplitzMatrix::new(row)),
-            default_value: T::default(),
-            auto_adjust: false,
-        }
+        Self::with_kind(MatrixKind::Toeplitz, row, col)
     }
 
     pub fn new_tridiagonal(row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
+    {
+        Self::with_kind(MatrixKind::Tridiagonal, row, col)
+    }
+
+    pub fn new_sparse(row: usize, col: usize) -> Self
+    where
+        T: Numeric,
     {
         Matrix {
             rows: row,
             columns: col,
-            matrix_type: Tridiagonal(TridiagonalMatrix::new(row)),
-            default_value: T::default(),
+            matrix_type: Sparse(SparseMatrix::new(row, col)),
             auto_adjust: false,
         }
     }
 
-    pub fn new_sparse(row: usize, col: usize) -> Self
+    pub fn new_symmetric(row: usize, col: usize) -> Self
+    where
+        T: Numeric,
+    {
+        Self::with_kind(MatrixKind::Symmetric, row, col)
+    }
+
+    pub fn new_banded(row: usize, col: usize, lower: usize, upper: usize) -> Self
+    where
+        T: Numeric,
+    {
+        Self::with_kind(MatrixKind::Banded { lower, upper }, row, col)
+    }
+
+    pub fn new_block_diagonal(row: usize, col: usize, block_size: usize) -> Self
+    where
+        T: Numeric,
+    {
+        Self::with_kind(MatrixKind::BlockDiagonal { size: block_size }, row, col)
+    }
+
+    // The representation of a square kind only has rows x rows elements, so it panics when the sizes differ.
+    fn with_kind(kind: MatrixKind, row: usize, col: usize) -> Self
     where
-        T: Default + Copy,
+        T: Numeric,
     {
+        if kind.is_square() && row != col {
+            panic!("{}", MatrixError::NotSquare(kind));
+        }
         Matrix {
             rows: row,
             columns: col,
-            matrix_type: Sparse(SparseMatrix::new(row, col)),
-            default_value: T::default(),
+            matrix_type: MatrixType::empty(kind, row, col),
             auto_adjust: false,
         }
     }
 
-    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
-        match &self.matrix_type {
-            Dense(matrix) => Some(matrix.get(row, col)),
-            UpperTriangular(matrix) => Some(matrix.get(row, col)),
-            LowerTriangular(matrix) => Some(matrix.get(row, col)),
-            Diagonal(matrix) => Some(matrix.get(row, col)),
-            Toeplitz(matrix) => Some(matrix.get(row, col)),
-            Tridiagonal(matrix) => Some(matrix.get(row, col)),
-            Sparse(matrix) => Some(matrix.get(row, col)),
+    pub fn identity(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut matrix = Self::new_diagonal(dimension, dimension);
+        for i in 0..dimension {
+            matrix.set(i, i, T::one());
         }
+        matrix
     }
 
-    pub fn set(&mut self, row: usize, col: usize, val: T) {
-        match &mut self.matrix_type {
-            Dense(matrix) => matrix.set(row, col, val),
-            UpperTriangular(matrix) => matrix.set(row, col, val),
-            LowerTriangular(matrix) => matrix.set(row, col, val),
-            Diagonal(matrix) => matrix.set(row, col, val),
-            Toeplitz(matrix) => matrix.set(row, col, val),
-            Tridiagonal(matrix) => matrix.set(row, col, val),
-            Sparse(matrix) => matrix.set(row, col, val),
+    pub fn kind(&self) -> MatrixKind {
+        self.matrix_type.kind()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        self.rows == self.columns
+            && (0..self.rows)
+                .all(|i| (0..i).all(|j| self.matrix_type.get(i, j) == self.matrix_type.get(j, i)))
+    }
+
+    // With auto adjust on, every set picks the representation which fits the values with the least storage.
+    pub fn set_auto_adjust(&mut self, auto_adjust: bool) {
+        self.auto_adjust = auto_adjust;
+    }
+
+    fn check_bounds(&self, row: usize, col: usize) -> Result<(), MatrixError> {
+        check_bounds((row, col), (self.rows, self.columns))
+    }
+
+    // Rows and columns start at 0, the element is None when they are out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.try_get(row, col).ok()
+    }
+
+    pub fn try_get(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        self.check_bounds(row, col)?;
+        Ok(self.matrix_type.get(row, col))
+    }
+
+    // Panics when the row or column is out of bounds, try_set returns the error instead.
+    pub fn set(&mut self, row: usize, col: usize, val: T)
+    where
+        T: Numeric,
+    {
+        self.try_set(row, col, val)
+            .unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /*
+    NOTE:
+    Without auto adjust the value goes straight to the current representation, which ignores values outside of its
+    shape. With auto adjust the Matrix first moves to a Dense Matrix when the value doesn't fit and then optimizes.
+    Time Complexity is O(rows * columns) with auto adjust.
+    */
+    pub fn try_set(&mut self, row: usize, col: usize, val: T) -> Result<(), MatrixError>
+    where
+        T: Numeric,
+    {
+        self.check_bounds(row, col)?;
+        if !self.auto_adjust {
+            self.matrix_type.set(row, col, val);
+            return Ok(());
         }
+        if !self.matrix_type.can_hold(row, col, &val) {
+            self.matrix_type = self.build(MatrixKind::Dense);
+        }
+        self.matrix_type.set(row, col, val);
+        self.optimize();
+        Ok(())
+    }
+
+    // Switches to the one-based notation of the course.
+    pub fn one_based(self) -> OneBased<T> {
+        OneBased { matrix: self }
     }
 
     // pub fn get_array(&self) -> &HeapArray<T> {
@@ -157,806 +741,5279 @@ impl<T: PartialEq> Matrix<T> {
     //     }
     // }
 
-    fn optimize(&mut self) -> ()
+    // Time Complexity is O(rows * columns)
+    fn structure(&self) -> Structure
     where
-        T: PartialEq,
-        T: Default,
-    {
-        // // Cases that needs to be handled
-        // // By default, Dense matrix is created
-        // // We need to check at every push if the matrix can be optimized
-        // // Dense --> Diagonal or Sparse
-        // // Diagonal --> Dense or Sparse
-        // // Sparse --> Dense or Diagonal
-        // let mut is_diagonal = false;
-        // let mut is_sparse = false;
-        //
-        // let mut diagonal_count: usize = 0;
-        // let mut non_zero_count: usize = 0;
-        // let mut zero_count: usize = 0;
-        //
-        // for i in 1..self.rows + 1 {
-        //     for j in 1..self.columns + 1 {
-        //         let val = self.get(i, j).unwrap();
-        //
-        //         if *val == self.default_value {
-        //             zero_count += 1;
-        //         } else {
-        //             if i == j {
-        //                 diagonal_count += 1;
-        //             }
-        //             non_zero_count += 1;
-        //         }
-        //     }
-        // }
-        //
-        // if diagonal_count == non_zero_count {
-        //     self.convert_to_diagonal();
-        // } else if non_zero_count < zero_count {
-        //     is_sparse = true;
-        // } else {
-        //     ()
-        // }
-    }
-
-    fn convert_to_diagonal(&mut self)
-    where
-        T: Default,
-        T: Copy,
-    {
-        // let mut new_matrix: DiagonalMatrix<T> = DiagonalMatrix::new(self.rows.min(self.columns));
-        // for i in 1..self.rows.min(self.columns) + 1 {
-        //     new_matrix.set(i, i, self.get(i, i).unwrap())
-        // }
-        // self.matrix_type = Diagonal(new_matrix);
-    }
-
-    fn convert_to_sparse(&mut self) {
-        ()
-    }
-}
-
-impl<T: Display + Default + PartialEq> Display for Matrix<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for i in 1..self.rows + 1 {
-            for j in 1..self.columns + 1 {
-                write!(f, "{:>3} ", self.get(i, j).unwrap())?;
+        T: Numeric,
+    {
+        let mut structure = Structure::new(self.rows, self.columns);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                let val = self.matrix_type.get(i, j);
+                if i > 0 && j > 0 && val != self.matrix_type.get(i - 1, j - 1) {
+                    structure.toeplitz = false;
+                }
+                if structure.symmetric && i > j && val != self.matrix_type.get(j, i) {
+                    structure.symmetric = false;
+                }
+                if !val.is_zero() {
+                    structure.add(i, j);
+                }
             }
-            writeln!(f, "")?;
         }
-        Ok(())
+        structure.finish()
     }
-}
-
-enum IndexOrder {
-    RowMajor,
-    ColumnMajor,
-}
-
-pub trait MatrixOperations<T: PartialEq> {
-    fn array_size(row: usize, col: usize) -> usize;
-
-    fn array_index(&self, row: usize, col: usize) -> usize;
-
-    fn get(&self, row: usize, col: usize) -> &T;
-
-    fn set(&mut self, row: usize, col: usize, val: T);
-}
-
-pub struct DenseMatrix<T>
-where
-    T: PartialEq,
-{
-    rows: usize,
-    columns: usize,
-    array: HeapArray<T>,
-    index_order: IndexOrder,
-}
 
-impl<T: PartialEq> DenseMatrix<T> {
-    pub fn new(row: usize, col: usize) -> Self
+    // Copies the non-zero values into a new representation, which must fit them.
+    fn build(&self, kind: MatrixKind) -> MatrixType<T>
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        let mut array = HeapArray::with_capacity(Self::array_size(row, col));
-        array.fill(T::default());
-        DenseMatrix {
-            rows: row,
-            columns: col,
-            array,
-            index_order: IndexOrder::RowMajor,
-        }
+        let elements = Self::sorted_nonzero(self.elements());
+        MatrixType::from_sorted(kind, self.rows, self.columns, &elements)
     }
 
-    fn row_major_index(&self, i: usize, j: usize) -> usize {
-        (i - 1) * self.columns + (j - 1)
+    // Switches to the representation which fits the values with the least storage and returns its kind.
+    // Time Complexity is O(rows * columns)
+    pub fn optimize(&mut self) -> MatrixKind
+    where
+        T: Numeric,
+    {
+        if self.rows == 0 || self.columns == 0 {
+            return self.kind();
+        }
+        let best = self.structure().best();
+        if best != self.kind() {
+            self.matrix_type = self.build(best);
+        }
+        best
     }
 
-    fn column_major_index(&self, i: usize, j: usize) -> usize {
-        (j - 1) * self.rows + (i - 1)
+    // Time Complexity is O(rows * columns)
+    pub fn convert_to(&mut self, kind: MatrixKind) -> Result<(), MatrixError>
+    where
+        T: Numeric,
+    {
+        if kind.is_square() && self.rows != self.columns {
+            return Err(MatrixError::NotSquare(kind));
+        }
+        if !self.structure().fits(kind) {
+            return Err(MatrixError::DoesNotFit(kind));
+        }
+        if kind != self.kind() {
+            self.matrix_type = self.build(kind);
+        }
+        Ok(())
     }
 }
 
-impl<T: PartialEq> MatrixOperations<T> for DenseMatrix<T> {
-    fn array_size(row: usize, col: usize) -> usize {
-        row * col
-    }
+/*
+NOTE:
+Every operation builds its result from the positions the result representation stores, so the cost follows the
+representation instead of rows * columns. Diagonal, triangular, tridiagonal and Toeplitz operands keep their kind where
+the math allows it, Sparse operands are merged like the merge step of Merge Sort and everything else falls back to a
+Dense Matrix. The result keeps the auto adjust of the left operand and optimizes itself when it is on.
+*/
+// Values of k summed for the element at row i and column j of a product with n inner elements.
+type ProductRange = fn(usize, usize, usize) -> Range<usize>;
 
-    fn array_index(&self, i: usize, j: usize) -> usize {
-        if i == 0 || j == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
-        } else if i > self.rows || j > self.columns {
-            panic!("Row or column exceeds the maximum Matrix dimensions!");
+impl<T: Numeric> Matrix<T> {
+    // Non-zero candidates in row major order. A Sparse Matrix only yields its elements, while a Toeplitz Matrix
+    // yields every position since its first row and column repeat along the diagonals.
+    fn elements(&self) -> Vec<(usize, usize, T)> {
+        let kind = match self.kind() {
+            MatrixKind::Toeplitz => MatrixKind::Dense,
+            kind => kind,
+        };
+        match &self.matrix_type {
+            Sparse(matrix) => matrix
+                .iter()
+                .map(|(row, col, val)| (row, col, *val))
+                .collect(),
+            matrix_type => kind
+                .positions(self.rows, self.columns)
+                .into_iter()
+                .map(|(row, col)| (row, col, *matrix_type.get(row, col)))
+                .collect(),
         }
-        return match self.index_order {
-            IndexOrder::RowMajor => self.row_major_index(i, j),
-            IndexOrder::ColumnMajor => self.column_major_index(i, j),
-        };
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        self.array.get(self.array_index(row, col))
+    // Sorts the elements in row major order, keeps the last element of every position and drops the zeros.
+    fn sorted_nonzero(
+        elements: impl IntoIterator<Item = (usize, usize, T)>,
+    ) -> Vec<(usize, usize, T)> {
+        let mut elements: Vec<(usize, usize, T)> = elements.into_iter().collect();
+        elements.sort_by_key(|&(row, col, _)| (row, col));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(elements.len());
+        for (row, col, val) in elements {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (row, col) => last.2 = val,
+                _ => merged.push((row, col, val)),
+            }
+        }
+        merged.retain(|(_, _, val)| !val.is_zero());
+        merged
     }
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        self.array.set(self.array_index(row, col), val);
+    fn with_elements(
+        &self,
+        kind: MatrixKind,
+        rows: usize,
+        columns: usize,
+        elements: impl IntoIterator<Item = (usize, usize, T)>,
+    ) -> Self {
+        let elements = Self::sorted_nonzero(elements);
+        let matrix_type = MatrixType::from_sorted(kind, rows, columns, &elements);
+        let mut res = Matrix {
+            rows,
+            columns,
+            matrix_type,
+            auto_adjust: self.auto_adjust,
+        };
+        if res.auto_adjust {
+            res.optimize();
+        }
+        res
     }
-}
-
-pub struct TridiagonalMatrix<T>
-where
-    T: PartialEq,
-{
-    dimension: usize,
-    array: HeapArray<T>,
-    default_value: T,
-}
 
-impl<T: PartialEq> TridiagonalMatrix<T> {
-    pub fn new(dimension: usize) -> Self
-    where
-        T: Default + Copy,
-    {
-        let mut array: HeapArray<T> =
-            HeapArray::with_capacity(Self::array_size(dimension, dimension));
-        array.fill(T::default());
-        TridiagonalMatrix {
-            dimension,
-            array,
-            default_value: T::default(),
+    // A Sparse result falls back to Dense when it has too many non-zero elements.
+    fn sparse_or_dense(
+        &self,
+        rows: usize,
+        columns: usize,
+        elements: &[(usize, usize, T)],
+    ) -> MatrixKind {
+        let nonzero = elements.iter().filter(|(_, _, val)| !val.is_zero()).count();
+        if nonzero <= SparseMatrix::<T>::array_size(rows, columns) {
+            MatrixKind::Sparse
+        } else {
+            MatrixKind::Dense
         }
     }
-}
 
-impl<T: PartialEq> MatrixOperations<T> for TridiagonalMatrix<T> {
-    fn array_size(row: usize, _col: usize) -> usize {
-        3 * row - 2
+    fn check_dimensions(&self, other: &Self) -> Result<(), MatrixError> {
+        if self.rows != other.rows || self.columns != other.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, self.columns),
+                found: (other.rows, other.columns),
+            });
+        }
+        Ok(())
     }
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
-        }
-        let mut index: usize = 0;
-        if row as isize - col as isize == 1 {
-            index = row - 2;
-        } else if row as isize - col as isize == 0 {
-            index = self.dimension + row - 2;
-        } else if row as isize - col as isize == -1 {
-            index = 2 * self.dimension + row - 2;
+    // Merges two row major element lists, a missing element counts as zero.
+    fn merge(
+        &self,
+        left: Vec<(usize, usize, T)>,
+        right: Vec<(usize, usize, T)>,
+        f: impl Fn(T, T) -> T,
+    ) -> Vec<(usize, usize, T)> {
+        let zero = T::zero();
+        let mut res = Vec::with_capacity(left.len() + right.len());
+        let (mut i, mut j) = (0, 0);
+        while i < left.len() || j < right.len() {
+            let order = match (left.get(i), right.get(j)) {
+                (Some(a), Some(b)) => (a.0, a.1).cmp(&(b.0, b.1)),
+                (Some(_), None) => Ordering::Less,
+                _ => Ordering::Greater,
+            };
+            match order {
+                Ordering::Less => {
+                    res.push((left[i].0, left[i].1, f(left[i].2, zero)));
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    res.push((right[j].0, right[j].1, f(zero, right[j].2)));
+                    j += 1;
+                }
+                Ordering::Equal => {
+                    res.push((left[i].0, left[i].1, f(left[i].2, right[j].2)));
+                    i += 1;
+                    j += 1;
+                }
+            }
         }
-        index
+        res
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        if (row as isize - col as isize).abs() <= 1 {
-            return self.array.get(self.array_index(row, col));
+    // The operation must map zero and zero to zero whenever the kind isn't Dense.
+    fn elementwise(
+        &self,
+        other: &Self,
+        kind: MatrixKind,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Self, MatrixError> {
+        self.check_dimensions(other)?;
+        if kind == MatrixKind::Sparse {
+            // With one Sparse operand the result is a product, so only the elements of the Sparse one can be non-zero.
+            let elements = match (&self.matrix_type, &other.matrix_type) {
+                (Sparse(_), Sparse(_)) => self.merge(self.elements(), other.elements(), f),
+                (Sparse(_), matrix_type) => self
+                    .elements()
+                    .into_iter()
+                    .map(|(row, col, a)| (row, col, f(a, *matrix_type.get(row, col))))
+                    .collect(),
+                (matrix_type, _) => other
+                    .elements()
+                    .into_iter()
+                    .map(|(row, col, b)| (row, col, f(*matrix_type.get(row, col), b)))
+                    .collect(),
+            };
+            let kind = self.sparse_or_dense(self.rows, self.columns, &elements);
+            return Ok(self.with_elements(kind, self.rows, self.columns, elements));
         }
-        return &self.default_value;
+        let elements = kind
+            .positions(self.rows, self.columns)
+            .into_iter()
+            .map(|(row, col)| {
+                let a = *self.matrix_type.get(row, col);
+                let b = *other.matrix_type.get(row, col);
+                (row, col, f(a, b))
+            });
+        Ok(self.with_elements(kind, self.rows, self.columns, elements))
     }
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        if (row as isize - col as isize).abs() <= 1 {
-            self.array.set(self.array_index(row, col), val);
-        }
+    // Applies an operation which maps zero to zero on the stored elements, so the kind stays the same.
+    fn map_stored(&self, f: impl Fn(T) -> T) -> Self {
+        let elements = self
+            .elements()
+            .into_iter()
+            .map(|(row, col, val)| (row, col, f(val)));
+        self.with_elements(self.kind(), self.rows, self.columns, elements)
     }
-}
 
-pub struct UpperTraingularMatrix<T>
-where
-    T: PartialEq,
-{
-    dimension: usize,
-    array: HeapArray<T>,
-    default_value: T,
-    index_order: IndexOrder,
-}
+    pub fn checked_add(&self, other: &Self) -> Result<Self, MatrixError> {
+        self.elementwise(other, self.kind().sum(other.kind()), |a, b| a + b)
+    }
 
-impl<T: PartialEq> UpperTraingularMatrix<T> {
-    pub fn new(dimension: usize) -> Self
-    where
-        T: Default + Copy,
-    {
-        let mut array: HeapArray<T> =
-            HeapArray::with_capacity(Self::array_size(dimension, dimension));
-        array.fill(T::default());
-        UpperTraingularMatrix {
-            dimension,
-            array,
-            default_value: T::default(),
-            index_order: IndexOrder::ColumnMajor,
-        }
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, MatrixError> {
+        self.elementwise(other, self.kind().sum(other.kind()), |a, b| a - b)
     }
 
-    fn row_major_index(&self, row: usize, col: usize) -> usize {
-        let row_signed = row as isize;
-        let col_signed = col as isize;
-        let dimenion_signed = self.dimension as isize;
-        ((dimenion_signed * (row_signed - 1) - (row_signed - 2) * (row_signed - 1) / 2)
-            + col_signed
-            - row_signed) as usize
+    // Element-wise product.
+    pub fn hadamard(&self, other: &Self) -> Result<Self, MatrixError> {
+        self.elementwise(other, self.kind().product(other.kind()), |a, b| a * b)
     }
 
-    fn column_major_index(&self, row: usize, col: usize) -> usize {
-        return (col * (col - 1) / 2) + (row - 1);
+    // Combines the elements at the same positions, the result is Dense since the operation may not keep zeros.
+    pub fn zip_with(&self, other: &Self, f: impl Fn(T, T) -> T) -> Result<Self, MatrixError> {
+        self.elementwise(other, MatrixKind::Dense, f)
     }
-}
 
-impl<T: PartialEq> MatrixOperations<T> for UpperTraingularMatrix<T> {
-    fn array_size(row: usize, _col: usize) -> usize {
-        row * (row + 1) / 2
+    // Applies the operation to every element, the result is Dense since the operation may not keep zeros.
+    pub fn map(&self, f: impl Fn(T) -> T) -> Self {
+        let elements = MatrixKind::Dense
+            .positions(self.rows, self.columns)
+            .into_iter()
+            .map(|(row, col)| (row, col, f(*self.matrix_type.get(row, col))));
+        self.with_elements(MatrixKind::Dense, self.rows, self.columns, elements)
     }
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
-        }
-        return match self.index_order {
-            IndexOrder::RowMajor => self.row_major_index(row, col),
-            IndexOrder::ColumnMajor => self.column_major_index(row, col),
-        };
+    pub fn scale(&self, scalar: T) -> Self {
+        self.map_stored(|val| val * scalar)
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        if row <= col {
-            return self.array.get(self.array_index(row, col));
-        }
-        return &self.default_value;
+    pub fn transpose(&self) -> Self {
+        let elements = self
+            .elements()
+            .into_iter()
+            .map(|(row, col, val)| (col, row, val));
+        self.with_elements(self.kind().transposed(), self.columns, self.rows, elements)
     }
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        if row <= col {
-            self.array.set(self.array_index(row, col), val);
+    /*
+    Diagonal x Diagonal, Upper x Upper and Lower x Lower keep their kind and only sum the products between the
+    diagonals, e.g. an element of two upper triangular matrices is the sum of a(i, k) * b(k, j) for k in i..=j.
+    */
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.columns != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, other.columns),
+                found: (other.rows, other.columns),
+            });
         }
+        let (kind, range): (MatrixKind, ProductRange) = match (self.kind(), other.kind()) {
+            (MatrixKind::Sparse, MatrixKind::Sparse) => return Ok(self.sparse_product(other)),
+            (MatrixKind::Diagonal, MatrixKind::Diagonal) => {
+                (MatrixKind::Diagonal, |i, _, _| i..i + 1)
+            }
+            (MatrixKind::UpperTriangular, MatrixKind::UpperTriangular) => {
+                (MatrixKind::UpperTriangular, |i, j, _| i..j + 1)
+            }
+            (MatrixKind::LowerTriangular, MatrixKind::LowerTriangular) => {
+                (MatrixKind::LowerTriangular, |i, j, _| j..i + 1)
+            }
+            _ => (MatrixKind::Dense, |_, _, n| 0..n),
+        };
+        let elements = kind
+            .positions(self.rows, other.columns)
+            .into_iter()
+            .map(|(i, j)| {
+                let val = range(i, j, self.columns).fold(T::zero(), |sum, k| {
+                    sum + *self.matrix_type.get(i, k) * *other.matrix_type.get(k, j)
+                });
+                (i, j, val)
+            });
+        Ok(self.with_elements(kind, self.rows, other.columns, elements))
     }
-}
-
-pub struct LowerTriangularMatrix<T>
-where
-    T: PartialEq,
-{
-    dimension: usize,
-    array: HeapArray<T>,
-    default_value: T,
-    index_order: IndexOrder,
-}
 
-impl<T: PartialEq> LowerTriangularMatrix<T> {
-    pub fn new(dimension: usize) -> Self
-    where
-        T: Default + Copy,
-    {
-        let mut array: HeapArray<T> =
-            HeapArray::with_capacity(Self::array_size(dimension, dimension));
-        array.fill(T::default());
-        LowerTriangularMatrix {
-            dimension,
-            array,
-            default_value: T::default(),
-            index_order: IndexOrder::ColumnMajor,
+    // Multiplies every element with the elements in the matching row of the other matrix and merges the products
+    // which land on the same position.
+    fn sparse_product(&self, other: &Self) -> Self {
+        let right = other.elements();
+        let mut products: Vec<(usize, usize, T)> = Vec::new();
+        for (i, k, a) in self.elements() {
+            let start = right.partition_point(|&(row, _, _)| row < k);
+            for &(_, j, b) in right[start..].iter().take_while(|&&(row, _, _)| row == k) {
+                products.push((i, j, a * b));
+            }
         }
+        products.sort_by_key(|&(row, col, _)| (row, col));
+        let mut merged: Vec<(usize, usize, T)> = Vec::with_capacity(products.len());
+        for (row, col, val) in products {
+            match merged.last_mut() {
+                Some(last) if (last.0, last.1) == (row, col) => last.2 = last.2 + val,
+                _ => merged.push((row, col, val)),
+            }
+        }
+        let kind = self.sparse_or_dense(self.rows, other.columns, &merged);
+        self.with_elements(kind, self.rows, other.columns, merged)
     }
+}
 
-    fn row_major_index(row: usize, col: usize) -> usize {
-        return (row * (row - 1) / 2) + (col - 1);
+impl<T: Display + PartialEq> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                write!(f, "{:>3} ", self.matrix_type.get(i, j))?;
+            }
+            writeln!(f, "")?;
+        }
+        Ok(())
     }
+}
 
-    fn column_major_index(&self, row: usize, col: usize) -> usize {
-        let row_signed = row as isize;
-        let col_signed = col as isize;
-        let dimenion_signed = self.dimension as isize;
-        ((dimenion_signed * (col_signed - 1) - (col_signed - 2) * (col_signed - 1) / 2)
-            + row_signed
-            - col_signed) as usize
+impl<T: PartialEq> PartialEq for Matrix<T> {
+    // Matrices are equal when their values are, whatever their representations.
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+            && self.columns == other.columns
+            && (0..self.rows).all(|i| {
+                (0..self.columns).all(|j| self.matrix_type.get(i, j) == other.matrix_type.get(i, j))
+            })
     }
 }
 
-impl<T: PartialEq> MatrixOperations<T> for LowerTriangularMatrix<T> {
-    fn array_size(row: usize, _col: usize) -> usize {
-        row * (row + 1) / 2
+impl<T: Debug + PartialEq> Debug for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let values: Vec<Vec<&T>> = (0..self.rows)
+            .map(|i| {
+                (0..self.columns)
+                    .map(|j| self.matrix_type.get(i, j))
+                    .collect()
+            })
+            .collect();
+        f.debug_struct("Matrix")
+            .field("kind", &self.kind())
+            .field("values", &values)
+            .finish()
     }
+}
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        return match self.index_order {
-            IndexOrder::RowMajor => Self::row_major_index(row, col),
-            IndexOrder::ColumnMajor => self.column_major_index(row, col),
-        };
-    }
+impl<T: PartialEq> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
-        }
-        if row >= col {
-            return self.array.get(self.array_index(row, col));
-        }
-        return &self.default_value;
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
+}
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
+impl<T: Numeric> IndexMut<(usize, usize)> for Matrix<T> {
+    /*
+    NOTE:
+    Writing through a reference needs an element with a slot of its own. A Sparse Matrix inserts a zero element for a
+    missing one. When the representation leaves the element out or shares it with other elements, e.g. a Diagonal
+    Matrix off its diagonal or a Symmetric Matrix above it, the Matrix moves to a Dense Matrix first. Use set to keep
+    the representation.
+    */
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.check_bounds(row, col)
+            .unwrap_or_else(|error| panic!("{}", error));
+        if self.kind() != MatrixKind::Sparse && self.matrix_type.get_mut(row, col).is_none() {
+            self.matrix_type = self.build(MatrixKind::Dense);
         }
-        if row >= col {
-            self.array.set(self.array_index(row, col), val);
+        match &mut self.matrix_type {
+            Sparse(matrix) => matrix.slot(row, col),
+            matrix_type => matrix_type.get_mut(row, col).unwrap(),
         }
     }
 }
 
-pub struct DiagonalMatrix<T>
+/*
+NOTE:
+The course notes count rows and columns from 1. OneBased wraps a Matrix and shifts every index down by one before it
+reaches the Matrix, so examples can keep the notation while the Matrix itself stays zero-based. Index 0 is out of
+bounds just like an index past the last row or column.
+*/
+pub struct OneBased<T>
 where
     T: PartialEq,
 {
-    dimension: usize,
-    array: HeapArray<T>,
-    default_value: T,
+    matrix: Matrix<T>,
 }
 
-impl<T: PartialEq> DiagonalMatrix<T> {
-    pub fn new(dimension: usize) -> Self
-    where
-        T: Default + Copy,
-    {
-        let mut array: HeapArray<T> =
-            HeapArray::with_capacity(Self::array_size(dimension, dimension));
-        array.fill(T::default());
-        DiagonalMatrix {
-            dimension,
-            array,
-            default_value: T::default(),
-        }
+impl<T: PartialEq> OneBased<T> {
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
     }
-}
 
-impl<T: PartialEq> MatrixOperations<T> for DiagonalMatrix<T> {
-    fn array_size(row: usize, _col: usize) -> usize {
-        row
+    pub fn into_inner(self) -> Matrix<T> {
+        self.matrix
     }
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
+    fn shift(&self, row: usize, col: usize) -> Result<(usize, usize), MatrixError> {
+        let (rows, columns) = (self.matrix.rows, self.matrix.columns);
+        if row == 0 || col == 0 || row > rows || col > columns {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dimensions: (rows, columns),
+            });
         }
-        row - 1
+        Ok((row - 1, col - 1))
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        if row == col {
-            return self.array.get(self.array_index(row, col));
-        }
-        return &self.default_value;
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.try_get(row, col).ok()
     }
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        if row == col {
-            self.array.set(self.array_index(row, col), val);
-        }
+    pub fn try_get(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        let (row, col) = self.shift(row, col)?;
+        self.matrix.try_get(row, col)
     }
-}
 
-// TODO: Fix Toeplitz Matrix struct
-pub struct ToeplitzMatrix<T>
-where
-    T: PartialEq,
-{
-    dimension: usize,
-    array: HeapArray<T>,
-}
+    pub fn set(&mut self, row: usize, col: usize, val: T)
+    where
+        T: Numeric,
+    {
+        self.try_set(row, col, val)
+            .unwrap_or_else(|error| panic!("{}", error));
+    }
 
-impl<T: PartialEq> ToeplitzMatrix<T> {
-    pub fn new(dimension: usize) -> Self
+    pub fn try_set(&mut self, row: usize, col: usize, val: T) -> Result<(), MatrixError>
     where
-        T: Default + Copy,
+        T: Numeric,
     {
-        let mut array: HeapArray<T> =
-            HeapArray::with_capacity(Self::array_size(dimension, dimension));
-        array.fill(T::default());
-        ToeplitzMatrix { dimension, array }
+        let (row, col) = self.shift(row, col)?;
+        self.matrix.try_set(row, col, val)
     }
 }
 
-impl<T: PartialEq> MatrixOperations<T> for ToeplitzMatrix<T> {
-    fn array_size(rows: usize, cols: usize) -> usize {
-        rows + cols - 1
-    }
+impl<T: PartialEq> Index<(usize, usize)> for OneBased<T> {
+    type Output = T;
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
-        }
-        let mut index: usize = 0;
-        if row <= col {
-            index = col - row;
-        } else if row > col {
-            index = self.dimension + col - row - 1;
-        }
-        index
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.try_get(row, col)
+            .unwrap_or_else(|error| panic!("{}", error))
     }
+}
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        return self.array.get(self.array_index(row, col));
+impl<T: Numeric> IndexMut<(usize, usize)> for OneBased<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (row, col) = self
+            .shift(row, col)
+            .unwrap_or_else(|error| panic!("{}", error));
+        &mut self.matrix[(row, col)]
     }
+}
 
-    fn set(&mut self, row: usize, col: usize, val: T) {
-        self.array.set(self.array_index(row, col), val);
+impl<T: Display + PartialEq> Display for OneBased<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.matrix)
     }
 }
 
-#[derive(Debug, PartialEq, Default)]
-pub(crate) struct SparseMatrixElement<T> {
-    row: usize,
-    column: usize,
-    value: T,
-}
+// The operators panic with the Matrix error message, the checked methods return it instead.
+macro_rules! impl_matrix_operator {
+    ($op:ident, $method:ident, $checked:ident) => {
+        impl<T: Numeric> $op for &Matrix<T> {
+            type Output = Matrix<T>;
 
-impl<T: Copy> Clone for SparseMatrixElement<T> {
-    fn clone(&self) -> Self {
-        SparseMatrixElement {
-            row: self.row,
-            column: self.column,
-            value: self.value,
+            fn $method(self, other: Self) -> Matrix<T> {
+                self.$checked(other)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            }
         }
-    }
-}
 
-impl<T: Copy> Copy for SparseMatrixElement<T> {}
+        impl<T: Numeric> $op for Matrix<T> {
+            type Output = Matrix<T>;
 
-pub struct SparseMatrix<T>
-where
-    T: PartialEq,
-{
-    rows: usize,
-    columns: usize,
-    nonzero_count: usize,
-    array: HeapArray<SparseMatrixElement<T>>,
-    default_value: T,
+            fn $method(self, other: Self) -> Matrix<T> {
+                (&self).$method(&other)
+            }
+        }
+    };
 }
 
-impl<T: Default + PartialEq> SparseMatrix<T> {
-    pub fn new(rows: usize, columns: usize) -> Self
-    where
-        T: Default + Copy,
-    {
-        let array: HeapArray<SparseMatrixElement<T>> =
-            HeapArray::with_capacity(Self::array_size(rows, columns));
-        SparseMatrix {
-            rows,
-            columns,
-            nonzero_count: 0,
-            array: array,
-            default_value: T::default(),
-        }
+impl_matrix_operator!(Add, add, checked_add);
+impl_matrix_operator!(Sub, sub, checked_sub);
+impl_matrix_operator!(Mul, mul, checked_mul);
+
+impl<T: Numeric> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.scale(scalar)
     }
 }
 
-impl<T: PartialEq> MatrixOperations<T> for SparseMatrix<T> {
-    fn array_size(row: usize, col: usize) -> usize {
-        (row * col) / 2
+impl<T: Numeric> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.scale(scalar)
     }
+}
 
-    fn array_index(&self, row: usize, col: usize) -> usize {
-        if row == 0 || col == 0 {
-            panic!("Row or column can't be 0. Matrices always start with 1 indices!");
+impl<T: Numeric + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        self.map_stored(|val| -val)
+    }
+}
+
+impl<T: Numeric + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -&self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrorKind {
+    MissingHeader,
+    // Word of the Matrix Market header which isn't supported.
+    Unsupported(String),
+    InvalidSize,
+    InvalidEntry,
+    InvalidValue(String),
+    IndexOutOfBounds(usize, usize),
+    WrongEntryCount { expected: usize, found: usize },
+    RaggedRow { expected: usize, found: usize },
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingHeader => write!(f, "Matrix Market header is missing!"),
+            ParseErrorKind::Unsupported(word) => {
+                write!(f, "Matrix Market '{}' isn't supported!", word)
+            }
+            ParseErrorKind::InvalidSize => write!(f, "Invalid Matrix size line!"),
+            ParseErrorKind::InvalidEntry => write!(f, "Invalid Matrix entry line!"),
+            ParseErrorKind::InvalidValue(value) => write!(f, "Invalid Matrix value '{}'!", value),
+            ParseErrorKind::IndexOutOfBounds(row, col) => {
+                write!(f, "Entry ({}, {}) is out of the Matrix bounds!", row, col)
+            }
+            ParseErrorKind::WrongEntryCount { expected, found } => {
+                write!(f, "Expected {} entries, found {}!", expected, found)
+            }
+            ParseErrorKind::RaggedRow { expected, found } => {
+                write!(
+                    f,
+                    "Expected {} values in the row, found {}!",
+                    expected, found
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MatrixIoError {
+    Io(io::Error),
+    Parse { line: usize, kind: ParseErrorKind },
+}
+
+impl Display for MatrixIoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixIoError::Io(error) => write!(f, "Matrix I/O failed! {}", error),
+            MatrixIoError::Parse { line, kind } => write!(f, "Line {}: {}", line, kind),
+        }
+    }
+}
+
+impl Error for MatrixIoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MatrixIoError::Io(error) => Some(error),
+            MatrixIoError::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for MatrixIoError {
+    fn from(error: io::Error) -> Self {
+        MatrixIoError::Io(error)
+    }
+}
+
+fn parse_error(line: usize, kind: ParseErrorKind) -> MatrixIoError {
+    MatrixIoError::Parse { line, kind }
+}
+
+fn parse_value<T: FromStr>(line: usize, token: &str) -> Result<T, MatrixIoError> {
+    token
+        .parse()
+        .map_err(|_| parse_error(line, ParseErrorKind::InvalidValue(token.to_string())))
+}
+
+// Numbered lines without blank lines and % comments, where the first line has the given number.
+fn data_lines<R: BufRead>(
+    lines: Lines<R>,
+    first: usize,
+) -> impl Iterator<Item = Result<(usize, String), MatrixIoError>> {
+    lines
+        .enumerate()
+        .filter_map(move |(index, line)| match line {
+            Ok(line) if line.trim().is_empty() || line.starts_with('%') => None,
+            Ok(line) => Some(Ok((index + first, line))),
+            Err(error) => Some(Err(MatrixIoError::Io(error))),
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarketFormat {
+    // Lists the non-zero elements as row, column and value.
+    Coordinate,
+    // Lists every value in column major order.
+    Array,
+}
+
+/*
+NOTE:
+Matrix Market files start with a header like "%%MatrixMarket matrix coordinate real general", followed by comment lines
+starting with %, a size line and the entries. A symmetric file only keeps the lower triangle and the reader mirrors it.
+Reading picks the best representation with optimize, so e.g. a file of a diagonal matrix becomes a Diagonal Matrix.
+The files count rows and columns from 1, so the indices shift by one on the way in and out.
+*/
+impl<T: Numeric> Matrix<T> {
+    pub fn read_matrix_market(reader: impl BufRead) -> Result<Self, MatrixIoError>
+    where
+        T: FromStr,
+    {
+        let mut lines = reader.lines();
+        let header = match lines.next() {
+            Some(line) => line?,
+            None => return Err(parse_error(1, ParseErrorKind::MissingHeader)),
+        };
+        let words: Vec<String> = header
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect();
+        if words.len() != 5 || words[0] != "%%matrixmarket" {
+            return Err(parse_error(1, ParseErrorKind::MissingHeader));
+        }
+        let supported = [
+            ["matrix"].as_slice(),
+            &["coordinate", "array"],
+            &["real", "double", "integer"],
+            &["general", "symmetric"],
+        ];
+        for (word, allowed) in words[1..].iter().zip(supported) {
+            if !allowed.contains(&word.as_str()) {
+                return Err(parse_error(1, ParseErrorKind::Unsupported(word.clone())));
+            }
+        }
+        let coordinate = words[2] == "coordinate";
+        let symmetric = words[4] == "symmetric";
+
+        let mut data = data_lines(lines, 2);
+        let (size_line, size) = data
+            .next()
+            .ok_or(parse_error(1, ParseErrorKind::InvalidSize))??;
+        let size: Vec<usize> = size
+            .split_whitespace()
+            .map(|token| token.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| parse_error(size_line, ParseErrorKind::InvalidSize))?;
+        let (rows, columns) = match (coordinate, size.as_slice()) {
+            (true, &[rows, columns, _]) | (false, &[rows, columns]) => (rows, columns),
+            _ => return Err(parse_error(size_line, ParseErrorKind::InvalidSize)),
+        };
+        if symmetric && rows != columns {
+            return Err(parse_error(size_line, ParseErrorKind::InvalidSize));
+        }
+
+        let mut positions: Vec<(usize, usize)> = Vec::new();
+        let expected = if coordinate {
+            size[2]
+        } else {
+            positions = (0..columns)
+                .flat_map(|j| (if symmetric { j } else { 0 }..rows).map(move |i| (i, j)))
+                .collect();
+            positions.len()
+        };
+
+        let mut elements: Vec<(usize, usize, T)> = Vec::new();
+        let mut found: usize = 0;
+        let mut last_line = size_line;
+        for line in data {
+            let (number, line) = line?;
+            last_line = number;
+            if found == expected {
+                return Err(parse_error(
+                    number,
+                    ParseErrorKind::WrongEntryCount {
+                        expected,
+                        found: found + 1,
+                    },
+                ));
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let (row, col, val) = match (coordinate, tokens.as_slice()) {
+                (true, &[row, col, val]) => {
+                    let row: usize = parse_value(number, row)?;
+                    let col: usize = parse_value(number, col)?;
+                    if row == 0 || col == 0 || row > rows || col > columns {
+                        return Err(parse_error(
+                            number,
+                            ParseErrorKind::IndexOutOfBounds(row, col),
+                        ));
+                    }
+                    (row - 1, col - 1, val)
+                }
+                (false, &[val]) => (positions[found].0, positions[found].1, val),
+                _ => return Err(parse_error(number, ParseErrorKind::InvalidEntry)),
+            };
+            let val: T = parse_value(number, val)?;
+            // A zero coordinate entry still replaces an earlier entry of the same position.
+            if coordinate || !val.is_zero() {
+                elements.push((row, col, val));
+                if symmetric && row != col {
+                    elements.push((col, row, val));
+                }
+            }
+            found += 1;
+        }
+        if found != expected {
+            return Err(parse_error(
+                last_line,
+                ParseErrorKind::WrongEntryCount { expected, found },
+            ));
+        }
+        Ok(Self::from_elements(rows, columns, elements))
+    }
+
+    /*
+    NOTE:
+    Coordinate files usually hold large Sparse matrices, so the reader keeps the entries as a list and picks the
+    representation from their Structure before it allocates anything. A Dense Matrix is only allocated when no other
+    representation fits. The entries are sorted in row major order and the last entry of a position wins.
+    Time Complexity is O(k log k + n) for k entries, plus the storage of the picked representation.
+    */
+    fn from_elements(rows: usize, columns: usize, elements: Vec<(usize, usize, T)>) -> Self {
+        let merged = Self::sorted_nonzero(elements);
+        let kind = if rows == 0 || columns == 0 {
+            MatrixKind::Dense
+        } else {
+            Structure::from_elements(rows, columns, &merged).best()
+        };
+        let matrix_type = MatrixType::from_sorted(kind, rows, columns, &merged);
+        Matrix {
+            rows,
+            columns,
+            matrix_type,
+            auto_adjust: false,
+        }
+    }
+
+    // Writes a symmetric Matrix as its lower triangle. The field is integer when every value prints as an integer.
+    pub fn write_matrix_market(
+        &self,
+        mut writer: impl Write,
+        format: MarketFormat,
+    ) -> io::Result<()>
+    where
+        T: Display,
+    {
+        let symmetric = self.is_symmetric();
+        let entries: Vec<(usize, usize, T)> = match format {
+            MarketFormat::Coordinate => self
+                .elements()
+                .into_iter()
+                .filter(|&(row, col, val)| !val.is_zero() && (!symmetric || row >= col))
+                .collect(),
+            MarketFormat::Array => (0..self.columns)
+                .flat_map(|j| {
+                    let first = if symmetric { j } else { 0 };
+                    (first..self.rows).map(move |i| (i, j, *self.matrix_type.get(i, j)))
+                })
+                .collect(),
+        };
+        let integer = entries
+            .iter()
+            .all(|(_, _, val)| val.to_string().parse::<i64>().is_ok());
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix {} {} {}",
+            if format == MarketFormat::Coordinate {
+                "coordinate"
+            } else {
+                "array"
+            },
+            if integer { "integer" } else { "real" },
+            if symmetric { "symmetric" } else { "general" }
+        )?;
+        match format {
+            MarketFormat::Coordinate => {
+                writeln!(writer, "{} {} {}", self.rows, self.columns, entries.len())?;
+                for (row, col, val) in entries {
+                    writeln!(writer, "{} {} {}", row + 1, col + 1, val)?;
+                }
+            }
+            MarketFormat::Array => {
+                writeln!(writer, "{} {}", self.rows, self.columns)?;
+                for (_, _, val) in entries {
+                    writeln!(writer, "{}", val)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Reads one row per line with the values separated by the delimiter, e.g. ',' for CSV and '\t' for TSV.
+    pub fn read_delimited(reader: impl BufRead, delimiter: char) -> Result<Self, MatrixIoError>
+    where
+        T: FromStr,
+    {
+        let mut values: Vec<Vec<T>> = Vec::new();
+        for line in data_lines(reader.lines(), 1) {
+            let (number, line) = line?;
+            let row: Vec<T> = line
+                .split(delimiter)
+                .map(|token| parse_value(number, token.trim()))
+                .collect::<Result<_, _>>()?;
+            if let Some(first) = values.first() {
+                if row.len() != first.len() {
+                    return Err(parse_error(
+                        number,
+                        ParseErrorKind::RaggedRow {
+                            expected: first.len(),
+                            found: row.len(),
+                        },
+                    ));
+                }
+            }
+            values.push(row);
+        }
+        let columns = values.first().map_or(0, |row| row.len());
+        let mut matrix = Matrix::new(values.len(), columns);
+        for (i, row) in values.into_iter().enumerate() {
+            for (j, val) in row.into_iter().enumerate() {
+                matrix.set(i, j, val);
+            }
+        }
+        matrix.optimize();
+        Ok(matrix)
+    }
+
+    pub fn write_delimited(&self, mut writer: impl Write, delimiter: char) -> io::Result<()>
+    where
+        T: Display,
+    {
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                if j > 0 {
+                    write!(writer, "{}", delimiter)?;
+                }
+                write!(writer, "{}", self.matrix_type.get(i, j))?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/*
+NOTE:
+A pivot counts as zero when it is within the rounding error of the largest value, which is machine epsilon scaled by
+that value and the dimension.
+*/
+fn pivot_tolerance(values: impl IntoIterator<Item = f64>, n: usize) -> f64 {
+    let max = values
+        .into_iter()
+        .fold(0.0, |max: f64, val| max.max(val.abs()));
+    max * f64::EPSILON * n as f64
+}
+
+fn check_length(n: usize, b: &[f64]) -> Result<(), MatrixError> {
+    if b.len() != n {
+        return Err(MatrixError::DimensionMismatch {
+            expected: (n, 1),
+            found: (b.len(), 1),
+        });
+    }
+    Ok(())
+}
+
+/*
+LU Decomposition with partial pivoting keeps P * A = L * U, where P swaps the rows so that every pivot is the largest
+remaining value of its column. L has a unit diagonal, so both factors share a single n x n array.
+Time Complexity is O(n^3) for the decomposition and O(n^2) for every solve.
+*/
+pub struct LuDecomposition {
+    // L below the diagonal and U on and above it.
+    lu: Vec<Vec<f64>>,
+    // Row of the original matrix at every row of the decomposition.
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl LuDecomposition {
+    fn new(rows: Vec<Vec<f64>>) -> Result<Self, MatrixError> {
+        let n = rows.len();
+        let tolerance = pivot_tolerance(rows.iter().flatten().copied(), n);
+        let mut lu = rows;
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps: usize = 0;
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| lu[a][k].abs().total_cmp(&lu[b][k].abs()))
+                .unwrap();
+            if lu[pivot][k].abs() <= tolerance {
+                return Err(MatrixError::Singular(k));
+            }
+            if pivot != k {
+                lu.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            let (top, bottom) = lu.split_at_mut(k + 1);
+            let pivot_row = &top[k];
+            for row in bottom {
+                let factor = row[k] / pivot_row[k];
+                row[k] = factor;
+                for (val, pivot_val) in row[k + 1..].iter_mut().zip(&pivot_row[k + 1..]) {
+                    *val -= factor * pivot_val;
+                }
+            }
+        }
+        Ok(LuDecomposition {
+            lu,
+            permutation,
+            swaps,
+        })
+    }
+
+    pub fn determinant(&self) -> f64 {
+        let sign = if self.swaps.is_multiple_of(2) { 1.0 } else { -1.0 };
+        (0..self.lu.len()).fold(sign, |det, i| det * self.lu[i][i])
+    }
+
+    // Solves L * y = P * b and then U * x = y.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.lu.len();
+        check_length(n, b)?;
+        let mut x: Vec<f64> = self.permutation.iter().map(|&row| b[row]).collect();
+        for i in 0..n {
+            let sum: f64 = self.lu[i][..i]
+                .iter()
+                .zip(&x[..i])
+                .map(|(l, y)| l * y)
+                .sum();
+            x[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let sum: f64 = self.lu[i][i + 1..]
+                .iter()
+                .zip(&x[i + 1..])
+                .map(|(u, x)| u * x)
+                .sum();
+            x[i] = (x[i] - sum) / self.lu[i][i];
+        }
+        Ok(x)
+    }
+
+    pub fn lower(&self) -> Matrix<f64> {
+        let n = self.lu.len();
+        let mut matrix = Matrix::new_lower_triangular(n, n);
+        for i in 0..n {
+            for j in 0..i {
+                matrix.set(i, j, self.lu[i][j]);
+            }
+            matrix.set(i, i, 1.0);
+        }
+        matrix
+    }
+
+    pub fn upper(&self) -> Matrix<f64> {
+        let n = self.lu.len();
+        let mut matrix = Matrix::new_upper_triangular(n, n);
+        for i in 0..n {
+            for j in i..n {
+                matrix.set(i, j, self.lu[i][j]);
+            }
+        }
+        matrix
+    }
+
+    pub fn permutation_matrix(&self) -> Matrix<f64> {
+        let n = self.lu.len();
+        let mut matrix = Matrix::new(n, n);
+        for (i, &row) in self.permutation.iter().enumerate() {
+            matrix.set(i, row, 1.0);
+        }
+        matrix
+    }
+}
+
+impl Matrix<f64> {
+    fn check_square(&self) -> Result<(), MatrixError> {
+        if self.rows != self.columns {
+            return Err(MatrixError::NotSquare(self.kind()));
+        }
+        Ok(())
+    }
+
+    fn to_rows(&self) -> Vec<Vec<f64>> {
+        (0..self.rows)
+            .map(|i| {
+                (0..self.columns)
+                    .map(|j| *self.matrix_type.get(i, j))
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn lu(&self) -> Result<LuDecomposition, MatrixError> {
+        self.check_square()?;
+        LuDecomposition::new(self.to_rows())
+    }
+
+    // A singular Matrix has a zero determinant, so only a Matrix which isn't square is an error.
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        self.check_square()?;
+        // The determinant of a triangular Matrix is the product of its diagonal.
+        if matches!(
+            self.kind(),
+            MatrixKind::Diagonal | MatrixKind::UpperTriangular | MatrixKind::LowerTriangular
+        ) {
+            return Ok((0..self.rows).map(|i| self.matrix_type.get(i, i)).product());
+        }
+        match self.lu() {
+            Ok(lu) => Ok(lu.determinant()),
+            Err(MatrixError::Singular(_)) => Ok(0.0),
+            Err(error) => Err(error),
+        }
+    }
+
+    // Solves A * x = e for every column e of the identity Matrix.
+    pub fn inverse(&self) -> Result<Matrix<f64>, MatrixError> {
+        let lu = self.lu()?;
+        let n = self.rows;
+        let mut elements = Vec::with_capacity(n * n);
+        for j in 0..n {
+            let mut e = vec![0.0; n];
+            e[j] = 1.0;
+            for (i, val) in lu.solve(&e)?.into_iter().enumerate() {
+                elements.push((i, j, val));
+            }
+        }
+        Ok(self.with_elements(MatrixKind::Dense, n, n, elements))
+    }
+
+    // Number of pivots Gaussian Elimination with partial pivoting finds. Time Complexity is O(rows * columns^2)
+    pub fn rank(&self) -> usize {
+        let mut rows = self.to_rows();
+        let tolerance =
+            pivot_tolerance(rows.iter().flatten().copied(), self.rows.max(self.columns));
+        let mut rank: usize = 0;
+        for col in 0..self.columns {
+            if rank == self.rows {
+                break;
+            }
+            let pivot = (rank..self.rows)
+                .max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))
+                .unwrap();
+            if rows[pivot][col].abs() <= tolerance {
+                continue;
+            }
+            rows.swap(pivot, rank);
+            let (top, bottom) = rows.split_at_mut(rank + 1);
+            let pivot_row = &top[rank];
+            for row in bottom {
+                let factor = row[col] / pivot_row[col];
+                for (val, pivot_val) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *val -= factor * pivot_val;
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /*
+    Solves A * x = b with the cheapest method for the representation. Diagonal and triangular matrices only need a
+    substitution and a tridiagonal Matrix uses the Thomas Algorithm. The Thomas Algorithm doesn't pivot, so it falls
+    back to LU Decomposition when it meets a zero pivot.
+    */
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.check_square()?;
+        check_length(self.rows, b)?;
+        match &self.matrix_type {
+            Diagonal(matrix) => {
+                let tolerance = pivot_tolerance(matrix.array.iter().copied(), self.rows);
+                let mut x = Vec::with_capacity(self.rows);
+                for (i, val) in b.iter().enumerate() {
+                    let pivot = *matrix.get(i, i);
+                    if pivot.abs() <= tolerance {
+                        return Err(MatrixError::Singular(i));
+                    }
+                    x.push(val / pivot);
+                }
+                Ok(x)
+            }
+            LowerTriangular(matrix) => matrix.forward_substitution(b),
+            UpperTriangular(matrix) => matrix.back_substitution(b),
+            Tridiagonal(matrix) => match matrix.thomas(b) {
+                Err(MatrixError::Singular(_)) => self.lu()?.solve(b),
+                res => res,
+            },
+            _ => self.lu()?.solve(b),
+        }
+    }
+}
+
+enum IndexOrder {
+    RowMajor,
+    ColumnMajor,
+}
+
+fn check_bounds(index: (usize, usize), dimensions: (usize, usize)) -> Result<(), MatrixError> {
+    if index.0 >= dimensions.0 || index.1 >= dimensions.1 {
+        return Err(MatrixError::IndexOutOfBounds { index, dimensions });
+    }
+    Ok(())
+}
+
+// Only the index of the row or column is checked, so the row of a Matrix without columns is still valid.
+fn check_lane(lane: &str, index: usize, count: usize) {
+    if index >= count {
+        panic!(
+            "{} {} is out of bounds for a Matrix with {} {}s!",
+            lane,
+            index,
+            count,
+            lane.to_lowercase()
+        );
+    }
+}
+
+/*
+NOTE:
+Rows and columns start at 0. The array index of an element assumes it is in bounds and inside the shape of the
+representation, get and set check the bounds first and panic with the error of check_bounds, so an index past the
+Matrix never reaches the array or lands on another element.
+*/
+pub trait MatrixOperations<T: PartialEq> {
+    fn array_size(row: usize, col: usize) -> usize;
+
+    fn array_index(&self, row: usize, col: usize) -> usize;
+
+    // Rows and columns of the Matrix.
+    fn dimensions(&self) -> (usize, usize);
+
+    fn get(&self, row: usize, col: usize) -> &T;
+
+    fn set(&mut self, row: usize, col: usize, val: T);
+
+    // The element when it has a slot of its own, i.e. it isn't left out or shared with other elements.
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T>;
+
+    fn check_bounds(&self, row: usize, col: usize) -> Result<(), MatrixError> {
+        check_bounds((row, col), self.dimensions())
+    }
+
+    fn checked_get(&self, row: usize, col: usize) -> Option<&T> {
+        self.check_bounds(row, col).ok()?;
+        Some(self.get(row, col))
+    }
+
+    fn assert_bounds(&self, row: usize, col: usize) {
+        if let Err(error) = self.check_bounds(row, col) {
+            panic!("{}", error);
+        }
+    }
+}
+
+pub struct DenseMatrix<T>
+where
+    T: PartialEq,
+{
+    rows: usize,
+    columns: usize,
+    array: HeapArray<T>,
+    index_order: IndexOrder,
+}
+
+impl<T: PartialEq> DenseMatrix<T> {
+    pub fn new(row: usize, col: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array = HeapArray::with_capacity(Self::array_size(row, col));
+        array.fill(T::zero());
+        DenseMatrix {
+            rows: row,
+            columns: col,
+            array,
+            index_order: IndexOrder::RowMajor,
+        }
+    }
+
+    fn row_major_index(&self, i: usize, j: usize) -> usize {
+        i * self.columns + j
+    }
+
+    fn column_major_index(&self, i: usize, j: usize) -> usize {
+        j * self.rows + i
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for DenseMatrix<T> {
+    fn array_size(row: usize, col: usize) -> usize {
+        row * col
+    }
+
+    fn array_index(&self, i: usize, j: usize) -> usize {
+        return match self.index_order {
+            IndexOrder::RowMajor => self.row_major_index(i, j),
+            IndexOrder::ColumnMajor => self.column_major_index(i, j),
+        };
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        self.array.get(self.array_index(row, col))
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        self.array.set(self.array_index(row, col), val);
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        let index = self.array_index(row, col);
+        Some(&mut self.array[index])
+    }
+}
+
+pub struct TridiagonalMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+    zero: T,
+}
+
+impl<T: PartialEq> TridiagonalMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        TridiagonalMatrix {
+            dimension,
+            array,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for TridiagonalMatrix<T> {
+    fn array_size(row: usize, _col: usize) -> usize {
+        (3 * row).saturating_sub(2)
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        let mut index: usize = 0;
+        if row as isize - col as isize == 1 {
+            index = row - 1;
+        } else if row as isize - col as isize == 0 {
+            index = self.dimension + row - 1;
+        } else if row as isize - col as isize == -1 {
+            index = 2 * self.dimension + row - 1;
+        }
+        index
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if (row as isize - col as isize).abs() <= 1 {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if (row as isize - col as isize).abs() <= 1 {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        let index = self.array_index(row, col);
+        (row.abs_diff(col) <= 1).then(|| &mut self.array[index])
+    }
+}
+
+impl TridiagonalMatrix<f64> {
+    /*
+    Thomas Algorithm is Gaussian Elimination without pivoting for a tridiagonal Matrix. The forward sweep removes the
+    diagonal below the main one and the backward sweep substitutes from the last row up.
+    Time Complexity is O(n)
+    */
+    pub fn thomas(&self, d: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.dimension;
+        check_length(n, d)?;
+        let tolerance = pivot_tolerance(self.array.iter().copied(), n);
+        let mut c: Vec<f64> = vec![0.0; n];
+        let mut x: Vec<f64> = vec![0.0; n];
+        for i in 0..n {
+            let (below, c_prev, x_prev) = match i {
+                0 => (0.0, 0.0, 0.0),
+                _ => (*self.get(i, i - 1), c[i - 1], x[i - 1]),
+            };
+            let pivot = *self.get(i, i) - below * c_prev;
+            if pivot.abs() <= tolerance {
+                return Err(MatrixError::Singular(i));
+            }
+            if i + 1 < n {
+                c[i] = *self.get(i, i + 1) / pivot;
+            }
+            x[i] = (d[i] - below * x_prev) / pivot;
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            x[i] -= c[i] * x[i + 1];
+        }
+        Ok(x)
+    }
+}
+
+pub struct UpperTraingularMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+    zero: T,
+    index_order: IndexOrder,
+}
+
+impl<T: PartialEq> UpperTraingularMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        UpperTraingularMatrix {
+            dimension,
+            array,
+            zero: T::zero(),
+            index_order: IndexOrder::ColumnMajor,
+        }
+    }
+
+    // The rows before hold n, n - 1, ... elements and the row starts at its diagonal.
+    fn row_major_index(&self, row: usize, col: usize) -> usize {
+        (self.dimension * row - row * (row + 1) / 2) + col
+    }
+
+    fn column_major_index(&self, row: usize, col: usize) -> usize {
+        (col * (col + 1) / 2) + row
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for UpperTraingularMatrix<T> {
+    fn array_size(row: usize, _col: usize) -> usize {
+        row * (row + 1) / 2
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        return match self.index_order {
+            IndexOrder::RowMajor => self.row_major_index(row, col),
+            IndexOrder::ColumnMajor => self.column_major_index(row, col),
+        };
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if row <= col {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if row <= col {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        if row <= col {
+            let index = self.array_index(row, col);
+            return Some(&mut self.array[index]);
+        }
+        None
+    }
+}
+
+impl UpperTraingularMatrix<f64> {
+    // Solves U * x = b from the last row up. Time Complexity is O(n^2)
+    pub fn back_substitution(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.dimension;
+        check_length(n, b)?;
+        let tolerance = pivot_tolerance(self.array.iter().copied(), n);
+        let mut x: Vec<f64> = vec![0.0; n];
+        for i in (0..n).rev() {
+            let pivot = *self.get(i, i);
+            if pivot.abs() <= tolerance {
+                return Err(MatrixError::Singular(i));
+            }
+            let sum: f64 = (i + 1..n).map(|j| self.get(i, j) * x[j]).sum();
+            x[i] = (b[i] - sum) / pivot;
+        }
+        Ok(x)
+    }
+}
+
+pub struct LowerTriangularMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+    zero: T,
+    index_order: IndexOrder,
+}
+
+impl<T: PartialEq> LowerTriangularMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        LowerTriangularMatrix {
+            dimension,
+            array,
+            zero: T::zero(),
+            index_order: IndexOrder::ColumnMajor,
+        }
+    }
+
+    fn row_major_index(row: usize, col: usize) -> usize {
+        (row * (row + 1) / 2) + col
+    }
+
+    // The columns before hold n, n - 1, ... elements and the column starts at its diagonal.
+    fn column_major_index(&self, row: usize, col: usize) -> usize {
+        (self.dimension * col - col * (col + 1) / 2) + row
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for LowerTriangularMatrix<T> {
+    fn array_size(row: usize, _col: usize) -> usize {
+        row * (row + 1) / 2
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        return match self.index_order {
+            IndexOrder::RowMajor => Self::row_major_index(row, col),
+            IndexOrder::ColumnMajor => self.column_major_index(row, col),
+        };
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if row >= col {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if row >= col {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        if row >= col {
+            let index = self.array_index(row, col);
+            return Some(&mut self.array[index]);
+        }
+        None
+    }
+}
+
+impl LowerTriangularMatrix<f64> {
+    // Solves L * x = b from the first row down. Time Complexity is O(n^2)
+    pub fn forward_substitution(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.dimension;
+        check_length(n, b)?;
+        let tolerance = pivot_tolerance(self.array.iter().copied(), n);
+        let mut x: Vec<f64> = vec![0.0; n];
+        for i in 0..n {
+            let pivot = *self.get(i, i);
+            if pivot.abs() <= tolerance {
+                return Err(MatrixError::Singular(i));
+            }
+            let sum: f64 = (0..i).map(|j| self.get(i, j) * x[j]).sum();
+            x[i] = (b[i] - sum) / pivot;
+        }
+        Ok(x)
+    }
+}
+
+pub struct DiagonalMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+    zero: T,
+}
+
+impl<T: PartialEq> DiagonalMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        DiagonalMatrix {
+            dimension,
+            array,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for DiagonalMatrix<T> {
+    fn array_size(row: usize, _col: usize) -> usize {
+        row
+    }
+
+    fn array_index(&self, row: usize, _col: usize) -> usize {
+        row
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if row == col {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if row == col {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        (row == col).then(|| &mut self.array[row])
+    }
+}
+
+pub struct ToeplitzMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+}
+
+impl<T: PartialEq> ToeplitzMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        ToeplitzMatrix { dimension, array }
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for ToeplitzMatrix<T> {
+    fn array_size(rows: usize, cols: usize) -> usize {
+        (rows + cols).saturating_sub(1)
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        // The first row keeps the diagonals on and above the main one, the first column the ones below it.
+        if row <= col {
+            col - row
+        } else {
+            self.dimension + row - col - 1
+        }
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        return self.array.get(self.array_index(row, col));
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        self.array.set(self.array_index(row, col), val);
+    }
+
+    // Only the corners are diagonals of a single element.
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        let index = self.array_index(row, col);
+        (row.abs_diff(col) + 1 == self.dimension).then(|| &mut self.array[index])
+    }
+}
+
+pub struct SymmetricMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    array: HeapArray<T>,
+}
+
+impl<T: PartialEq> SymmetricMatrix<T> {
+    pub fn new(dimension: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::array_size(dimension, dimension));
+        array.fill(T::zero());
+        SymmetricMatrix { dimension, array }
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for SymmetricMatrix<T> {
+    fn array_size(row: usize, _col: usize) -> usize {
+        row * (row + 1) / 2
+    }
+
+    // Only the lower triangle is kept in row major order, an element above the diagonal is its mirror.
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        let (row, col) = (row.max(col), row.min(col));
+        (row * (row + 1) / 2) + col
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        self.array.get(self.array_index(row, col))
+    }
+
+    // Sets the mirrored element as well.
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        self.array.set(self.array_index(row, col), val);
+    }
+
+    // Only the diagonal isn't shared with a mirrored element.
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        let index = self.array_index(row, col);
+        (row == col).then(|| &mut self.array[index])
+    }
+}
+
+/*
+NOTE:
+A Banded Matrix keeps the diagonals from lower below the main one up to upper above it, one after another like the
+Tridiagonal Matrix, which is the band with a single diagonal on each side. The diagonal at col - row = d holds n - |d|
+elements and an element sits at min(row, col) inside its diagonal.
+*/
+pub struct BandedMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    lower: usize,
+    upper: usize,
+    array: HeapArray<T>,
+    zero: T,
+}
+
+impl<T: PartialEq> BandedMatrix<T> {
+    // Bandwidths wider than the Matrix are cut down to it.
+    pub fn new(dimension: usize, lower: usize, upper: usize) -> Self
+    where
+        T: Numeric,
+    {
+        let lower = lower.min(dimension.saturating_sub(1));
+        let upper = upper.min(dimension.saturating_sub(1));
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::band_size(dimension, lower, upper));
+        array.fill(T::zero());
+        BandedMatrix {
+            dimension,
+            lower,
+            upper,
+            array,
+            zero: T::zero(),
+        }
+    }
+
+    // Number of elements inside the band.
+    fn band_size(n: usize, lower: usize, upper: usize) -> usize {
+        let lower = lower.min(n.saturating_sub(1));
+        let upper = upper.min(n.saturating_sub(1));
+        n * (lower + upper + 1) - lower * (lower + 1) / 2 - upper * (upper + 1) / 2
+    }
+
+    fn in_band(&self, row: usize, col: usize) -> bool {
+        row <= col + self.lower && col <= row + self.upper
+    }
+
+    // Index of the first element of the diagonal below (row > col) or above the main one.
+    fn diagonal_offset(&self, row: usize, col: usize) -> usize {
+        let (n, l) = (self.dimension, self.lower);
+        let main = l * n - l * (l + 1) / 2;
+        if row >= col {
+            let d = row - col;
+            main - (d * n - d * (d + 1) / 2)
+        } else {
+            let d = col - row;
+            main + d * n - d * (d - 1) / 2
+        }
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for BandedMatrix<T> {
+    // The size depends on the bandwidths, so without them the band may cover the whole Matrix.
+    fn array_size(row: usize, col: usize) -> usize {
+        row * col
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        self.diagonal_offset(row, col) + row.min(col)
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if self.in_band(row, col) {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if self.in_band(row, col) {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        if self.in_band(row, col) {
+            let index = self.array_index(row, col);
+            return Some(&mut self.array[index]);
+        }
+        None
+    }
+}
+
+/*
+NOTE:
+The blocks are kept one after another, each of them in row major order. Every block before the last one is full, so
+the block k starts at k * size * size and only the last one can be smaller.
+*/
+pub struct BlockDiagonalMatrix<T>
+where
+    T: PartialEq,
+{
+    dimension: usize,
+    block_size: usize,
+    array: HeapArray<T>,
+    zero: T,
+}
+
+impl<T: PartialEq> BlockDiagonalMatrix<T> {
+    pub fn new(dimension: usize, block_size: usize) -> Self
+    where
+        T: Numeric,
+    {
+        if block_size == 0 {
+            panic!("Block size can't be 0!");
+        }
+        let block_size = block_size.min(dimension.max(1));
+        let mut array: HeapArray<T> =
+            HeapArray::with_capacity(Self::blocks_size(dimension, block_size));
+        array.fill(T::zero());
+        BlockDiagonalMatrix {
+            dimension,
+            block_size,
+            array,
+            zero: T::zero(),
+        }
+    }
+
+    // Number of elements inside the blocks.
+    fn blocks_size(n: usize, size: usize) -> usize {
+        let size = size.min(n.max(1));
+        (n / size) * size * size + (n % size) * (n % size)
+    }
+
+    fn in_block(&self, row: usize, col: usize) -> bool {
+        row / self.block_size == col / self.block_size
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for BlockDiagonalMatrix<T> {
+    // The size depends on the block size, so without it a single block may cover the whole Matrix.
+    fn array_size(row: usize, col: usize) -> usize {
+        row * col
+    }
+
+    fn array_index(&self, row: usize, col: usize) -> usize {
+        let start = row / self.block_size * self.block_size;
+        let width = self.block_size.min(self.dimension - start);
+        start * self.block_size + (row - start) * width + (col - start)
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.dimension, self.dimension)
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        if self.in_block(row, col) {
+            return self.array.get(self.array_index(row, col));
+        }
+        &self.zero
+    }
+
+    fn set(&mut self, row: usize, col: usize, val: T) {
+        self.assert_bounds(row, col);
+        if self.in_block(row, col) {
+            self.array.set(self.array_index(row, col), val);
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        if self.in_block(row, col) {
+            let index = self.array_index(row, col);
+            return Some(&mut self.array[index]);
+        }
+        None
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub(crate) struct SparseMatrixElement<T> {
+    row: usize,
+    column: usize,
+    value: T,
+}
+
+impl<T: Copy> Clone for SparseMatrixElement<T> {
+    fn clone(&self) -> Self {
+        SparseMatrixElement {
+            row: self.row,
+            column: self.column,
+            value: self.value,
+        }
+    }
+}
+
+impl<T: Copy> Copy for SparseMatrixElement<T> {}
+
+pub struct SparseMatrix<T>
+where
+    T: PartialEq,
+{
+    rows: usize,
+    columns: usize,
+    nonzero_count: usize,
+    array: HeapArray<SparseMatrixElement<T>>,
+    zero: T,
+}
+
+impl<T: Numeric> SparseMatrix<T> {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        let array: HeapArray<SparseMatrixElement<T>> =
+            HeapArray::with_capacity(Self::array_size(rows, columns));
+        SparseMatrix {
+            rows,
+            columns,
+            nonzero_count: 0,
+            array: array,
+            zero: T::zero(),
+        }
+    }
+
+    // Only keeps room for the sorted non-zero elements, set grows the array when a new element doesn't fit.
+    fn from_sorted(rows: usize, columns: usize, elements: &[(usize, usize, T)]) -> Self {
+        let size = elements.len().max(1).min(Self::array_size(rows, columns));
+        let mut array: HeapArray<SparseMatrixElement<T>> = HeapArray::with_capacity(size);
+        for &(row, column, value) in elements {
+            array.push(SparseMatrixElement { row, column, value });
+        }
+        SparseMatrix {
+            rows,
+            columns,
+            nonzero_count: elements.len(),
+            array,
+            zero: T::zero(),
+        }
+    }
+
+    // A missing element gets a zero slot, which stays stored until set writes zero to it.
+    fn slot(&mut self, row: usize, col: usize) -> &mut T {
+        self.assert_bounds(row, col);
+        let index = match self.search(row, col) {
+            Ok(index) => index,
+            Err(index) => {
+                self.insert(
+                    index,
+                    SparseMatrixElement {
+                        row,
+                        column: col,
+                        value: T::zero(),
+                    },
+                );
+                index
+            }
+        };
+        &mut self.array[index].value
+    }
+}
+
+/*
+NOTE:
+The array keeps the elements in row major order, which makes it the coordinate (COO) format. A lookup is a Binary
+Search over the (row, column) pairs, while setting a new element shifts the elements after it.
+*/
+impl<T: PartialEq> SparseMatrix<T> {
+    // Binary Search for the element, returns the index where it belongs when it is missing.
+    fn search(&self, row: usize, col: usize) -> Result<usize, usize> {
+        let mut low: usize = 0;
+        let mut high: usize = self.array.get_len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let ele = self.array.get(mid);
+            match (ele.row, ele.column).cmp(&(row, col)) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    fn position(&self, row: usize, col: usize) -> Option<usize> {
+        self.search(row, col).ok()
+    }
+
+    // Places a new element at the index search returned for it and grows the array when it is full.
+    fn insert(&mut self, index: usize, element: SparseMatrixElement<T>) {
+        if self.nonzero_count == Self::array_size(self.rows, self.columns) {
+            panic!("Sparse Matrix non-zero values count will exceed zero values count!")
+        }
+        if self.array.get_len() == self.array.get_size() {
+            let size = (self.array.get_size() * 2).min(Self::array_size(self.rows, self.columns));
+            self.array.resize(size).unwrap();
+        }
+        self.array.insert(index, element);
+        self.nonzero_count += 1;
+    }
+
+    pub fn nonzero_count(&self) -> usize {
+        self.nonzero_count
+    }
+
+    // Iterates over the non-zero elements in row major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.array
+            .iter()
+            .map(|ele| (ele.row, ele.column, &ele.value))
+    }
+
+    // Non-zero elements of the row as (column, value). Time Complexity is O(log n + k)
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        let start = self.search(row, 0).unwrap_or_else(|index| index);
+        (start..self.array.get_len())
+            .map(|index| self.array.get(index))
+            .take_while(move |ele| ele.row == row)
+            .map(|ele| (ele.column, &ele.value))
+    }
+
+    // Non-zero elements of the column as (row, value). Time Complexity is O(n)
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.array
+            .iter()
+            .filter(move |ele| ele.column == col)
+            .map(|ele| (ele.row, &ele.value))
+    }
+
+    // Time Complexity is O(n + rows)
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Numeric,
+    {
+        CsrMatrix {
+            compressed: CompressedMatrix::from_sorted(
+                self.rows,
+                self.columns,
+                self.iter().map(|(row, col, val)| (row, col, *val)),
+            ),
+        }
+    }
+
+    // Time Complexity is O(n + rows + columns)
+    pub fn to_csc(&self) -> CscMatrix<T>
+    where
+        T: Numeric,
+    {
+        self.to_csr().to_csc()
+    }
+}
+
+impl<T: PartialEq> MatrixOperations<T> for SparseMatrix<T> {
+    fn array_size(row: usize, col: usize) -> usize {
+        (row * col) / 2
+    }
+
+    // The elements have no fixed index, search finds them instead.
+    fn array_index(&self, _row: usize, _col: usize) -> usize {
+        0
+    }
+
+    fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    // Time Complexity is O(log n)
+    fn get(&self, row: usize, col: usize) -> &T {
+        self.assert_bounds(row, col);
+        match self.search(row, col) {
+            Ok(index) => &self.array.get(index).value,
+            Err(_) => &self.zero,
+        }
+    }
+
+    // Time Complexity is O(n) as the elements after a new one move up.
+    fn set(&mut self, row: usize, col: usize, val: T)
+    where
+        T: PartialEq,
+    {
+        self.assert_bounds(row, col);
+        // Existing elements are replaced in place and setting a zero value removes them.
+        match self.search(row, col) {
+            Ok(index) if val == self.zero => {
+                self.array.delete(index);
+                self.nonzero_count -= 1;
+            }
+            Ok(index) => self.array.set(
+                index,
+                SparseMatrixElement {
+                    row,
+                    column: col,
+                    value: val,
+                },
+            ),
+            Err(_) if val == self.zero => {}
+            Err(index) => self.insert(
+                index,
+                SparseMatrixElement {
+                    row,
+                    column: col,
+                    value: val,
+                },
+            ),
+        }
+    }
+
+    // Only a stored element has a slot, writing zero to it keeps it stored until the next set.
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.assert_bounds(row, col);
+        let index = self.position(row, col)?;
+        Some(&mut self.array[index].value)
+    }
+}
+
+/*
+NOTE:
+Compressed Sparse Row keeps the non-zero values row by row. The columns and values of row i sit between offsets[i]
+and offsets[i + 1] sorted by column, so a row is a contiguous range and an element is a Binary Search inside its row.
+Compressed Sparse Column is the same structure with the roles of rows and columns swapped, so both share it. The major
+index is the compressed one, i.e. the row for CSR and the column for CSC.
+*/
+struct CompressedMatrix<T> {
+    majors: usize,
+    minors: usize,
+    // Holds majors + 1 offsets into the indices and values.
+    offsets: HeapArray<usize>,
+    indices: HeapArray<usize>,
+    values: HeapArray<T>,
+    zero: T,
+}
+
+fn heap_array<T>(values: Vec<T>) -> HeapArray<T> {
+    let mut array = HeapArray::with_capacity(values.len());
+    for val in values {
+        array.push(val);
+    }
+    array
+}
+
+impl<T: Numeric> CompressedMatrix<T> {
+    // The elements must be sorted by their major and then by their minor index. Zero values are skipped.
+    fn from_sorted(
+        majors: usize,
+        minors: usize,
+        elements: impl IntoIterator<Item = (usize, usize, T)>,
+    ) -> Self {
+        let mut offsets: Vec<usize> = vec![0; majors + 1];
+        let mut indices: Vec<usize> = Vec::new();
+        let mut values: Vec<T> = Vec::new();
+        for (major, minor, val) in elements {
+            if !val.is_zero() {
+                offsets[major + 1] += 1;
+                indices.push(minor);
+                values.push(val);
+            }
+        }
+        let mut total: usize = 0;
+        for offset in offsets.iter_mut() {
+            total += *offset;
+            *offset = total;
+        }
+        CompressedMatrix {
+            majors,
+            minors,
+            offsets: heap_array(offsets),
+            indices: heap_array(indices),
+            values: heap_array(values),
+            zero: T::zero(),
+        }
+    }
+
+    // The error reports the indices in (major, minor) order, so CSC checks its (row, column) bounds first.
+    fn check_bounds(&self, major: usize, minor: usize) {
+        if let Err(error) = check_bounds((major, minor), (self.majors, self.minors)) {
+            panic!("{}", error);
+        }
+    }
+
+    fn nonzero_count(&self) -> usize {
+        self.values.get_len()
+    }
+
+    fn lane_range(&self, major: usize) -> Range<usize> {
+        *self.offsets.get(major)..*self.offsets.get(major + 1)
+    }
+
+    // Time Complexity is O(log k) where k is the non-zero count of the lane.
+    fn get(&self, major: usize, minor: usize) -> &T {
+        self.check_bounds(major, minor);
+        let Range {
+            start: mut low,
+            end: mut high,
+        } = self.lane_range(major);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.indices.get(mid).cmp(&minor) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return self.values.get(mid),
+            }
+        }
+        &self.zero
+    }
+
+    fn lane(&self, major: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.lane_range(major)
+            .map(|index| (*self.indices.get(index), self.values.get(index)))
+    }
+
+    // Elements of the lanes across the major index as (major, value). Time Complexity is O(majors * log k)
+    fn cross_lane(&self, minor: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        (0..self.majors).filter_map(move |major| {
+            let val = self.get(major, minor);
+            (!val.is_zero()).then_some((major, val))
+        })
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        (0..self.majors).flat_map(move |major| {
+            self.lane_range(major)
+                .map(move |index| (major, *self.indices.get(index), self.values.get(index)))
+        })
+    }
+
+    // Swaps the roles of the indices, which turns CSR into CSC and back. Counting Sort by the minor index keeps the
+    // major indices sorted inside every new lane. Time Complexity is O(n + majors + minors)
+    fn transpose(&self) -> Self {
+        let mut offsets: Vec<usize> = vec![0; self.minors + 1];
+        for (_, minor, _) in self.iter() {
+            offsets[minor + 1] += 1;
+        }
+        let mut total: usize = 0;
+        for offset in offsets.iter_mut() {
+            total += *offset;
+            *offset = total;
+        }
+        let mut next: Vec<usize> = offsets[..self.minors].to_vec();
+        let mut indices: Vec<usize> = vec![0; self.nonzero_count()];
+        let mut values: Vec<T> = vec![T::zero(); self.nonzero_count()];
+        for (major, minor, val) in self.iter() {
+            let slot = next[minor];
+            indices[slot] = major;
+            values[slot] = *val;
+            next[minor] += 1;
+        }
+        CompressedMatrix {
+            majors: self.minors,
+            minors: self.majors,
+            offsets: heap_array(offsets),
+            indices: heap_array(indices),
+            values: heap_array(values),
+            zero: T::zero(),
+        }
+    }
+
+    fn to_coo(&self, rows: usize, columns: usize) -> SparseMatrix<T> {
+        let mut res = SparseMatrix::new(rows, columns);
+        for (row, col, val) in self.iter() {
+            res.set(row, col, *val);
+        }
+        res
+    }
+}
+
+pub struct CsrMatrix<T> {
+    compressed: CompressedMatrix<T>,
+}
+
+impl<T: Numeric> CsrMatrix<T> {
+    pub fn rows(&self) -> usize {
+        self.compressed.majors
+    }
+
+    pub fn columns(&self) -> usize {
+        self.compressed.minors
+    }
+
+    pub fn nonzero_count(&self) -> usize {
+        self.compressed.nonzero_count()
+    }
+
+    // Time Complexity is O(log k) where k is the non-zero count of the row.
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        self.compressed.get(row, col)
+    }
+
+    // Non-zero elements of the row as (column, value). Time Complexity is O(k)
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        check_lane("Row", row, self.rows());
+        self.compressed.lane(row)
+    }
+
+    // Non-zero elements of the column as (row, value). Time Complexity is O(rows * log k)
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        check_lane("Column", col, self.columns());
+        self.compressed.cross_lane(col)
+    }
+
+    // Iterates over the non-zero elements in row major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.compressed.iter()
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            compressed: self.compressed.transpose(),
+        }
+    }
+
+    // The array form only has room for (rows * columns) / 2 elements and panics when the matrix doesn't fit.
+    pub fn to_coo(&self) -> SparseMatrix<T> {
+        self.compressed.to_coo(self.rows(), self.columns())
+    }
+
+    // Every value of the result is the dot product of a row with the vector. Time Complexity is O(n + rows)
+    pub fn multiply_vector(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        if x.len() != self.columns() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns(), 1),
+                found: (x.len(), 1),
+            });
+        }
+        Ok((0..self.rows())
+            .map(|row| {
+                self.row(row)
+                    .fold(T::zero(), |sum, (col, val)| sum + *val * x[col])
+            })
+            .collect())
+    }
+
+    /*
+    Gustavson's Algorithm builds the result row by row. Every element a(i, k) scales row k of the other matrix into an
+    accumulator, which remembers the columns it touched so only those are collected and reset for the next row.
+    Time Complexity is O(flops + rows * c log c) where c is the non-zero count of a result row.
+    */
+    pub fn multiply(&self, other: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError> {
+        if self.columns() != other.rows() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns(), other.columns()),
+                found: (other.rows(), other.columns()),
+            });
+        }
+        let mut accumulator: Vec<T> = vec![T::zero(); other.columns()];
+        let mut occupied: Vec<bool> = vec![false; other.columns()];
+        let mut touched: Vec<usize> = Vec::new();
+        let mut elements: Vec<(usize, usize, T)> = Vec::new();
+        for row in 0..self.rows() {
+            for (k, a) in self.row(row) {
+                for (col, b) in other.row(k) {
+                    if !occupied[col] {
+                        occupied[col] = true;
+                        touched.push(col);
+                    }
+                    accumulator[col] = accumulator[col] + *a * *b;
+                }
+            }
+            touched.sort_unstable();
+            for &col in touched.iter() {
+                elements.push((row, col, accumulator[col]));
+                accumulator[col] = T::zero();
+                occupied[col] = false;
+            }
+            touched.clear();
+        }
+        Ok(CsrMatrix {
+            compressed: CompressedMatrix::from_sorted(self.rows(), other.columns(), elements),
+        })
+    }
+}
+
+pub struct CscMatrix<T> {
+    compressed: CompressedMatrix<T>,
+}
+
+impl<T: Numeric> CscMatrix<T> {
+    pub fn rows(&self) -> usize {
+        self.compressed.minors
+    }
+
+    pub fn columns(&self) -> usize {
+        self.compressed.majors
+    }
+
+    pub fn nonzero_count(&self) -> usize {
+        self.compressed.nonzero_count()
+    }
+
+    fn check_bounds(&self, row: usize, col: usize) {
+        if let Err(error) = check_bounds((row, col), (self.rows(), self.columns())) {
+            panic!("{}", error);
+        }
+    }
+
+    // Time Complexity is O(log k) where k is the non-zero count of the column.
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        self.check_bounds(row, col);
+        self.compressed.get(col, row)
+    }
+
+    // Non-zero elements of the row as (column, value). Time Complexity is O(columns * log k)
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        check_lane("Row", row, self.rows());
+        self.compressed.cross_lane(row)
+    }
+
+    // Non-zero elements of the column as (row, value). Time Complexity is O(k)
+    pub fn column(&self, col: usize) -> impl Iterator<Item = (usize, &T)> + '_ {
+        check_lane("Column", col, self.columns());
+        self.compressed.lane(col)
+    }
+
+    // Iterates over the non-zero elements in column major order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.compressed
+            .iter()
+            .map(|(col, row, val)| (row, col, val))
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            compressed: self.compressed.transpose(),
+        }
+    }
+
+    // The array form only has room for (rows * columns) / 2 elements and panics when the matrix doesn't fit.
+    pub fn to_coo(&self) -> SparseMatrix<T> {
+        self.to_csr().to_coo()
+    }
+
+    // Every column scales its value of the vector into the result. Time Complexity is O(n + columns)
+    pub fn multiply_vector(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        if x.len() != self.columns() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns(), 1),
+                found: (x.len(), 1),
+            });
+        }
+        let mut res: Vec<T> = vec![T::zero(); self.rows()];
+        for (col, val) in x.iter().enumerate() {
+            for (row, a) in self.column(col) {
+                res[row] = res[row] + *a * *val;
+            }
+        }
+        Ok(res)
+    }
+
+    // Converts both matrices to CSR, multiplies them there and converts the product back.
+    pub fn multiply(&self, other: &CscMatrix<T>) -> Result<CscMatrix<T>, MatrixError> {
+        Ok(self.to_csr().multiply(&other.to_csr())?.to_csc())
+    }
+}
+
+/*
+NOTE:
+A Sparse Matrix kept as a Linked List of rows where every row node holds its own Linked List of columns. Only the rows
+and columns with non-zero values get a node and both lists are sorted, so the nodes can be merged row by row.
+Setting a zero value removes the column node and a row without columns is removed as well.
+*/
+struct ColumnNode<T> {
+    column: usize,
+    value: T,
+    next: Option<HeapBox<ColumnNode<T>>>,
+}
+
+struct RowNode<T> {
+    row: usize,
+    columns: Option<HeapBox<ColumnNode<T>>>,
+    next: Option<HeapBox<RowNode<T>>>,
+}
+
+pub struct LinkedSparseMatrix<T>
+where
+    T: PartialEq,
+{
+    rows: usize,
+    columns: usize,
+    nonzero_count: usize,
+    head: Option<HeapBox<RowNode<T>>>,
+    zero: T,
+}
+
+impl<T: Numeric> LinkedSparseMatrix<T> {
+    pub fn new(rows: usize, columns: usize) -> Self {
+        LinkedSparseMatrix {
+            rows,
+            columns,
+            nonzero_count: 0,
+            head: None,
+            zero: T::zero(),
+        }
+    }
+
+    pub fn nonzero_count(&self) -> usize {
+        self.nonzero_count
+    }
+
+    fn check_bounds(&self, row: usize, col: usize) {
+        if let Err(error) = check_bounds((row, col), (self.rows, self.columns)) {
+            panic!("{}", error);
+        }
+    }
+
+    fn find_row(&self, row: usize) -> Option<&RowNode<T>> {
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            if node.row >= row {
+                return if node.row == row { Some(node) } else { None };
+            }
+            current = node.next.as_deref();
+        }
+        None
+    }
+
+    // Time Complexity is O(r + c) where r and c are the non-zero rows and the non-zero columns of the row.
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        self.check_bounds(row, col);
+        let mut current = self.find_row(row).and_then(|node| node.columns.as_deref());
+        while let Some(node) = current {
+            if node.column >= col {
+                if node.column == col {
+                    return &node.value;
+                }
+                break;
+            }
+            current = node.next.as_deref();
+        }
+        &self.zero
+    }
+
+    // Time Complexity is O(r + c)
+    pub fn set(&mut self, row: usize, col: usize, val: T) {
+        self.check_bounds(row, col);
+        let is_zero = val.is_zero();
+
+        let mut row_link = &mut self.head;
+        while row_link.as_ref().is_some_and(|node| node.row < row) {
+            row_link = &mut row_link.as_mut().unwrap().next;
+        }
+        if row_link.as_ref().is_none_or(|node| node.row != row) {
+            if is_zero {
+                return;
+            }
+            let next = row_link.take();
+            *row_link = Some(HeapBox::new(RowNode {
+                row,
+                columns: None,
+                next,
+            }));
+        }
+
+        let row_node = row_link.as_mut().unwrap();
+        let mut col_link = &mut row_node.columns;
+        while col_link.as_ref().is_some_and(|node| node.column < col) {
+            col_link = &mut col_link.as_mut().unwrap().next;
+        }
+        match col_link {
+            Some(node) if node.column == col => {
+                if is_zero {
+                    let next = node.next.take();
+                    *col_link = next;
+                    self.nonzero_count -= 1;
+                } else {
+                    node.value = val;
+                }
+            }
+            _ => {
+                if !is_zero {
+                    let next = col_link.take();
+                    *col_link = Some(HeapBox::new(ColumnNode {
+                        column: col,
+                        value: val,
+                        next,
+                    }));
+                    self.nonzero_count += 1;
+                }
+            }
+        }
+
+        if row_node.columns.is_none() {
+            let next = row_node.next.take();
+            *row_link = next;
+        }
+    }
+
+    // Iterates over the non-zero elements in row major order.
+    pub fn iter(&self) -> LinkedSparseMatrixIterator<'_, T> {
+        LinkedSparseMatrixIterator {
+            row: self.head.as_deref(),
+            column: self
+                .head
+                .as_deref()
+                .and_then(|node| node.columns.as_deref()),
+        }
+    }
+
+    fn check_dimensions(&self, other: &Self) {
+        if self.rows != other.rows || self.columns != other.columns {
+            panic!("Matrix dimensions must match!");
+        }
+    }
+
+    // Time Complexity is O(n * (r + c)) where n is the non-zero count of the other matrix.
+    pub fn add(&self, other: &Self) -> Self {
+        self.check_dimensions(other);
+        let mut res = self.clone();
+        for (row, col, val) in other.iter() {
+            let sum = *res.get(row, col) + *val;
+            res.set(row, col, sum);
+        }
+        res
+    }
+
+    // Every non-zero element of a row is multiplied with the non-zero elements of the matching row of the other matrix.
+    pub fn multiply(&self, other: &Self) -> Self {
+        if self.columns != other.rows {
+            panic!("Matrix columns must match the rows of the other Matrix!");
+        }
+        let mut res = Self::new(self.rows, other.columns);
+        for (row, k, a) in self.iter() {
+            let mut current = other.find_row(k).and_then(|node| node.columns.as_deref());
+            while let Some(node) = current {
+                let sum = *res.get(row, node.column) + *a * node.value;
+                res.set(row, node.column, sum);
+                current = node.next.as_deref();
+            }
+        }
+        res
+    }
+
+    pub fn from_sparse(matrix: &SparseMatrix<T>) -> Self {
+        let mut res = Self::new(matrix.rows, matrix.columns);
+        for ele in matrix.array.iter() {
+            res.set(ele.row, ele.column, ele.value);
+        }
+        res
+    }
+
+    // The array form only has room for (rows * columns) / 2 elements and panics when the matrix doesn't fit.
+    pub fn to_sparse(&self) -> SparseMatrix<T> {
+        let mut res = SparseMatrix::new(self.rows, self.columns);
+        for (row, col, val) in self.iter() {
+            res.set(row, col, *val);
+        }
+        res
+    }
+}
+
+impl<T: Numeric> Clone for LinkedSparseMatrix<T> {
+    fn clone(&self) -> Self {
+        let mut res = Self::new(self.rows, self.columns);
+        for (row, col, val) in self.iter() {
+            res.set(row, col, *val);
+        }
+        res
+    }
+}
+
+impl<T: Numeric> PartialEq for LinkedSparseMatrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+            && self.columns == other.columns
+            && self.nonzero_count == other.nonzero_count
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Display + Numeric> Display for LinkedSparseMatrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                write!(f, "{:>3} ", self.get(i, j))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug + Numeric> Debug for LinkedSparseMatrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> Drop for LinkedSparseMatrix<T> {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut row) = current {
+            let mut column = row.columns.take();
+            while let Some(mut node) = column {
+                column = node.next.take();
+            }
+            current = row.next.take();
+        }
+    }
+}
+
+pub struct LinkedSparseMatrixIterator<'a, T> {
+    row: Option<&'a RowNode<T>>,
+    column: Option<&'a ColumnNode<T>>,
+}
+
+impl<'a, T> Iterator for LinkedSparseMatrixIterator<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(row) = self.row {
+            if let Some(column) = self.column {
+                self.column = column.next.as_deref();
+                return Some((row.row, column.column, &column.value));
+            }
+            self.row = row.next.as_deref();
+            self.column = self.row.and_then(|node| node.columns.as_deref());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod diagonal_matrix {
+    use crate::structs::matrices::{DiagonalMatrix, MatrixOperations};
+
+    #[test]
+    fn test_new() {
+        let matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
+        assert_eq!(
+            matrix.array.get_size(),
+            5,
+            "Verifying diagonal matrix array creation"
+        );
+        assert_eq!(
+            matrix.array.get_len(),
+            5,
+            "Verifying diagonal matrix array creation"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
+        matrix.set(0, 0, 10);
+        assert_eq!(
+            matrix.array[0], 10,
+            "Testing setting a diagonal matrix element"
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
+        let val: i8 = 10;
+        matrix.set(0, 0, val);
+        matrix.set(3, 3, val);
+        assert_eq!(
+            matrix.get(0, 0),
+            &val,
+            "Testing fetching a diagonal matrix element"
+        );
+        assert_eq!(
+            matrix.get(3, 3),
+            &val,
+            "Testing fetching a diagonal matrix element"
+        );
+        assert_eq!(
+            matrix.get(0, 3),
+            &0,
+            "Testing fetching a null diagonal matrix element"
+        );
+    }
+
+    #[test]
+    fn test_checked_get() {
+        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(3);
+        matrix.set(2, 2, 4);
+        assert_eq!(
+            matrix.checked_get(2, 2),
+            Some(&4),
+            "Invalid checked element!"
+        );
+        assert_eq!(
+            matrix.checked_get(3, 0),
+            None,
+            "Row past the Matrix must be None!"
+        );
+        assert_eq!(
+            matrix.checked_get(0, 3),
+            None,
+            "Column past the Matrix must be None!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 3) is out of bounds for a 3x3 Matrix!")]
+    fn test_set_panic() {
+        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(3);
+        matrix.set(3, 3, 1);
+    }
+}
+
+#[cfg(test)]
+mod lower_triangular_matrix {
+    use crate::structs::matrices::{LowerTriangularMatrix, MatrixOperations};
+
+    #[test]
+    fn test_new() {
+        let dimension: usize = 5;
+        let total_elements: usize = dimension * (dimension + 1) / 2;
+        let matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(dimension);
+        assert_eq!(
+            matrix.array.get_size(),
+            total_elements,
+            "Verifying lower triangular matrix array size"
+        );
+        assert_eq!(
+            matrix.array.get_len(),
+            total_elements,
+            "Verifying lower triangular matrix array length"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(5);
+        matrix.set(4, 3, 10);
+        matrix.set(1, 3, 10);
+        assert_eq!(
+            matrix.array[13], 10,
+            "Testing a lower triangular matrix element set"
+        );
+        assert_eq!(
+            matrix.array[3], 0,
+            "Testing a lower triangular matrix element set"
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(5);
+        let val: i8 = 10;
+        matrix.set(0, 0, val);
+        matrix.set(4, 3, val);
+        assert_eq!(
+            matrix.get(0, 0),
+            &val,
+            "Testing a lower triangular matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(4, 3),
+            &val,
+            "Testing a lower triangular matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(0, 3),
+            &0,
+            "Testing a null lower triangular matrix element fetch"
+        );
+    }
+}
+
+#[cfg(test)]
+mod upper_triangular_matrix {
+    use crate::structs::matrices::{MatrixOperations, UpperTraingularMatrix};
+
+    #[test]
+    fn test_new() {
+        let dimension: usize = 5;
+        let total_elements: usize = dimension * (dimension + 1) / 2;
+        let matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(dimension);
+        assert_eq!(
+            matrix.array.get_size(),
+            total_elements,
+            "Verifying upper triangular matrix array size"
+        );
+        assert_eq!(
+            matrix.array.get_len(),
+            total_elements,
+            "Verifying upper triangular matrix array length"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(5);
+        matrix.set(3, 4, 10);
+        matrix.set(1, 3, 10);
+        assert_eq!(
+            matrix.array[13], 10,
+            "Testing a upper triangular matrix element set"
+        );
+        // assert_eq!(matrix.array[7], 0, "Testing a upper triangular matrix element set");
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(5);
+        let val: i8 = 10;
+        matrix.set(0, 0, val);
+        matrix.set(3, 4, val);
+        assert_eq!(
+            matrix.get(0, 0),
+            &val,
+            "Testing a upper triangular matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(3, 4),
+            &val,
+            "Testing a upper triangular matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(3, 0),
+            &0,
+            "Testing a null upper triangular matrix element fetch"
+        );
+    }
+}
+
+#[cfg(test)]
+mod toeplitz_matrix {
+    use crate::structs::matrices::{MatrixOperations, ToeplitzMatrix};
+    use rand::random;
+
+    #[test]
+    fn test_new() {
+        // n x n matrix. e.g. 5 x 5
+        let dimension: usize = 5;
+        // total = n + n - 1
+        let total_elements: usize = 9;
+        let matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(dimension);
+        assert_eq!(
+            matrix.array.get_size(),
+            total_elements,
+            "Verifying toeplitz matrix array size"
+        );
+        assert_eq!(
+            matrix.array.get_len(),
+            total_elements,
+            "Verifying toeplitz matrix array length"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(5);
+        for i in 0..5 {
+            for j in 0..5 {
+                matrix.set(i, j, random())
+            }
+        }
+        assert_eq!(
+            matrix.get(3, 2),
+            matrix.get(2, 1),
+            "Testing a toeplitz  matrix element set"
+        );
+
+        // n + i - j - 1
+        let test_index: usize = 5 + 3 - 2 - 1;
+        assert_eq!(
+            matrix.get(3, 2),
+            &matrix.array[test_index],
+            "Testing a toeplitz  matrix element set"
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(5);
+        let val: i8 = 10;
+        matrix.set(0, 0, val);
+        matrix.set(3, 4, val);
+        assert_eq!(
+            matrix.get(0, 0),
+            &val,
+            "Testing a toeplitz matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(3, 4),
+            &val,
+            "Testing a toeplitz matrix element fetch"
+        );
+        assert_eq!(
+            matrix.get(3, 0),
+            &0,
+            "Testing a null upper triangular matrix element fetch"
+        );
+    }
+}
+
+#[cfg(test)]
+mod symmetric_matrix {
+    use crate::structs::matrices::{MatrixOperations, SymmetricMatrix};
+
+    #[test]
+    fn test_new() {
+        // n x n matrix with n * (n + 1) / 2 elements in the lower triangle.
+        let matrix: SymmetricMatrix<i8> = SymmetricMatrix::new(5);
+        assert_eq!(
+            matrix.array.get_size(),
+            15,
+            "Verifying symmetric matrix array size"
+        );
+    }
+
+    #[test]
+    fn test_set_get() {
+        let mut matrix: SymmetricMatrix<i8> = SymmetricMatrix::new(4);
+        matrix.set(0, 2, 7);
+        matrix.set(3, 1, -2);
+        matrix.set(2, 2, 5);
+        assert_eq!(matrix.get(2, 0), &7, "Upper element must be mirrored!");
+        assert_eq!(matrix.get(1, 3), &-2, "Lower element must be mirrored!");
+        assert_eq!(matrix.get(2, 2), &5, "Invalid diagonal element!");
+        assert_eq!(matrix.get(0, 1), &0, "Unset element must be zero!");
+        // i * (i + 1) / 2 + j for the lower element
+        assert_eq!(matrix.array_index(0, 2), 3, "Invalid symmetric index!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 0) is out of bounds for a 3x3 Matrix!")]
+    fn test_out_of_bounds() {
+        let matrix: SymmetricMatrix<i8> = SymmetricMatrix::new(3);
+        matrix.get(3, 0);
+    }
+}
+
+#[cfg(test)]
+mod banded_matrix {
+    use crate::structs::matrices::{BandedMatrix, MatrixOperations, TridiagonalMatrix};
+
+    #[test]
+    fn test_new() {
+        // 6 x 6 matrix with 2 diagonals below and 1 above: 4 + 5 + 6 + 5 elements.
+        let matrix: BandedMatrix<i8> = BandedMatrix::new(6, 2, 1);
+        assert_eq!(
+            matrix.array.get_size(),
+            20,
+            "Verifying banded matrix array size"
+        );
+        let matrix: BandedMatrix<i8> = BandedMatrix::new(3, 5, 5);
+        assert_eq!(
+            (matrix.lower, matrix.upper, matrix.array.get_size()),
+            (2, 2, 9),
+            "Bandwidths must be cut down to the Matrix!"
+        );
+    }
+
+    #[test]
+    fn test_tridiagonal_layout() {
+        let banded: BandedMatrix<i8> = BandedMatrix::new(5, 1, 1);
+        let tridiagonal: TridiagonalMatrix<i8> = TridiagonalMatrix::new(5);
+        for i in 0..5_usize {
+            for j in i.saturating_sub(1)..(i + 2).min(5) {
+                assert_eq!(
+                    banded.array_index(i, j),
+                    tridiagonal.array_index(i, j),
+                    "Band of width 1 must match the Tridiagonal layout!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_get() {
+        let mut matrix: BandedMatrix<i8> = BandedMatrix::new(6, 2, 1);
+        let mut val: i8 = 0;
+        for i in 0..6 {
+            for j in 0..6 {
+                val += 1;
+                matrix.set(i, j, val);
+            }
+        }
+        assert_eq!(matrix.get(2, 0), &13, "Invalid lowest diagonal element!");
+        assert_eq!(matrix.get(3, 3), &22, "Invalid main diagonal element!");
+        assert_eq!(matrix.get(4, 5), &30, "Invalid upper diagonal element!");
+        assert_eq!(matrix.get(3, 0), &0, "Element below the band must be zero!");
+        assert_eq!(matrix.get(0, 2), &0, "Element above the band must be zero!");
+        let mut indices: Vec<usize> = (0..6)
+            .flat_map(|i| (0..6).map(move |j| (i, j)))
+            .filter(|&(i, j)| matrix.in_band(i, j))
+            .map(|(i, j)| matrix.array_index(i, j))
+            .collect();
+        indices.sort();
+        assert_eq!(
+            indices,
+            (0..20).collect::<Vec<usize>>(),
+            "Every band element must have its own index!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod block_diagonal_matrix {
+    use crate::structs::matrices::{BlockDiagonalMatrix, MatrixOperations};
+
+    #[test]
+    fn test_new() {
+        // 7 x 7 matrix with blocks of 3: two full blocks and a 1 x 1 block.
+        let matrix: BlockDiagonalMatrix<i8> = BlockDiagonalMatrix::new(7, 3);
+        assert_eq!(
+            matrix.array.get_size(),
+            19,
+            "Verifying block diagonal matrix array size"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Block size can't be 0!")]
+    fn test_new_empty_block() {
+        let _: BlockDiagonalMatrix<i8> = BlockDiagonalMatrix::new(3, 0);
+    }
+
+    #[test]
+    fn test_set_get() {
+        let mut matrix: BlockDiagonalMatrix<i8> = BlockDiagonalMatrix::new(5, 2);
+        matrix.set(0, 1, 4);
+        matrix.set(3, 2, 6);
+        matrix.set(4, 4, 8);
+        matrix.set(1, 2, 9);
+        assert_eq!(matrix.get(0, 1), &4, "Invalid first block element!");
+        assert_eq!(matrix.get(3, 2), &6, "Invalid second block element!");
+        assert_eq!(matrix.get(4, 4), &8, "Invalid last block element!");
+        assert_eq!(matrix.get(1, 2), &0, "Element between blocks must be zero!");
+        // The second block starts at 2 * 2 and the last one at 4 * 2.
+        assert_eq!(matrix.array_index(3, 2), 6, "Invalid block index!");
+        assert_eq!(matrix.array_index(4, 4), 8, "Invalid last block index!");
+    }
+}
+
+#[cfg(test)]
+mod sparse_matrix {
+    use crate::structs::matrices::{MatrixOperations, SparseMatrix};
+
+    #[test]
+    fn test_new() {
+        // n x n matrix. e.g. 5 x 5
+        let row: usize = 5;
+        let col: usize = 5;
+
+        // total = n x n / 2
+        let max_nonzero_elements: usize = 12;
+        let matrix: SparseMatrix<i8> = SparseMatrix::new(row, col);
+        assert_eq!(
+            matrix.array.get_size(),
+            max_nonzero_elements,
+            "Invalid Sparse Matrix array size. It should be (row * col / 2)!"
+        );
+        assert_eq!(
+            matrix.array.get_len(),
+            0,
+            "Invalid Sparse Matrix array length. It should be 0!"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut matrix: SparseMatrix<i8> = SparseMatrix::new(4, 4);
+        matrix.set(2, 1, 7);
+        matrix.set(0, 3, 5);
+        matrix.set(2, 0, 2);
+        matrix.set(0, 0, 9);
+        assert_eq!(
+            matrix
+                .iter()
+                .map(|(r, c, v)| (r, c, *v))
+                .collect::<Vec<_>>(),
+            vec![(0, 0, 9), (0, 3, 5), (2, 0, 2), (2, 1, 7)],
+            "Sparse Matrix elements must be sorted in row major order!"
+        );
+        matrix.set(0, 3, 6);
+        assert_eq!(
+            matrix.nonzero_count(),
+            4,
+            "Replacing must not change the count!"
+        );
+        matrix.set(2, 0, 0);
+        matrix.set(1, 1, 0);
+        assert_eq!(matrix.nonzero_count(), 3, "Zero element must be removed!");
+        assert_eq!(
+            matrix.row(2).map(|(c, v)| (c, *v)).collect::<Vec<_>>(),
+            vec![(1, 7)],
+            "Invalid Sparse Matrix row!"
+        );
+        assert_eq!(
+            matrix.column(3).map(|(r, v)| (r, *v)).collect::<Vec<_>>(),
+            vec![(0, 6)],
+            "Invalid Sparse Matrix column!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Sparse Matrix non-zero values count will exceed zero values count!")]
+    fn test_set_panic() {
+        let mut matrix: SparseMatrix<i8> = SparseMatrix::new(2, 2);
+        matrix.set(0, 0, 1);
+        matrix.set(1, 1, 1);
+        matrix.set(0, 1, 1);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut matrix: SparseMatrix<i8> = SparseMatrix::new(5, 5);
+        for i in (0..5).rev() {
+            matrix.set(i, 4 - i, i as i8 + 1);
+        }
+        for i in 0..5 {
+            assert_eq!(
+                matrix.get(i, 4 - i),
+                &(i as i8 + 1),
+                "Invalid Sparse Matrix element!"
+            );
+        }
+        for (row, col) in [(0, 0), (1, 1), (3, 0), (4, 4)] {
+            assert_eq!(matrix.get(row, col), &0, "Missing element must be zero!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod compressed_sparse_matrix {
+    use crate::structs::matrices::{MatrixError, MatrixOperations, SparseMatrix};
+
+    // | 1 0 2 0 |
+    // | 0 0 3 0 |
+    // | 4 5 0 6 |
+    fn coo() -> SparseMatrix<i32> {
+        let mut matrix = SparseMatrix::new(3, 4);
+        for (row, col, val) in [
+            (0, 0, 1),
+            (0, 2, 2),
+            (1, 2, 3),
+            (2, 0, 4),
+            (2, 1, 5),
+            (2, 3, 6),
+        ] {
+            matrix.set(row, col, val);
+        }
+        matrix
+    }
+
+    fn triplets<'a>(
+        iter: impl Iterator<Item = (usize, usize, &'a i32)>,
+    ) -> Vec<(usize, usize, i32)> {
+        iter.map(|(r, c, v)| (r, c, *v)).collect()
+    }
+
+    #[test]
+    fn test_csr() {
+        let csr = coo().to_csr();
+        assert_eq!(csr.nonzero_count(), 6, "Invalid CSR non-zero count!");
+        assert_eq!(
+            csr.compressed.offsets.iter().copied().collect::<Vec<_>>(),
+            vec![0, 2, 3, 6],
+            "Invalid CSR row offsets!"
+        );
+        assert_eq!(
+            csr.compressed.indices.iter().copied().collect::<Vec<_>>(),
+            vec![0, 2, 2, 0, 1, 3],
+            "Invalid CSR column indices!"
+        );
+        assert_eq!(csr.get(2, 1), &5, "Invalid CSR element!");
+        assert_eq!(csr.get(1, 3), &0, "Missing CSR element must be zero!");
+        assert_eq!(
+            csr.row(2).map(|(c, v)| (c, *v)).collect::<Vec<_>>(),
+            vec![(0, 4), (1, 5), (3, 6)],
+            "Invalid CSR row!"
+        );
+        assert_eq!(
+            csr.column(2).map(|(r, v)| (r, *v)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 3)],
+            "Invalid CSR column!"
+        );
+    }
+
+    #[test]
+    fn test_csc() {
+        let csc = coo().to_csc();
+        assert_eq!(
+            csc.compressed.offsets.iter().copied().collect::<Vec<_>>(),
+            vec![0, 2, 3, 5, 6],
+            "Invalid CSC column offsets!"
+        );
+        assert_eq!(
+            csc.compressed.indices.iter().copied().collect::<Vec<_>>(),
+            vec![0, 2, 2, 0, 1, 2],
+            "Invalid CSC row indices!"
+        );
+        assert_eq!(
+            (csc.rows(), csc.columns()),
+            (3, 4),
+            "Invalid CSC dimensions!"
+        );
+        assert_eq!(csc.get(0, 2), &2, "Invalid CSC element!");
+        assert_eq!(csc.get(1, 0), &0, "Missing CSC element must be zero!");
+        assert_eq!(
+            csc.column(0).map(|(r, v)| (r, *v)).collect::<Vec<_>>(),
+            vec![(0, 1), (2, 4)],
+            "Invalid CSC column!"
+        );
+        assert_eq!(
+            csc.row(0).map(|(c, v)| (c, *v)).collect::<Vec<_>>(),
+            vec![(0, 1), (2, 2)],
+            "Invalid CSC row!"
+        );
+        assert_eq!(
+            triplets(csc.iter()),
+            vec![
+                (0, 0, 1),
+                (2, 0, 4),
+                (2, 1, 5),
+                (0, 2, 2),
+                (1, 2, 3),
+                (2, 3, 6)
+            ],
+            "CSC elements must be in column major order!"
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let coo = coo();
+        let expected = triplets(coo.iter());
+        assert_eq!(
+            triplets(coo.to_csr().to_coo().iter()),
+            expected,
+            "COO -> CSR -> COO must round trip!"
+        );
+        assert_eq!(
+            triplets(coo.to_csc().to_coo().iter()),
+            expected,
+            "COO -> CSC -> COO must round trip!"
+        );
+        assert_eq!(
+            triplets(coo.to_csr().to_csc().to_csr().iter()),
+            expected,
+            "CSR -> CSC -> CSR must round trip!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 0) is out of bounds for a 3x4 Matrix!")]
+    fn test_get_panic() {
+        coo().to_csr().get(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (0, 4) is out of bounds for a 3x4 Matrix!")]
+    fn test_csc_get_panic() {
+        coo().to_csc().get(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 3 is out of bounds for a Matrix with 3 rows!")]
+    fn test_row_panic() {
+        coo().to_csr().row(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 4 is out of bounds for a Matrix with 4 columns!")]
+    fn test_csc_column_panic() {
+        coo().to_csc().column(4);
+    }
+
+    #[test]
+    fn test_multiply_vector() {
+        let x = [1, 2, 3, 4];
+        assert_eq!(
+            coo().to_csr().multiply_vector(&x),
+            Ok(vec![7, 9, 38]),
+            "Invalid CSR product!"
+        );
+        assert_eq!(
+            coo().to_csc().multiply_vector(&x),
+            Ok(vec![7, 9, 38]),
+            "Invalid CSC product!"
+        );
+        assert_eq!(
+            coo().to_csr().multiply_vector(&[1, 2]),
+            Err(MatrixError::DimensionMismatch {
+                expected: (4, 1),
+                found: (2, 1)
+            }),
+            "Product must check the vector length!"
+        );
+        let empty = SparseMatrix::<i32>::new(3, 0);
+        assert_eq!(
+            empty.to_csr().multiply_vector(&[]),
+            Ok(vec![0, 0, 0]),
+            "CSR product of a Matrix without columns must be zero!"
+        );
+        assert_eq!(
+            empty.to_csc().multiply_vector(&[]),
+            Ok(vec![0, 0, 0]),
+            "CSC product of a Matrix without columns must be zero!"
+        );
+        assert_eq!(
+            SparseMatrix::<i32>::new(0, 3)
+                .to_csc()
+                .multiply_vector(&[1, 2, 3]),
+            Ok(vec![]),
+            "CSC product of a Matrix without rows must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_multiply() {
+        // | 1 0 2 0 |   | 1 0 0 |   | 1  0  0 |
+        // | 0 0 3 0 | x | 0 0 1 | = | 0  0  0 |
+        // | 4 5 0 6 |   | 0 0 0 |   | 4  6  5 |
+        //               | 0 1 0 |
+        let mut other = SparseMatrix::new(4, 3);
+        for (row, col, val) in [(0, 0, 1), (1, 2, 1), (3, 1, 1)] {
+            other.set(row, col, val);
+        }
+        let expected = vec![(0, 0, 1), (2, 0, 4), (2, 1, 6), (2, 2, 5)];
+        let product = coo().to_csr().multiply(&other.to_csr()).unwrap();
+        assert_eq!(
+            triplets(product.iter()),
+            expected,
+            "Invalid CSR x CSR product!"
+        );
+        let product = coo().to_csc().multiply(&other.to_csc()).unwrap();
+        assert_eq!(
+            triplets(product.to_csr().iter()),
+            expected,
+            "Invalid CSC x CSC product!"
+        );
+        assert!(
+            coo().to_csr().multiply(&coo().to_csr()).is_err(),
+            "Product must check the dimensions!"
+        );
+
+        // Products which cancel out must not be stored.
+        let mut m1 = SparseMatrix::new(2, 2);
+        m1.set(0, 0, 1);
+        m1.set(0, 1, 1);
+        let mut m2 = SparseMatrix::new(2, 2);
+        m2.set(0, 0, 1);
+        m2.set(1, 0, -1);
+        let product = m1.to_csr().multiply(&m2.to_csr()).unwrap();
+        assert_eq!(product.nonzero_count(), 0, "Zero product must be dropped!");
+    }
+}
+
+// Compares the sparse formats with a Dense Matrix. Run with `cargo test sparse_matrix_benchmark -- --ignored --nocapture`.
+#[cfg(test)]
+mod sparse_matrix_benchmark {
+    use crate::structs::matrices::{DenseMatrix, MatrixOperations, SparseMatrix};
+    use std::time::Instant;
+
+    const DIMENSION: usize = 1_000;
+    const PER_ROW: usize = 5;
+    const ROUNDS: usize = 20;
+
+    fn matrices() -> (DenseMatrix<i64>, SparseMatrix<i64>) {
+        let mut dense = DenseMatrix::new(DIMENSION, DIMENSION);
+        let mut sparse = SparseMatrix::new(DIMENSION, DIMENSION);
+        for row in 0..DIMENSION {
+            for k in 0..PER_ROW {
+                let col = (row * 7 + k * 131) % DIMENSION;
+                dense.set(row, col, (row + col) as i64);
+                sparse.set(row, col, (row + col) as i64);
+            }
+        }
+        (dense, sparse)
+    }
+
+    fn report(name: &str, start: Instant, sum: i64, expected: i64) {
+        assert_eq!(sum, expected, "{} has an invalid sum!", name);
+        println!("{:<24} {:?}", name, start.elapsed());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_multiply_vector() {
+        let (dense, sparse) = matrices();
+        let (csr, csc) = (sparse.to_csr(), sparse.to_csc());
+        let x: Vec<i64> = (1..DIMENSION as i64 + 1).collect();
+
+        let start = Instant::now();
+        let mut expected: i64 = 0;
+        for _i in 0..ROUNDS {
+            for row in 0..DIMENSION {
+                for col in 0..DIMENSION {
+                    expected += dense.get(row, col) * x[col];
+                }
+            }
+        }
+        report("Dense Matrix", start, expected, expected);
+
+        let start = Instant::now();
+        let mut sum: i64 = 0;
+        for _i in 0..ROUNDS {
+            sum += csr.multiply_vector(&x).unwrap().iter().sum::<i64>();
+        }
+        report("CSR Matrix", start, sum, expected);
+
+        let start = Instant::now();
+        let mut sum: i64 = 0;
+        for _i in 0..ROUNDS {
+            sum += csc.multiply_vector(&x).unwrap().iter().sum::<i64>();
+        }
+        report("CSC Matrix", start, sum, expected);
+    }
+
+    #[test]
+    #[ignore]
+    fn test_get() {
+        let (dense, sparse) = matrices();
+        let csr = sparse.to_csr();
+        let positions: Vec<(usize, usize)> = (0..100_000)
+            .map(|i| (i * 37 % DIMENSION, i * 101 % DIMENSION))
+            .collect();
+
+        let start = Instant::now();
+        let expected: i64 = positions.iter().map(|&(r, c)| dense.get(r, c)).sum();
+        report("Dense Matrix", start, expected, expected);
+
+        let start = Instant::now();
+        let sum: i64 = positions.iter().map(|&(r, c)| sparse.get(r, c)).sum();
+        report("COO Matrix", start, sum, expected);
+
+        let start = Instant::now();
+        let sum: i64 = positions.iter().map(|&(r, c)| csr.get(r, c)).sum();
+        report("CSR Matrix", start, sum, expected);
+    }
+}
+
+#[cfg(test)]
+mod linked_sparse_matrix {
+    use crate::structs::matrices::{LinkedSparseMatrix, MatrixOperations, SparseMatrix};
+
+    fn from_values(
+        rows: usize,
+        columns: usize,
+        values: &[(usize, usize, i32)],
+    ) -> LinkedSparseMatrix<i32> {
+        let mut matrix = LinkedSparseMatrix::new(rows, columns);
+        for (row, col, val) in values {
+            matrix.set(*row, *col, *val);
+        }
+        matrix
+    }
+
+    #[test]
+    fn test_new() {
+        let matrix: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(4, 5);
+        assert!(
+            matrix.head.is_none(),
+            "Linked Sparse Matrix must not have rows!"
+        );
+        assert_eq!(matrix.nonzero_count(), 0, "Invalid initial non-zero count!");
+        assert_eq!(
+            matrix.get(3, 4),
+            &0,
+            "Empty Linked Sparse Matrix must return zero!"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let matrix = from_values(4, 4, &[(2, 1, 7), (0, 3, 5), (2, 0, 2), (0, 0, 9)]);
+        assert_eq!(matrix.nonzero_count(), 4, "Invalid non-zero count!");
+        assert_eq!(
+            matrix
+                .iter()
+                .map(|(r, c, v)| (r, c, *v))
+                .collect::<Vec<_>>(),
+            vec![(0, 0, 9), (0, 3, 5), (2, 0, 2), (2, 1, 7)],
+            "Linked Sparse Matrix elements must be sorted in row major order!"
+        );
+        assert_eq!(
+            matrix.get(2, 1),
+            &7,
+            "Invalid Linked Sparse Matrix element!"
+        );
+        assert_eq!(matrix.get(1, 1), &0, "Missing element must be zero!");
+    }
+
+    #[test]
+    fn test_set_overwrite_and_zero() {
+        let mut matrix = from_values(3, 3, &[(1, 1, 4), (1, 2, 1)]);
+        matrix.set(1, 1, 6);
+        assert_eq!(matrix.get(1, 1), &6, "Existing element must be replaced!");
+        assert_eq!(
+            matrix.nonzero_count(),
+            2,
+            "Replacing must not change the count!"
+        );
+        matrix.set(1, 2, 0);
+        matrix.set(0, 0, 0);
+        assert_eq!(matrix.nonzero_count(), 1, "Zero element must be removed!");
+        matrix.set(1, 1, 0);
+        assert!(
+            matrix.head.is_none(),
+            "Row without columns must be removed!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 0) is out of bounds for a 3x3 Matrix!")]
+    fn test_set_panic() {
+        let mut matrix: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(3, 3);
+        matrix.set(3, 0, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (1, 3) is out of bounds for a 3x3 Matrix!")]
+    fn test_get_panic() {
+        let matrix: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(3, 3);
+        matrix.get(1, 3);
+    }
+
+    #[test]
+    fn test_add() {
+        let m1 = from_values(3, 3, &[(0, 0, 1), (1, 2, 4), (2, 1, -2)]);
+        let m2 = from_values(3, 3, &[(0, 0, 2), (1, 0, 5), (2, 1, 2)]);
+        assert_eq!(
+            m1.add(&m2),
+            from_values(3, 3, &[(0, 0, 3), (1, 0, 5), (1, 2, 4)]),
+            "Invalid Linked Sparse Matrix addition!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix dimensions must match!")]
+    fn test_add_panic() {
+        let m1: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(3, 3);
+        let m2: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(3, 2);
+        m1.add(&m2);
+    }
+
+    #[test]
+    fn test_multiply() {
+        // | 1 0 2 |   | 0 3 |   | 2  3 |
+        // | 0 0 3 | x | 0 0 | = | 3  0 |
+        //             | 1 0 |
+        let m1 = from_values(2, 3, &[(0, 0, 1), (0, 2, 2), (1, 2, 3)]);
+        let m2 = from_values(3, 2, &[(0, 1, 3), (2, 0, 1)]);
+        assert_eq!(
+            format!("{}", m1.multiply(&m2)),
+            "  2   3 \n  3   0 \n",
+            "Invalid Linked Sparse Matrix multiplication!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix columns must match the rows of the other Matrix!")]
+    fn test_multiply_panic() {
+        let m1: LinkedSparseMatrix<i32> = LinkedSparseMatrix::new(2, 3);
+        m1.multiply(&m1);
+    }
+
+    #[test]
+    fn test_sparse_conversions() {
+        let mut sparse: SparseMatrix<i32> = SparseMatrix::new(4, 4);
+        sparse.set(3, 0, 3);
+        sparse.set(0, 1, 8);
+        let linked = LinkedSparseMatrix::from_sparse(&sparse);
+        assert_eq!(
+            linked.nonzero_count(),
+            2,
+            "Invalid converted non-zero count!"
+        );
+        assert_eq!(linked.get(3, 0), &3, "Invalid converted element!");
+        assert_eq!(linked.get(0, 1), &8, "Invalid converted element!");
+
+        let array = linked.to_sparse();
+        assert_eq!(
+            array.nonzero_count, 2,
+            "Invalid Sparse Matrix non-zero count!"
+        );
+        assert_eq!(array.get(0, 1), &8, "Invalid Sparse Matrix element!");
+        assert_eq!(array.get(3, 0), &3, "Invalid Sparse Matrix element!");
+        assert_eq!(array.get(1, 1), &0, "Invalid Sparse Matrix element!");
+    }
+}
+
+#[cfg(test)]
+mod matrix {
+    use crate::structs::matrices::{Matrix, MatrixError, MatrixKind, MatrixType};
+
+    fn from_rows(values: &[&[i32]]) -> Matrix<i32> {
+        let mut matrix = Matrix::new(values.len(), values[0].len());
+        for (i, row) in values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                matrix.set(i, j, *val);
+            }
+        }
+        matrix
+    }
+
+    fn values(matrix: &Matrix<i32>) -> Vec<Vec<i32>> {
+        (0..matrix.rows)
+            .map(|i| {
+                (0..matrix.columns)
+                    .map(|j| *matrix.get(i, j).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_optimize() {
+        let cases: [(&[&[i32]], MatrixKind); 7] = [
+            (&[&[1, 0, 0], &[0, 2, 0], &[0, 0, 3]], MatrixKind::Diagonal),
+            (&[&[1, 2, 3], &[4, 1, 2], &[5, 4, 1]], MatrixKind::Toeplitz),
+            (
+                &[&[1, 2, 0], &[3, 4, 5], &[0, 6, 7]],
+                MatrixKind::Tridiagonal,
+            ),
+            (
+                &[&[1, 2, 3], &[0, 4, 5], &[0, 0, 6]],
+                MatrixKind::UpperTriangular,
+            ),
+            (
+                &[&[1, 0, 0], &[2, 3, 0], &[4, 5, 6]],
+                MatrixKind::LowerTriangular,
+            ),
+            (&[&[0, 7, 0], &[0, 0, 0], &[8, 0, 0]], MatrixKind::Sparse),
+            (&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 0]], MatrixKind::Dense),
+        ];
+        for (rows, kind) in cases {
+            let mut matrix = from_rows(rows);
+            assert_eq!(matrix.optimize(), kind, "Invalid optimized Matrix kind!");
+            assert_eq!(
+                matrix.kind(),
+                kind,
+                "Matrix must switch its representation!"
+            );
+            assert_eq!(
+                values(&matrix),
+                values(&from_rows(rows)),
+                "Optimizing must keep the values!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_optimize_structured() {
+        let cases: [(&[&[i32]], MatrixKind); 3] = [
+            (
+                &[&[1, 2, 3, 4], &[2, 5, 6, 7], &[3, 6, 8, 9], &[4, 7, 9, 1]],
+                MatrixKind::Symmetric,
+            ),
+            (
+                &[
+                    &[1, 2, 0, 0, 0],
+                    &[3, 4, 5, 0, 0],
+                    &[6, 7, 8, 9, 0],
+                    &[0, 1, 2, 3, 4],
+                    &[0, 0, 5, 6, 7],
+                ],
+                MatrixKind::Banded { lower: 2, upper: 1 },
+            ),
+            (
+                &[
+                    &[1, 2, 3, 0, 0, 0],
+                    &[4, 5, 6, 0, 0, 0],
+                    &[7, 8, 9, 0, 0, 0],
+                    &[0, 0, 0, 1, 2, 0],
+                    &[0, 0, 0, 3, 4, 0],
+                    &[0, 0, 0, 0, 0, 5],
+                ],
+                MatrixKind::BlockDiagonal { size: 3 },
+            ),
+        ];
+        for (rows, kind) in cases {
+            let mut matrix = from_rows(rows);
+            assert_eq!(matrix.optimize(), kind, "Invalid optimized Matrix kind!");
+            assert_eq!(
+                values(&matrix),
+                values(&from_rows(rows)),
+                "Optimizing must keep the values!"
+            );
+            assert_eq!(
+                format!("{}", matrix),
+                format!("{}", from_rows(rows)),
+                "Display must show every element!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_structured_kinds() {
+        let mut matrix = from_rows(&[&[1, 2, 0], &[2, 3, 4], &[0, 4, 5]]);
+        matrix
+            .convert_to(MatrixKind::Banded { lower: 1, upper: 2 })
+            .unwrap();
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Banded { lower: 1, upper: 2 },
+            "Matrix must convert to a wider band!"
+        );
+        assert_eq!(
+            matrix.transpose().kind(),
+            MatrixKind::Banded { lower: 2, upper: 1 },
+            "Transpose must swap the bandwidths!"
+        );
+        assert_eq!(
+            matrix.convert_to(MatrixKind::BlockDiagonal { size: 2 }),
+            Err(MatrixError::DoesNotFit(MatrixKind::BlockDiagonal {
+                size: 2
+            })),
+            "Element 1, 2 crosses the blocks!"
+        );
+        matrix.convert_to(MatrixKind::Symmetric).unwrap();
+        assert_eq!(matrix.get(2, 1), Some(&4), "Invalid symmetric element!");
+
+        let mut matrix: Matrix<i32> = Matrix::new_banded(4, 4, 1, 0);
+        matrix.set_auto_adjust(true);
+        matrix.set(1, 0, 3);
+        matrix.set(0, 3, 6);
+        assert_eq!(
+            matrix.get(0, 3),
+            Some(&6),
+            "Auto adjust must keep values outside of the band!"
+        );
+        let mut matrix: Matrix<i32> = Matrix::new_block_diagonal(4, 4, 2);
+        matrix.set(2, 3, 1);
+        matrix.set(0, 3, 1);
+        assert_eq!(
+            matrix.get(0, 3),
+            Some(&0),
+            "Block Diagonal Matrix ignores elements outside of the blocks!"
+        );
+    }
+
+    #[test]
+    fn test_optimize_rectangular() {
+        let mut matrix = from_rows(&[&[1, 0, 0, 0], &[0, 2, 0, 0]]);
+        assert_eq!(
+            matrix.optimize(),
+            MatrixKind::Sparse,
+            "Rectangular Matrix can only be Sparse or Dense!"
+        );
+        let mut matrix = from_rows(&[&[1, 2, 0, 0], &[0, 2, 3, 0]]);
+        assert_eq!(
+            matrix.optimize(),
+            MatrixKind::Dense,
+            "Invalid optimized Matrix kind!"
+        );
+    }
+
+    #[test]
+    fn test_optimize_sparse_storage() {
+        let mut matrix: Matrix<f64> = Matrix::new(100, 100);
+        matrix.set(3, 7, 1.5);
+        assert_eq!(
+            matrix.optimize(),
+            MatrixKind::Sparse,
+            "Matrix with a single element must be Sparse!"
+        );
+        let size = match &matrix.matrix_type {
+            MatrixType::Sparse(sparse) => sparse.array.get_size(),
+            _ => 0,
+        };
+        assert_eq!(
+            size, 1,
+            "Sparse Matrix must only keep room for its elements!"
+        );
+        matrix.set(50, 50, 2.5);
+        assert_eq!(matrix.get(3, 7), Some(&1.5), "Sparse element must stay!");
+        assert_eq!(matrix.get(50, 50), Some(&2.5), "Sparse Matrix must grow!");
+    }
+
+    #[test]
+    fn test_auto_adjust() {
+        let mut matrix: Matrix<i32> = Matrix::new(3, 3);
+        matrix.set_auto_adjust(true);
+        for i in 0..3 {
+            matrix.set(i, i, i as i32 + 1);
+        }
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Diagonal,
+            "Matrix must switch to Diagonal!"
+        );
+
+        matrix.set(2, 0, 7);
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::LowerTriangular,
+            "Value below the diagonal must switch to Lower Triangular!"
+        );
+        matrix.set(0, 2, 9);
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Dense,
+            "Matrix must switch to Dense!"
+        );
+        assert_eq!(
+            values(&matrix),
+            vec![vec![1, 0, 9], vec![0, 2, 0], vec![7, 0, 3]],
+            "Auto adjust must keep every value!"
+        );
+
+        matrix.set(0, 2, 0);
+        matrix.set(2, 0, 0);
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Diagonal,
+            "Matrix must switch back to Diagonal!"
+        );
+    }
+
+    #[test]
+    fn test_auto_adjust_toeplitz() {
+        let mut matrix = from_rows(&[&[1, 2, 3], &[4, 1, 2], &[5, 4, 1]]);
+        matrix.convert_to(MatrixKind::Toeplitz).unwrap();
+        matrix.set_auto_adjust(true);
+        matrix.set(1, 1, 8);
+        assert_eq!(
+            values(&matrix),
+            vec![vec![1, 2, 3], vec![4, 8, 2], vec![5, 4, 1]],
+            "Setting a Toeplitz element must not change its diagonal!"
+        );
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Dense,
+            "Matrix must leave Toeplitz!"
+        );
+
+        matrix.set(1, 1, 1);
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Toeplitz,
+            "Matrix must switch back to Toeplitz!"
+        );
+    }
+
+    #[test]
+    fn test_auto_adjust_off() {
+        let mut matrix: Matrix<i32> = Matrix::new_diagonal(3, 3);
+        matrix.set(0, 1, 4);
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Diagonal,
+            "Matrix must keep its kind!"
+        );
+        assert_eq!(
+            matrix.get(0, 1),
+            Some(&0),
+            "Diagonal Matrix must ignore the value!"
+        );
+    }
+
+    #[test]
+    fn test_convert_to() {
+        let mut matrix = from_rows(&[&[1, 0, 0], &[2, 3, 0], &[0, 4, 5]]);
+        assert_eq!(
+            matrix.convert_to(MatrixKind::Diagonal),
+            Err(MatrixError::DoesNotFit(MatrixKind::Diagonal)),
+            "Lower Triangular values must not fit a Diagonal Matrix!"
+        );
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Dense,
+            "Failed conversion must keep the kind!"
+        );
+        assert_eq!(
+            matrix.convert_to(MatrixKind::Tridiagonal),
+            Ok(()),
+            "Conversion must succeed!"
+        );
+        assert_eq!(
+            matrix.convert_to(MatrixKind::LowerTriangular),
+            Ok(()),
+            "Conversion must succeed!"
+        );
+        assert_eq!(
+            values(&matrix),
+            vec![vec![1, 0, 0], vec![2, 3, 0], vec![0, 4, 5]],
+            "Conversion must keep the values!"
+        );
+        assert_eq!(
+            matrix.convert_to(MatrixKind::Sparse),
+            Err(MatrixError::DoesNotFit(MatrixKind::Sparse)),
+            "Too many non-zero values for a Sparse Matrix!"
+        );
+
+        let mut matrix: Matrix<i32> = Matrix::new(2, 3);
+        assert_eq!(
+            matrix.convert_to(MatrixKind::UpperTriangular),
+            Err(MatrixError::NotSquare(MatrixKind::UpperTriangular)),
+            "Triangular Matrix must be square!"
+        );
+        assert_eq!(
+            format!("{}", MatrixError::NotSquare(MatrixKind::Toeplitz)),
+            "Toeplitz Matrix must be square!",
+            "Invalid Matrix error message!"
+        );
+    }
+
+    #[test]
+    fn test_convert_empty() {
+        for kind in [
+            MatrixKind::Dense,
+            MatrixKind::Diagonal,
+            MatrixKind::UpperTriangular,
+            MatrixKind::LowerTriangular,
+            MatrixKind::Toeplitz,
+            MatrixKind::Tridiagonal,
+            MatrixKind::Sparse,
+            MatrixKind::Symmetric,
+            MatrixKind::Banded { lower: 0, upper: 0 },
+            MatrixKind::BlockDiagonal { size: 1 },
+        ] {
+            let mut matrix: Matrix<i32> = Matrix::new(0, 0);
+            assert_eq!(
+                matrix.convert_to(kind),
+                Ok(()),
+                "Empty Matrix must convert to {:?}!",
+                kind
+            );
+            assert_eq!(matrix.kind(), kind, "Empty Matrix must switch its kind!");
+            assert_eq!(format!("{}", matrix), "", "Empty Matrix has no elements!");
         }
-        0
     }
 
-    fn get(&self, row: usize, col: usize) -> &T {
-        for ele in self.array.iter() {
-            if ele.row == row && ele.column == col {
-                return &ele.value;
-            }
-        }
-        return &self.default_value;
-    }
+    #[test]
+    fn test_sparse_set() {
+        let mut matrix: Matrix<i32> = Matrix::new_sparse(4, 4);
+        matrix.set(1, 2, 4);
+        matrix.set(1, 2, 6);
+        matrix.set(0, 0, 2);
+        assert_eq!(
+            matrix.get(1, 2),
+            Some(&6),
+            "Sparse element must be replaced!"
+        );
+        matrix.set(1, 2, 0);
+        assert_eq!(
+            matrix.get(1, 2),
+            Some(&0),
+            "Sparse element must be removed!"
+        );
+        assert_eq!(
+            matrix.get(0, 0),
+            Some(&2),
+            "Other Sparse elements must stay!"
+        );
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut matrix: Matrix<i32> = Matrix::new(2, 3);
+        let error = MatrixError::IndexOutOfBounds {
+            index: (2, 0),
+            dimensions: (2, 3),
+        };
+        assert_eq!(
+            matrix.get(1, 2),
+            Some(&0),
+            "Last element must be in bounds!"
+        );
+        assert_eq!(matrix.get(2, 0), None, "Row past the Matrix must be None!");
+        assert_eq!(
+            matrix.get(0, 3),
+            None,
+            "Column past the Matrix must be None!"
+        );
+        assert_eq!(matrix.try_get(2, 0), Err(error), "Invalid bounds error!");
+        assert_eq!(matrix.try_set(2, 0, 1), Err(error), "Invalid bounds error!");
+        assert_eq!(
+            format!("{}", error),
+            "Index (2, 0) is out of bounds for a 2x3 Matrix!",
+            "Invalid Matrix error message!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (0, 3) is out of bounds for a 2x3 Matrix!")]
+    fn test_set_panic() {
+        let mut matrix: Matrix<i32> = Matrix::new(2, 3);
+        matrix.set(0, 3, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Diagonal Matrix must be square!")]
+    fn test_new_not_square_panic() {
+        Matrix::<i32>::new_diagonal(2, 3);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut matrix = from_rows(&[&[1, 2], &[3, 4]]);
+        matrix[(1, 0)] += 5;
+        assert_eq!(matrix[(1, 0)], 8, "Index must reach the element!");
+        assert_eq!(
+            values(&matrix),
+            vec![vec![1, 2], vec![8, 4]],
+            "IndexMut must only change the element!"
+        );
+
+        let mut matrix: Matrix<i32> = Matrix::new_diagonal(3, 3);
+        matrix[(1, 1)] = 2;
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Diagonal,
+            "Element with its own slot must keep the kind!"
+        );
+        matrix[(0, 2)] = 7;
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Dense,
+            "Element outside of the diagonal must move to Dense!"
+        );
+        assert_eq!(
+            values(&matrix),
+            vec![vec![0, 0, 7], vec![0, 2, 0], vec![0, 0, 0]],
+            "IndexMut must keep every value!"
+        );
+
+        let mut matrix: Matrix<i32> = Matrix::new_sparse(50, 50);
+        matrix[(1, 1)] = 5;
+        matrix[(1, 1)] += 1;
+        matrix[(0, 3)] = 2;
+        assert_eq!(
+            matrix.kind(),
+            MatrixKind::Sparse,
+            "Sparse Matrix must insert a slot for a missing element!"
+        );
+        assert_eq!(
+            matrix.get(1, 1),
+            Some(&6),
+            "IndexMut must reach the Sparse element!"
+        );
+        assert_eq!(
+            matrix.get(0, 3),
+            Some(&2),
+            "IndexMut must keep the Sparse elements sorted!"
+        );
+        assert_eq!(
+            matrix.get(2, 2),
+            Some(&0),
+            "Other Sparse elements must stay zero!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (3, 3) is out of bounds for a 3x3 Matrix!")]
+    fn test_index_panic() {
+        let matrix: Matrix<i32> = Matrix::new(3, 3);
+        let _ = matrix[(3, 3)];
+    }
+
+    #[test]
+    fn test_one_based() {
+        let mut matrix = from_rows(&[&[1, 2], &[3, 4]]).one_based();
+        assert_eq!(
+            matrix.get(1, 1),
+            Some(&1),
+            "One-based index must start at 1!"
+        );
+        assert_eq!(matrix[(2, 1)], 3, "Invalid one-based element!");
+        assert_eq!(matrix.get(0, 1), None, "Index 0 must be out of bounds!");
+        assert_eq!(matrix.get(3, 1), None, "Row past the Matrix must be None!");
+        assert_eq!(
+            matrix.try_set(0, 2, 5),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (0, 2),
+                dimensions: (2, 2)
+            }),
+            "Error must report the one-based index!"
+        );
+        matrix.set(1, 2, 5);
+        matrix[(2, 2)] *= 2;
+        assert_eq!(
+            values(matrix.matrix()),
+            vec![vec![1, 5], vec![3, 8]],
+            "One-based writes must shift the index!"
+        );
+        assert_eq!(
+            values(&matrix.into_inner()),
+            vec![vec![1, 5], vec![3, 8]],
+            "Inner Matrix must keep the values!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (0, 1) is out of bounds for a 2x2 Matrix!")]
+    fn test_one_based_panic() {
+        let matrix = Matrix::<i32>::new(2, 2).one_based();
+        let _ = matrix[(0, 1)];
+    }
+}
+
+#[cfg(test)]
+mod matrix_arithmetic {
+    use crate::structs::matrices::{Matrix, MatrixError, MatrixKind};
+
+    fn from_rows(values: &[&[i32]]) -> Matrix<i32> {
+        let mut matrix = Matrix::new(values.len(), values[0].len());
+        for (i, row) in values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                matrix.set(i, j, *val);
+            }
+        }
+        matrix
+    }
+
+    fn of_kind(values: &[&[i32]], kind: MatrixKind) -> Matrix<i32> {
+        let mut matrix = from_rows(values);
+        matrix.convert_to(kind).unwrap();
+        matrix
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let m1 = from_rows(&[&[1, 2], &[3, 4]]);
+        let m2 = from_rows(&[&[5, 6], &[7, 8]]);
+        assert_eq!(
+            &m1 + &m2,
+            from_rows(&[&[6, 8], &[10, 12]]),
+            "Invalid Matrix addition!"
+        );
+        assert_eq!(
+            &m2 - &m1,
+            from_rows(&[&[4, 4], &[4, 4]]),
+            "Invalid Matrix subtraction!"
+        );
+        assert_eq!(
+            m1.checked_add(&Matrix::new(2, 3)),
+            Err(MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            }),
+            "Addition must check the dimensions!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a 2x2 Matrix, found a 3x2 Matrix!")]
+    fn test_add_panic() {
+        let _ = from_rows(&[&[1, 2], &[3, 4]]) + Matrix::new(3, 2);
+    }
+
+    #[test]
+    fn test_add_kinds() {
+        let diagonal = of_kind(&[&[1, 0, 0], &[0, 2, 0], &[0, 0, 3]], MatrixKind::Diagonal);
+        let upper = of_kind(
+            &[&[1, 2, 3], &[0, 4, 5], &[0, 0, 6]],
+            MatrixKind::UpperTriangular,
+        );
+        let lower = of_kind(
+            &[&[1, 0, 0], &[2, 3, 0], &[4, 5, 6]],
+            MatrixKind::LowerTriangular,
+        );
+        let toeplitz = of_kind(&[&[1, 2, 3], &[4, 1, 2], &[5, 4, 1]], MatrixKind::Toeplitz);
+
+        let sum = &diagonal + &diagonal;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::Diagonal,
+            "Diagonal + Diagonal must stay Diagonal!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[2, 0, 0], &[0, 4, 0], &[0, 0, 6]]),
+            "Invalid Diagonal sum!"
+        );
+
+        let sum = &upper + &diagonal;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::UpperTriangular,
+            "Upper + Diagonal must stay Upper!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[2, 2, 3], &[0, 6, 5], &[0, 0, 9]]),
+            "Invalid Upper sum!"
+        );
+
+        let sum = &toeplitz + &toeplitz;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::Toeplitz,
+            "Toeplitz + Toeplitz must stay Toeplitz!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[2, 4, 6], &[8, 2, 4], &[10, 8, 2]]),
+            "Invalid Toeplitz sum!"
+        );
+
+        let sum = &upper + &lower;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::Dense,
+            "Upper + Lower must be Dense!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[2, 2, 3], &[2, 7, 5], &[4, 5, 12]]),
+            "Invalid mixed sum!"
+        );
+    }
+
+    #[test]
+    fn test_sparse_merge() {
+        let m1 = of_kind(
+            &[&[0, 1, 0, 0], &[0, 0, 0, 2], &[3, 0, 0, 0]],
+            MatrixKind::Sparse,
+        );
+        let m2 = of_kind(
+            &[&[0, 4, 0, 0], &[5, 0, 0, 0], &[-3, 0, 0, 0]],
+            MatrixKind::Sparse,
+        );
+        let sum = &m1 + &m2;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::Sparse,
+            "Sparse + Sparse must stay Sparse!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[0, 5, 0, 0], &[5, 0, 0, 2], &[0, 0, 0, 0]]),
+            "Invalid Sparse sum!"
+        );
+        assert_eq!(
+            sum.elements().len(),
+            3,
+            "Cancelled elements must be dropped!"
+        );
+        let difference = &m1 - &m2;
+        assert_eq!(
+            difference,
+            from_rows(&[&[0, -3, 0, 0], &[-5, 0, 0, 2], &[6, 0, 0, 0]]),
+            "Invalid Sparse difference!"
+        );
+
+        let m3 = of_kind(&[&[1, 1, 0], &[0, 1, 0], &[0, 0, 0]], MatrixKind::Sparse);
+        let m4 = of_kind(&[&[0, 0, 0], &[1, 0, 1], &[0, 1, 0]], MatrixKind::Sparse);
+        assert_eq!(
+            (&m3 + &m4).kind(),
+            MatrixKind::Dense,
+            "Sparse sum with too many elements must be Dense!"
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        let m1 = from_rows(&[&[1, 2, 3], &[4, 5, 6]]);
+        let m2 = from_rows(&[&[7, 8], &[9, 10], &[11, 12]]);
+        assert_eq!(
+            &m1 * &m2,
+            from_rows(&[&[58, 64], &[139, 154]]),
+            "Invalid Matrix product!"
+        );
+        assert_eq!(
+            m1.checked_mul(&m1),
+            Err(MatrixError::DimensionMismatch {
+                expected: (3, 3),
+                found: (2, 3)
+            }),
+            "Product must check the dimensions!"
+        );
+    }
+
+    #[test]
+    fn test_mul_kinds() {
+        let diagonal = of_kind(&[&[1, 0, 0], &[0, 2, 0], &[0, 0, 3]], MatrixKind::Diagonal);
+        let product = &diagonal * &diagonal;
+        assert_eq!(
+            product.kind(),
+            MatrixKind::Diagonal,
+            "Diagonal x Diagonal must stay Diagonal!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[1, 0, 0], &[0, 4, 0], &[0, 0, 9]]),
+            "Invalid Diagonal product!"
+        );
+
+        let upper = of_kind(
+            &[&[1, 2, 3], &[0, 4, 5], &[0, 0, 6]],
+            MatrixKind::UpperTriangular,
+        );
+        let product = &upper * &upper;
+        assert_eq!(
+            product.kind(),
+            MatrixKind::UpperTriangular,
+            "Upper x Upper must stay Upper!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[1, 10, 31], &[0, 16, 50], &[0, 0, 36]]),
+            "Invalid Upper product!"
+        );
+
+        let lower = upper.transpose();
+        let product = &lower * &lower;
+        assert_eq!(
+            product.kind(),
+            MatrixKind::LowerTriangular,
+            "Lower x Lower must stay Lower!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[1, 0, 0], &[10, 16, 0], &[31, 50, 36]]),
+            "Invalid Lower product!"
+        );
+
+        // | 1 0 2 |   | 0 3 0 |   | 2 3 0 |
+        // | 0 0 3 | x | 0 0 0 | = | 3 0 0 |
+        //             | 1 0 0 |
+        let m1 = of_kind(&[&[1, 0, 2], &[0, 0, 3]], MatrixKind::Sparse);
+        let m2 = of_kind(&[&[0, 3, 0], &[0, 0, 0], &[1, 0, 0]], MatrixKind::Sparse);
+        let product = &m1 * &m2;
+        assert_eq!(
+            product.kind(),
+            MatrixKind::Sparse,
+            "Sparse x Sparse must stay Sparse!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[2, 3, 0], &[3, 0, 0]]),
+            "Invalid Sparse product!"
+        );
+    }
+
+    #[test]
+    fn test_scalar_and_neg() {
+        let tridiagonal = of_kind(
+            &[&[1, 2, 0], &[3, 4, 5], &[0, 6, 7]],
+            MatrixKind::Tridiagonal,
+        );
+        let scaled = &tridiagonal * 2;
+        assert_eq!(
+            scaled.kind(),
+            MatrixKind::Tridiagonal,
+            "Scaling must keep the kind!"
+        );
+        assert_eq!(
+            scaled,
+            from_rows(&[&[2, 4, 0], &[6, 8, 10], &[0, 12, 14]]),
+            "Invalid scaled Matrix!"
+        );
+        let negated = -tridiagonal;
+        assert_eq!(
+            negated.kind(),
+            MatrixKind::Tridiagonal,
+            "Negation must keep the kind!"
+        );
+        assert_eq!(
+            negated,
+            from_rows(&[&[-1, -2, 0], &[-3, -4, -5], &[0, -6, -7]]),
+            "Invalid negated Matrix!"
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let matrix = from_rows(&[&[1, 2, 3], &[4, 5, 6]]);
+        assert_eq!(
+            matrix.transpose(),
+            from_rows(&[&[1, 4], &[2, 5], &[3, 6]]),
+            "Invalid transpose!"
+        );
+
+        let upper = of_kind(&[&[1, 2], &[0, 3]], MatrixKind::UpperTriangular);
+        let lower = upper.transpose();
+        assert_eq!(
+            lower.kind(),
+            MatrixKind::LowerTriangular,
+            "Upper transpose must be Lower!"
+        );
+        assert_eq!(
+            lower,
+            from_rows(&[&[1, 0], &[2, 3]]),
+            "Invalid triangular transpose!"
+        );
+
+        let toeplitz = of_kind(&[&[1, 2, 3], &[4, 1, 2], &[5, 4, 1]], MatrixKind::Toeplitz);
+        assert_eq!(
+            toeplitz.transpose(),
+            from_rows(&[&[1, 4, 5], &[2, 1, 4], &[3, 2, 1]]),
+            "Invalid Toeplitz transpose!"
+        );
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let upper = of_kind(&[&[1, 2], &[0, 3]], MatrixKind::UpperTriangular);
+        let lower = of_kind(&[&[4, 0], &[5, 6]], MatrixKind::LowerTriangular);
+        let product = upper.hadamard(&lower).unwrap();
+        assert_eq!(
+            product.kind(),
+            MatrixKind::Diagonal,
+            "Upper * Lower element-wise must be Diagonal!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[4, 0], &[0, 18]]),
+            "Invalid element-wise product!"
+        );
+
+        let sparse = of_kind(&[&[0, 2], &[0, 0]], MatrixKind::Sparse);
+        let product = sparse.hadamard(&from_rows(&[&[1, 5], &[1, 1]])).unwrap();
+        assert_eq!(
+            product.kind(),
+            MatrixKind::Sparse,
+            "Sparse element-wise product must stay Sparse!"
+        );
+        assert_eq!(
+            product,
+            from_rows(&[&[0, 10], &[0, 0]]),
+            "Invalid Sparse element-wise product!"
+        );
+
+        let sparse = of_kind(&[&[0, 0, 0], &[0, 3, 0], &[0, 0, 0]], MatrixKind::Sparse);
+        let toeplitz = of_kind(&[&[2, 1, 0], &[4, 2, 1], &[0, 4, 2]], MatrixKind::Toeplitz);
+        let expected = from_rows(&[&[0, 0, 0], &[0, 6, 0], &[0, 0, 0]]);
+        assert_eq!(
+            sparse.hadamard(&toeplitz).unwrap(),
+            expected,
+            "Sparse * Toeplitz must use every Toeplitz element!"
+        );
+        assert_eq!(
+            toeplitz.hadamard(&sparse).unwrap(),
+            expected,
+            "Toeplitz * Sparse must use every Toeplitz element!"
+        );
+
+        let shifted = upper.map(|val| val + 1);
+        assert_eq!(
+            shifted.kind(),
+            MatrixKind::Dense,
+            "Mapped Matrix must be Dense!"
+        );
+        assert_eq!(
+            shifted,
+            from_rows(&[&[2, 3], &[1, 4]]),
+            "Invalid mapped Matrix!"
+        );
+        assert_eq!(
+            upper.zip_with(&lower, |a, b| a.max(b)).unwrap(),
+            from_rows(&[&[4, 2], &[5, 6]]),
+            "Invalid zipped Matrix!"
+        );
+    }
+
+    #[test]
+    fn test_auto_adjust_result() {
+        let mut m1 = from_rows(&[&[1, 2], &[0, 3]]);
+        m1.set_auto_adjust(true);
+        let m2 = from_rows(&[&[1, -2], &[0, 1]]);
+        let sum = &m1 + &m2;
+        assert_eq!(
+            sum.kind(),
+            MatrixKind::Diagonal,
+            "Result must optimize with auto adjust!"
+        );
+        assert_eq!(
+            sum,
+            from_rows(&[&[2, 0], &[0, 4]]),
+            "Invalid optimized sum!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod numeric_elements {
+    use crate::structs::matrices::{Matrix, MatrixKind, Numeric};
+    use num::{Complex, Rational64};
 
-    fn set(&mut self, row: usize, col: usize, val: T)
-    where
-        T: PartialEq,
-    {
-        if val != self.default_value {
-            if self.nonzero_count == (self.rows * self.columns) / 2 {
-                panic!("Sparse Matrix non-zero values count will exceed zero values count!")
+    fn from_rows<T: Numeric>(values: &[&[T]]) -> Matrix<T> {
+        let mut matrix = Matrix::new(values.len(), values[0].len());
+        for (i, row) in values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                matrix.set(i, j, *val);
             }
-            self.array.push(SparseMatrixElement {
-                row,
-                column: col,
-                value: val,
-            });
-            self.nonzero_count += 1;
         }
+        matrix
     }
-}
-
-#[cfg(test)]
-mod diagonal_matrix {
-    use crate::structs::matrices::{DiagonalMatrix, MatrixOperations};
 
     #[test]
-    fn test_new() {
-        let matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
+    fn test_identity() {
+        let identity: Matrix<i64> = Matrix::identity(3);
         assert_eq!(
-            matrix.array.get_size(),
-            5,
-            "Verifying diagonal matrix array creation"
+            identity.kind(),
+            MatrixKind::Diagonal,
+            "Identity must be Diagonal!"
         );
+        assert_eq!(identity.get(1, 1), Some(&1), "Diagonal must hold ones!");
         assert_eq!(
-            matrix.array.get_len(),
-            5,
-            "Verifying diagonal matrix array creation"
+            identity.get(0, 1),
+            Some(&0),
+            "Off diagonal must hold zeros!"
         );
+        let m = from_rows(&[&[1i64, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        assert_eq!(&identity * &m, m, "I * A must be A!");
+        assert_eq!(&m * &identity, m, "A * I must be A!");
     }
 
     #[test]
-    fn test_set() {
-        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
-        matrix.set(1, 1, 10);
+    fn test_float() {
+        let m = from_rows(&[&[0.5f32, 0.0], &[0.0, 2.0]]);
+        assert_eq!(m.kind(), MatrixKind::Dense, "New Matrix must be Dense!");
         assert_eq!(
-            matrix.array[0], 10,
-            "Testing setting a diagonal matrix element"
+            &m * 4.0,
+            from_rows(&[&[2.0f32, 0.0], &[0.0, 8.0]]),
+            "Invalid scaled f32 Matrix!"
         );
     }
 
     #[test]
-    fn test_get() {
-        let mut matrix: DiagonalMatrix<i8> = DiagonalMatrix::new(5);
-        let val: i8 = 10;
-        matrix.set(1, 1, val);
-        matrix.set(4, 4, val);
+    fn test_complex() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+        let mut m = from_rows(&[&[i, zero], &[zero, one]]);
         assert_eq!(
-            matrix.get(1, 1),
-            &val,
-            "Testing fetching a diagonal matrix element"
+            m.optimize(),
+            MatrixKind::Diagonal,
+            "Complex Matrix must be Diagonal!"
         );
         assert_eq!(
-            matrix.get(4, 4),
-            &val,
-            "Testing fetching a diagonal matrix element"
+            &m * &m,
+            from_rows(&[&[-one, zero], &[zero, one]]),
+            "i * i must be -1!"
         );
         assert_eq!(
-            matrix.get(1, 4),
-            &0,
-            "Testing fetching a null diagonal matrix element"
+            &m + &Matrix::identity(2),
+            from_rows(&[&[one + i, zero], &[zero, one + one]]),
+            "Invalid complex sum!"
+        );
+    }
+
+    #[test]
+    fn test_rational() {
+        let half = Rational64::new(1, 2);
+        let third = Rational64::new(1, 3);
+        let zero = Rational64::new(0, 1);
+        let m1 = from_rows(&[&[half, zero], &[zero, third]]);
+        let m2 = from_rows(&[&[third, zero], &[half, half]]);
+        assert_eq!(
+            &m1 + &m2,
+            from_rows(&[
+                &[Rational64::new(5, 6), zero],
+                &[half, Rational64::new(5, 6)]
+            ]),
+            "Invalid rational sum!"
+        );
+        assert_eq!(
+            &m1 * &m2,
+            from_rows(&[
+                &[Rational64::new(1, 6), zero],
+                &[Rational64::new(1, 6), Rational64::new(1, 6)]
+            ]),
+            "Invalid rational product!"
+        );
+
+        let mut sparse: Matrix<Rational64> = Matrix::new_sparse(4, 4);
+        sparse.set(1, 2, third);
+        sparse.set(1, 2, zero);
+
+        assert_eq!(
+            sparse,
+            Matrix::new(4, 4),
+            "Setting zero must remove the element!"
         );
     }
 }
 
 #[cfg(test)]
-mod lower_triangular_matrix {
-    use crate::structs::matrices::{LowerTriangularMatrix, MatrixOperations};
+mod linear_algebra {
+    use crate::structs::matrices::{
+        LowerTriangularMatrix, Matrix, MatrixError, MatrixKind, MatrixOperations,
+        TridiagonalMatrix, UpperTraingularMatrix,
+    };
+
+    fn from_rows(values: &[&[f64]]) -> Matrix<f64> {
+        let mut matrix = Matrix::new(values.len(), values[0].len());
+        for (i, row) in values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                matrix.set(i, j, *val);
+            }
+        }
+        matrix
+    }
+
+    fn is_close(actual: &[f64], expected: &[f64]) -> bool {
+        actual.len() == expected.len()
+            && actual
+                .iter()
+                .zip(expected)
+                .all(|(a, b)| (a - b).abs() < 1e-9)
+    }
+
+    fn is_close_matrix(actual: &Matrix<f64>, expected: &Matrix<f64>) -> bool {
+        (actual - expected)
+            .elements()
+            .iter()
+            .all(|(_, _, val)| val.abs() < 1e-9)
+    }
+
+    fn system() -> Matrix<f64> {
+        from_rows(&[&[2.0, 1.0, 1.0], &[4.0, -6.0, 0.0], &[-2.0, 7.0, 2.0]])
+    }
 
     #[test]
-    fn test_new() {
-        let dimension: usize = 5;
-        let total_elements: usize = dimension * (dimension + 1) / 2;
-        let matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(dimension);
+    fn test_lu() {
+        let matrix = system();
+        let lu = matrix.lu().unwrap();
+        let lower = lu.lower();
+        let upper = lu.upper();
         assert_eq!(
-            matrix.array.get_size(),
-            total_elements,
-            "Verifying lower triangular matrix array size"
+            lower.kind(),
+            MatrixKind::LowerTriangular,
+            "L must be Lower Triangular!"
         );
         assert_eq!(
-            matrix.array.get_len(),
-            total_elements,
-            "Verifying lower triangular matrix array length"
+            upper.kind(),
+            MatrixKind::UpperTriangular,
+            "U must be Upper Triangular!"
+        );
+        assert!(
+            is_close_matrix(&(&lu.permutation_matrix() * &matrix), &(&lower * &upper)),
+            "P * A must be equal to L * U!"
+        );
+        // Partial pivoting picks the largest value of the first column.
+        assert_eq!(upper.get(0, 0), Some(&4.0), "Invalid first pivot!");
+        assert!(
+            (0..3).all(|i| (1..3).all(|j| j <= i || lower.get(j, i).unwrap().abs() <= 1.0)),
+            "Multipliers of partial pivoting must not exceed 1!"
         );
     }
 
     #[test]
-    fn test_set() {
-        let mut matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(5);
-        matrix.set(5, 4, 10);
-        matrix.set(2, 4, 10);
+    fn test_determinant() {
+        assert!(
+            (system().determinant().unwrap() + 16.0).abs() < 1e-9,
+            "Invalid determinant!"
+        );
+        let singular = from_rows(&[&[1.0, 2.0], &[2.0, 4.0]]);
         assert_eq!(
-            matrix.array[13], 10,
-            "Testing a lower triangular matrix element set"
+            singular.determinant(),
+            Ok(0.0),
+            "Singular determinant must be 0!"
         );
+        let mut upper = from_rows(&[&[2.0, 5.0], &[0.0, 3.0]]);
+        upper.convert_to(MatrixKind::UpperTriangular).unwrap();
         assert_eq!(
-            matrix.array[3], 0,
-            "Testing a lower triangular matrix element set"
+            upper.determinant(),
+            Ok(6.0),
+            "Invalid triangular determinant!"
+        );
+        assert_eq!(
+            Matrix::<f64>::new(2, 3).determinant(),
+            Err(MatrixError::NotSquare(MatrixKind::Dense)),
+            "Determinant needs a square Matrix!"
         );
     }
 
     #[test]
-    fn test_get() {
-        let mut matrix: LowerTriangularMatrix<i8> = LowerTriangularMatrix::new(5);
-        let val: i8 = 10;
-        matrix.set(1, 1, val);
-        matrix.set(5, 4, val);
+    fn test_inverse() {
+        let matrix = from_rows(&[&[4.0, 7.0], &[2.0, 6.0]]);
+        let inverse = matrix.inverse().unwrap();
+        assert!(
+            is_close_matrix(&inverse, &from_rows(&[&[0.6, -0.7], &[-0.2, 0.4]])),
+            "Invalid inverse!"
+        );
+        let identity = from_rows(&[&[1.0, 0.0, 0.0], &[0.0, 1.0, 0.0], &[0.0, 0.0, 1.0]]);
+        assert!(
+            is_close_matrix(&(&system() * &system().inverse().unwrap()), &identity),
+            "A * inverse(A) must be the identity!"
+        );
         assert_eq!(
-            matrix.get(1, 1),
-            &val,
-            "Testing a lower triangular matrix element fetch"
+            from_rows(&[&[1.0, 2.0], &[2.0, 4.0]]).inverse().err(),
+            Some(MatrixError::Singular(1)),
+            "Singular Matrix must not have an inverse!"
         );
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(system().rank(), 3, "Invalid full rank!");
+        let matrix = from_rows(&[&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0], &[1.0, 0.0, 1.0]]);
+        assert_eq!(matrix.rank(), 2, "Invalid rank of dependent rows!");
+        let wide = from_rows(&[&[1.0, 2.0, 3.0, 4.0], &[2.0, 4.0, 6.0, 8.0]]);
+        assert_eq!(wide.rank(), 1, "Invalid rank of a wide Matrix!");
         assert_eq!(
-            matrix.get(5, 4),
-            &val,
-            "Testing a lower triangular matrix element fetch"
+            Matrix::<f64>::new(3, 2).rank(),
+            0,
+            "Zero Matrix must have rank 0!"
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let x = system().solve(&[5.0, -2.0, 9.0]).unwrap();
+        assert!(is_close(&x, &[1.0, 1.0, 2.0]), "Invalid solution!");
+        assert_eq!(
+            from_rows(&[&[1.0, 2.0], &[2.0, 4.0]]).solve(&[1.0, 2.0]),
+            Err(MatrixError::Singular(1)),
+            "Singular system must fail!"
         );
         assert_eq!(
-            matrix.get(1, 4),
-            &0,
-            "Testing a null lower triangular matrix element fetch"
+            system().solve(&[1.0, 2.0]),
+            Err(MatrixError::DimensionMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            }),
+            "Solve must check the length of b!"
+        );
+    }
+
+    #[test]
+    fn test_solve_kinds() {
+        let b = [2.0, 7.0, 10.0];
+        for kind in [
+            MatrixKind::Diagonal,
+            MatrixKind::LowerTriangular,
+            MatrixKind::UpperTriangular,
+            MatrixKind::Tridiagonal,
+        ] {
+            let mut matrix = from_rows(&[&[2.0, 0.0, 0.0], &[0.0, 7.0, 0.0], &[0.0, 0.0, 5.0]]);
+            matrix.convert_to(kind).unwrap();
+            assert!(
+                is_close(&matrix.solve(&b).unwrap(), &[1.0, 1.0, 2.0]),
+                "Invalid solution!"
+            );
+        }
+
+        // The Thomas Algorithm meets a zero pivot in the first row, so LU Decomposition solves it.
+        let mut matrix = from_rows(&[&[0.0, 1.0, 0.0], &[1.0, 0.0, 1.0], &[0.0, 1.0, 1.0]]);
+        matrix.convert_to(MatrixKind::Tridiagonal).unwrap();
+        assert!(
+            is_close(&matrix.solve(&[2.0, 4.0, 5.0]).unwrap(), &[1.0, 2.0, 3.0]),
+            "Tridiagonal solve must fall back to LU!"
+        );
+    }
+
+    #[test]
+    fn test_substitution() {
+        let mut lower: LowerTriangularMatrix<f64> = LowerTriangularMatrix::new(3);
+        for (i, j, val) in [
+            (0, 0, 2.0),
+            (1, 0, 1.0),
+            (1, 1, 4.0),
+            (2, 0, 3.0),
+            (2, 1, 2.0),
+            (2, 2, 1.0),
+        ] {
+            lower.set(i, j, val);
+        }
+        let x = lower.forward_substitution(&[2.0, 9.0, 11.0]).unwrap();
+        assert!(
+            is_close(&x, &[1.0, 2.0, 4.0]),
+            "Invalid forward substitution!"
+        );
+
+        let mut upper: UpperTraingularMatrix<f64> = UpperTraingularMatrix::new(3);
+        for (i, j, val) in [
+            (0, 0, 1.0),
+            (0, 1, 2.0),
+            (0, 2, 3.0),
+            (1, 1, 4.0),
+            (1, 2, 5.0),
+            (2, 2, 6.0),
+        ] {
+            upper.set(i, j, val);
+        }
+        let x = upper.back_substitution(&[14.0, 23.0, 18.0]).unwrap();
+        assert!(is_close(&x, &[1.0, 2.0, 3.0]), "Invalid back substitution!");
+
+        upper.set(1, 1, 0.0);
+        assert_eq!(
+            upper.back_substitution(&[1.0, 1.0, 1.0]),
+            Err(MatrixError::Singular(1)),
+            "Zero diagonal must be singular!"
+        );
+    }
+
+    #[test]
+    fn test_thomas() {
+        // Second difference Matrix from finite differences.
+        let n = 5;
+        let mut matrix: TridiagonalMatrix<f64> = TridiagonalMatrix::new(n);
+        for i in 0..n {
+            matrix.set(i, i, 2.0);
+            if i > 0 {
+                matrix.set(i, i - 1, -1.0);
+                matrix.set(i - 1, i, -1.0);
+            }
+        }
+        let x = matrix.thomas(&[1.0, 0.0, 0.0, 0.0, 1.0]).unwrap();
+        assert!(
+            is_close(&x, &[1.0; 5]),
+            "Invalid Thomas Algorithm solution!"
+        );
+        assert_eq!(
+            matrix.thomas(&[1.0]),
+            Err(MatrixError::DimensionMismatch {
+                expected: (5, 1),
+                found: (1, 1)
+            }),
+            "Thomas Algorithm must check the length of d!"
         );
     }
 }
 
 #[cfg(test)]
-mod upper_triangular_matrix {
-    use crate::structs::matrices::{MatrixOperations, UpperTraingularMatrix};
+mod matrix_io {
+    use crate::structs::matrices::{
+        MarketFormat, Matrix, MatrixIoError, MatrixKind, ParseErrorKind,
+    };
+
+    fn from_rows(values: &[&[i32]]) -> Matrix<i32> {
+        let mut matrix = Matrix::new(values.len(), values[0].len());
+        for (i, row) in values.iter().enumerate() {
+            for (j, val) in row.iter().enumerate() {
+                matrix.set(i, j, *val);
+            }
+        }
+        matrix
+    }
+
+    fn write_market(matrix: &Matrix<i32>, format: MarketFormat) -> String {
+        let mut out: Vec<u8> = Vec::new();
+        matrix.write_matrix_market(&mut out, format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn parse_error(result: Result<Matrix<i32>, MatrixIoError>) -> (usize, ParseErrorKind) {
+        match result {
+            Err(MatrixIoError::Parse { line, kind }) => (line, kind),
+            _ => panic!("Expected a parse error!"),
+        }
+    }
 
     #[test]
-    fn test_new() {
-        let dimension: usize = 5;
-        let total_elements: usize = dimension * (dimension + 1) / 2;
-        let matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(dimension);
+    fn test_read_coordinate() {
+        let file = "%%MatrixMarket matrix coordinate integer general\n\
+                    % A comment\n\
+                    \n\
+                    3 4 3\n\
+                    1 1 5\n\
+                    2 4 -1\n\
+                    3 2 7\n";
+        let matrix: Matrix<i32> = Matrix::read_matrix_market(file.as_bytes()).unwrap();
         assert_eq!(
-            matrix.array.get_size(),
-            total_elements,
-            "Verifying upper triangular matrix array size"
+            matrix,
+            from_rows(&[&[5, 0, 0, 0], &[0, 0, 0, -1], &[0, 7, 0, 0]]),
+            "Invalid coordinate Matrix!"
         );
         assert_eq!(
-            matrix.array.get_len(),
-            total_elements,
-            "Verifying upper triangular matrix array length"
+            matrix.kind(),
+            MatrixKind::Sparse,
+            "Few entries must be Sparse!"
         );
     }
 
     #[test]
-    fn test_set() {
-        let mut matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(5);
-        matrix.set(4, 5, 10);
-        matrix.set(2, 4, 10);
+    fn test_read_large_coordinate() {
+        // A Dense 100000 x 100000 Matrix wouldn't fit in memory.
+        let file = "%%MatrixMarket matrix coordinate integer general\n\
+                    100000 100000 4\n\
+                    1 100000 3\n\
+                    50000 2 4\n\
+                    1 100000 5\n\
+                    7 7 0\n";
+        let mut matrix: Matrix<i32> = Matrix::read_matrix_market(file.as_bytes()).unwrap();
         assert_eq!(
-            matrix.array[13], 10,
-            "Testing a upper triangular matrix element set"
+            matrix.kind(),
+            MatrixKind::Sparse,
+            "Few entries must be Sparse!"
+        );
+        assert_eq!(
+            matrix.get(0, 99_999),
+            Some(&5),
+            "Last entry of a position must win!"
+        );
+        assert_eq!(matrix.get(49_999, 1), Some(&4), "Invalid coordinate entry!");
+        assert_eq!(
+            matrix.elements().len(),
+            2,
+            "Zero entries must not be stored!"
+        );
+        matrix.set(2, 3, 9);
+        assert_eq!(
+            matrix.get(2, 3),
+            Some(&9),
+            "Sparse Matrix must grow for a new element!"
         );
-        // assert_eq!(matrix.array[7], 0, "Testing a upper triangular matrix element set");
     }
 
     #[test]
-    fn test_get() {
-        let mut matrix: UpperTraingularMatrix<i8> = UpperTraingularMatrix::new(5);
-        let val: i8 = 10;
-        matrix.set(1, 1, val);
-        matrix.set(4, 5, val);
+    fn test_read_array_symmetric() {
+        // Lower triangle in column major order.
+        let file = "%%MatrixMarket matrix array real symmetric\n3 3\n1.5\n2\n0\n4\n0\n6\n";
+        let matrix: Matrix<f64> = Matrix::read_matrix_market(file.as_bytes()).unwrap();
+        assert_eq!(matrix.get(1, 0), Some(&2.0), "Invalid array element!");
         assert_eq!(
-            matrix.get(1, 1),
-            &val,
-            "Testing a upper triangular matrix element fetch"
+            matrix.get(0, 1),
+            Some(&2.0),
+            "Symmetric element must be mirrored!"
         );
+        assert_eq!(matrix.get(2, 2), Some(&6.0), "Invalid array element!");
         assert_eq!(
-            matrix.get(4, 5),
-            &val,
-            "Testing a upper triangular matrix element fetch"
+            matrix.kind(),
+            MatrixKind::BlockDiagonal { size: 2 },
+            "Invalid Matrix kind!"
         );
+
+        let file = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 3\n2 1 4\n";
+        let matrix: Matrix<f64> = Matrix::read_matrix_market(file.as_bytes()).unwrap();
         assert_eq!(
-            matrix.get(4, 1),
-            &0,
-            "Testing a null upper triangular matrix element fetch"
+            matrix.get(0, 1),
+            Some(&4.0),
+            "Symmetric entry must be mirrored!"
         );
+        assert!(matrix.is_symmetric(), "Matrix must be symmetric!");
     }
-}
-
-#[cfg(test)]
-mod toeplitz_matrix {
-    use crate::structs::matrices::{MatrixOperations, ToeplitzMatrix};
-    use rand::random;
 
     #[test]
-    fn test_new() {
-        // n x n matrix. e.g. 5 x 5
-        let dimension: usize = 5;
-        // total = n + n - 1
-        let total_elements: usize = 9;
-        let matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(dimension);
+    fn test_read_picks_kind() {
+        let file = "%%MatrixMarket matrix array integer general\n3 3\n1\n0\n0\n0\n2\n0\n0\n0\n3\n";
+        let matrix: Matrix<i32> = Matrix::read_matrix_market(file.as_bytes()).unwrap();
         assert_eq!(
-            matrix.array.get_size(),
-            total_elements,
-            "Verifying toeplitz matrix array size"
+            matrix.kind(),
+            MatrixKind::Diagonal,
+            "Diagonal file must be Diagonal!"
         );
+        let csv = "1,2,3\n0,4,5\n0,0,6\n";
+        let matrix: Matrix<i32> = Matrix::read_delimited(csv.as_bytes(), ',').unwrap();
         assert_eq!(
-            matrix.array.get_len(),
-            total_elements,
-            "Verifying toeplitz matrix array length"
+            matrix.kind(),
+            MatrixKind::UpperTriangular,
+            "Upper CSV must be Upper!"
         );
     }
 
-    // #[test]
-    fn test_set() {
-        let mut matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(5);
-        for i in 1..6 {
-            for j in 1..6 {
-                matrix.set(i, j, random())
+    #[test]
+    fn test_round_trip() {
+        let general = from_rows(&[&[1, 0, 2], &[0, 0, 3], &[4, 0, 0]]);
+        let symmetric = from_rows(&[&[1, 2, 0], &[2, 0, 3], &[0, 3, 5]]);
+        for matrix in [&general, &symmetric] {
+            for format in [MarketFormat::Coordinate, MarketFormat::Array] {
+                let text = write_market(matrix, format);
+                let res: Matrix<i32> = Matrix::read_matrix_market(text.as_bytes()).unwrap();
+                assert_eq!(&res, matrix, "Matrix Market must round trip!");
             }
         }
         assert_eq!(
-            matrix.get(4, 3),
-            matrix.get(3, 2),
-            "Testing a toeplitz  matrix element set"
+            write_market(&symmetric, MarketFormat::Coordinate),
+            "%%MatrixMarket matrix coordinate integer symmetric\n3 3 4\n1 1 1\n2 1 2\n3 2 3\n3 3 5\n",
+            "Symmetric Matrix must only write its lower triangle!"
+        );
+        assert_eq!(
+            write_market(&general, MarketFormat::Array),
+            "%%MatrixMarket matrix array integer general\n3 3\n1\n0\n4\n0\n0\n0\n2\n3\n0\n",
+            "Array must be written in column major order!"
         );
 
-        // n + i - j - 1
-        let test_index: usize = 5 + 4 - 3 - 1;
+        let mut real: Matrix<f64> = Matrix::new(1, 2);
+        real.set(0, 1, 0.5);
+        let mut out: Vec<u8> = Vec::new();
+        real.write_matrix_market(&mut out, MarketFormat::Coordinate)
+            .unwrap();
         assert_eq!(
-            matrix.get(4, 3),
-            &matrix.array[test_index],
-            "Testing a toeplitz  matrix element set"
+            String::from_utf8(out).unwrap(),
+            "%%MatrixMarket matrix coordinate real general\n1 2 1\n1 2 0.5\n",
+            "Fractions must be written as real!"
         );
     }
 
-    // #[test]
-    fn test_get() {
-        let mut matrix: ToeplitzMatrix<i8> = ToeplitzMatrix::new(5);
-        let val: i8 = 10;
-        matrix.set(1, 1, val);
-        matrix.set(4, 5, val);
+    #[test]
+    fn test_round_trip_kinds() {
+        let cases: [(&[&[i32]], MatrixKind); 10] = [
+            (&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 0]], MatrixKind::Dense),
+            (&[&[1, 0, 0], &[0, 2, 0], &[0, 0, 3]], MatrixKind::Diagonal),
+            (
+                &[&[1, 2, 3], &[0, 4, 5], &[0, 0, 6]],
+                MatrixKind::UpperTriangular,
+            ),
+            (
+                &[&[1, 0, 0], &[2, 3, 0], &[4, 5, 6]],
+                MatrixKind::LowerTriangular,
+            ),
+            (
+                &[&[2, -1, 0], &[-1, 2, -1], &[0, -1, 2]],
+                MatrixKind::Toeplitz,
+            ),
+            (
+                &[&[1, 2, 0], &[3, 4, 5], &[0, 6, 7]],
+                MatrixKind::Tridiagonal,
+            ),
+            (&[&[0, 7, 0], &[0, 0, 0], &[8, 0, 0]], MatrixKind::Sparse),
+            (&[&[1, 2, 3], &[2, 4, 5], &[3, 5, 6]], MatrixKind::Symmetric),
+            (
+                &[&[1, 0, 0], &[2, 3, 0], &[0, 4, 5]],
+                MatrixKind::Banded { lower: 1, upper: 0 },
+            ),
+            (
+                &[&[1, 2, 0], &[3, 4, 0], &[0, 0, 5]],
+                MatrixKind::BlockDiagonal { size: 2 },
+            ),
+        ];
+        for (rows, kind) in cases {
+            let mut matrix = from_rows(rows);
+            matrix.convert_to(kind).unwrap();
+            for format in [MarketFormat::Coordinate, MarketFormat::Array] {
+                let text = write_market(&matrix, format);
+                let res: Matrix<i32> = Matrix::read_matrix_market(text.as_bytes()).unwrap();
+                assert_eq!(res, from_rows(rows), "{:?} Matrix must round trip!", kind);
+                assert_eq!(
+                    res.kind(),
+                    from_rows(rows).optimize(),
+                    "Reader must pick the kind optimize picks!"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_delimited() {
+        let tsv = "1\t2\n\n3 \t 4\n";
+        let matrix: Matrix<i32> = Matrix::read_delimited(tsv.as_bytes(), '\t').unwrap();
         assert_eq!(
-            matrix.get(1, 1),
-            &val,
-            "Testing a toeplitz matrix element fetch"
+            matrix,
+            from_rows(&[&[1, 2], &[3, 4]]),
+            "Invalid TSV Matrix!"
         );
+        let mut out: Vec<u8> = Vec::new();
+        matrix.write_delimited(&mut out, ',').unwrap();
         assert_eq!(
-            matrix.get(4, 5),
-            &val,
-            "Testing a toeplitz matrix element fetch"
+            String::from_utf8(out).unwrap(),
+            "1,2\n3,4\n",
+            "Invalid CSV output!"
         );
+
+        let empty: Matrix<i32> = Matrix::read_delimited("".as_bytes(), ',').unwrap();
         assert_eq!(
-            matrix.get(4, 1),
-            &0,
-            "Testing a null upper triangular matrix element fetch"
+            (empty.rows(), empty.columns()),
+            (0, 0),
+            "Empty CSV must be empty!"
         );
     }
-}
-
-mod sparse_matrix {
-    use crate::structs::matrices::SparseMatrix;
 
     #[test]
-    fn test_new() {
-        // n x n matrix. e.g. 5 x 5
-        let row: usize = 5;
-        let col: usize = 5;
-
-        // total = n x n / 2
-        let max_nonzero_elements: usize = 12;
-        let matrix: SparseMatrix<i8> = SparseMatrix::new(row, col);
+    fn test_read_errors() {
+        let read = |text: &str| Matrix::<i32>::read_matrix_market(text.as_bytes());
         assert_eq!(
-            matrix.array.get_size(),
-            max_nonzero_elements,
-            "Invalid Sparse Matrix array size. It should be (row * col / 2)!"
+            parse_error(read("")),
+            (1, ParseErrorKind::MissingHeader),
+            "Missing header!"
         );
         assert_eq!(
-            matrix.array.get_len(),
-            0,
-            "Invalid Sparse Matrix array length. It should be 0!"
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate complex general\n1 1 0\n"
+            )),
+            (1, ParseErrorKind::Unsupported(String::from("complex"))),
+            "Complex field isn't supported!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate real general\n% c\n2 x 1\n"
+            )),
+            (3, ParseErrorKind::InvalidSize),
+            "Invalid size line!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 4\n1 2 abc\n"
+            )),
+            (4, ParseErrorKind::InvalidValue(String::from("abc"))),
+            "Invalid value!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate integer general\n2 2 1\n3 1 4\n"
+            )),
+            (3, ParseErrorKind::IndexOutOfBounds(3, 1)),
+            "Entry out of bounds!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 1\n"
+            )),
+            (3, ParseErrorKind::InvalidEntry),
+            "Entry without value!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix array integer general\n2 2\n1\n2\n3\n"
+            )),
+            (
+                5,
+                ParseErrorKind::WrongEntryCount {
+                    expected: 4,
+                    found: 3
+                }
+            ),
+            "Too few entries!"
+        );
+        assert_eq!(
+            parse_error(read(
+                "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 1 1\n2 2 1\n"
+            )),
+            (
+                4,
+                ParseErrorKind::WrongEntryCount {
+                    expected: 1,
+                    found: 2
+                }
+            ),
+            "Too many entries!"
+        );
+        assert_eq!(
+            parse_error(Matrix::read_delimited("1,2\n3\n".as_bytes(), ',')),
+            (
+                2,
+                ParseErrorKind::RaggedRow {
+                    expected: 2,
+                    found: 1
+                }
+            ),
+            "Ragged CSV row!"
+        );
+        let error = Matrix::<i32>::read_delimited("1,x\n".as_bytes(), ',')
+            .err()
+            .unwrap();
+        assert_eq!(
+            format!("{}", error),
+            "Line 1: Invalid Matrix value 'x'!",
+            "Invalid error message!"
         );
     }
-
-    // TODO: Add set and get unit tests for Sparse Matrix
-    #[test]
-    fn test_set() {}
-
-    #[test]
-    fn test_get() {}
 }
@@ -1,8 +1,9 @@
 use crate::structs::arrays::HeapArray;
+use crate::structs::smart_ptrs::HeapBox;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct Term {
     coefficient: i32,
     exponent: u32,
@@ -114,6 +115,234 @@ impl Polynomial {
     }
 }
 
+/*
+NOTE:
+Sparse polynomials waste most of the array when only a few exponents are used, so the terms are kept in a Singly
+Linked List instead. The nodes are sorted by exponent in descending order, every exponent appears only once and terms
+with a zero coefficient are never stored.
+*/
+struct TermNode {
+    term: Term,
+    next: Option<HeapBox<TermNode>>,
+}
+
+pub struct LinkedPolynomial {
+    head: Option<HeapBox<TermNode>>,
+    count: usize,
+}
+
+impl LinkedPolynomial {
+    pub fn new() -> Self {
+        LinkedPolynomial {
+            head: None,
+            count: 0,
+        }
+    }
+
+    pub fn term_count(&self) -> usize {
+        self.count
+    }
+
+    pub fn degree(&self) -> Option<u32> {
+        self.head.as_ref().map(|node| node.term.exponent)
+    }
+
+    fn terms(&self) -> TermIterator<'_> {
+        TermIterator {
+            current: self.head.as_deref(),
+        }
+    }
+
+    // Returns the link where a term with the given exponent is or should be placed.
+    fn find_link(&mut self, exponent: u32) -> &mut Option<HeapBox<TermNode>> {
+        let mut link = &mut self.head;
+        while link
+            .as_ref()
+            .is_some_and(|node| node.term.exponent > exponent)
+        {
+            link = &mut link.as_mut().unwrap().next;
+        }
+        link
+    }
+
+    // Adds the term to the polynomial. A term that cancels out an existing one removes it.
+    // Time Complexity is O(n)
+    pub fn insert_term(&mut self, coefficient: i32, exponent: u32) {
+        if coefficient == 0 {
+            return;
+        }
+        let link = self.find_link(exponent);
+        match link {
+            Some(node) if node.term.exponent == exponent => {
+                node.term.coefficient += coefficient;
+                if node.term.coefficient == 0 {
+                    let next = node.next.take();
+                    *link = next;
+                    self.count -= 1;
+                }
+            }
+            _ => {
+                let next = link.take();
+                *link = Some(HeapBox::new(TermNode {
+                    term: Term {
+                        coefficient,
+                        exponent,
+                    },
+                    next,
+                }));
+                self.count += 1;
+            }
+        }
+    }
+
+    // Removes the term with the given exponent and returns its coefficient.
+    // Time Complexity is O(n)
+    pub fn remove_term(&mut self, exponent: u32) -> Option<i32> {
+        let link = self.find_link(exponent);
+        match link {
+            Some(node) if node.term.exponent == exponent => {
+                let coefficient = node.term.coefficient;
+                let next = node.next.take();
+                *link = next;
+                self.count -= 1;
+                Some(coefficient)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn coefficient(&self, exponent: u32) -> i32 {
+        self.terms()
+            .find(|term| term.exponent == exponent)
+            .map_or(0, |term| term.coefficient)
+    }
+
+    pub fn evaluate(&self, x: i32) -> i32 {
+        self.terms()
+            .map(|term| term.coefficient * x.pow(term.exponent))
+            .sum()
+    }
+
+    // Both lists are sorted, so they are merged in a single pass.
+    // Time Complexity is O(n + m)
+    pub fn add(&self, other: &Self) -> Self {
+        let mut res = Self::new();
+        let mut tail = &mut res.head;
+        let mut first = self.terms().peekable();
+        let mut second = other.terms().peekable();
+        loop {
+            let term = match (first.peek(), second.peek()) {
+                (Some(a), Some(b)) if a.exponent == b.exponent => {
+                    let term = Term {
+                        coefficient: a.coefficient + b.coefficient,
+                        exponent: a.exponent,
+                    };
+                    first.next();
+                    second.next();
+                    term
+                }
+                (Some(a), Some(b)) if a.exponent > b.exponent => *first.next().unwrap(),
+                (Some(_), Some(_)) | (None, Some(_)) => *second.next().unwrap(),
+                (Some(_), None) => *first.next().unwrap(),
+                (None, None) => break,
+            };
+            if term.coefficient != 0 {
+                *tail = Some(HeapBox::new(TermNode { term, next: None }));
+                tail = &mut tail.as_mut().unwrap().next;
+                res.count += 1;
+            }
+        }
+        res
+    }
+
+    // Time Complexity is O(n * m * (n + m)) because every product is inserted into the sorted result.
+    pub fn multiply(&self, other: &Self) -> Self {
+        let mut res = Self::new();
+        for a in self.terms() {
+            for b in other.terms() {
+                res.insert_term(a.coefficient * b.coefficient, a.exponent + b.exponent);
+            }
+        }
+        res
+    }
+
+    pub fn from_polynomial(polynomial: &Polynomial) -> Self {
+        let mut res = Self::new();
+        for i in 0..polynomial.terms.get_len() {
+            res.insert_term(
+                polynomial.terms[i].coefficient,
+                polynomial.terms[i].exponent,
+            );
+        }
+        res
+    }
+
+    pub fn to_polynomial(&self) -> Polynomial {
+        let mut res = Polynomial::new(self.count);
+        for term in self.terms() {
+            res.set_term(term.coefficient, term.exponent);
+        }
+        res
+    }
+}
+
+impl Default for LinkedPolynomial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for LinkedPolynomial {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.terms().eq(other.terms())
+    }
+}
+
+impl Display for LinkedPolynomial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.count == 0 {
+            write!(f, "0")?;
+        }
+        for (i, term) in self.terms().enumerate() {
+            if i > 0 && term.coefficient.is_positive() {
+                write!(f, "+")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for LinkedPolynomial {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.terms()).finish()
+    }
+}
+
+impl Drop for LinkedPolynomial {
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+struct TermIterator<'a> {
+    current: Option<&'a TermNode>,
+}
+
+impl<'a> Iterator for TermIterator<'a> {
+    type Item = &'a Term;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| {
+            self.current = node.next.as_deref();
+            &node.term
+        })
+    }
+}
+
 mod polynomial {
     use super::*;
 
@@ -210,3 +439,146 @@ mod polynomial {
         assert_eq!(p1, res, "Invalid Polynomial addition!");
     }
 }
+
+#[cfg(test)]
+mod linked_polynomial {
+    use crate::structs::polynomials::{LinkedPolynomial, Polynomial};
+
+    fn from_terms(terms: &[(i32, u32)]) -> LinkedPolynomial {
+        let mut poly = LinkedPolynomial::new();
+        for (coefficient, exponent) in terms {
+            poly.insert_term(*coefficient, *exponent);
+        }
+        poly
+    }
+
+    #[test]
+    fn test_new() {
+        let poly = LinkedPolynomial::new();
+        assert_eq!(
+            poly.term_count(),
+            0,
+            "Invalid initial Linked Polynomial count!"
+        );
+        assert_eq!(
+            poly.degree(),
+            None,
+            "Empty Linked Polynomial has no degree!"
+        );
+        assert_eq!(format!("{}", poly), "0", "Invalid empty Linked Polynomial!");
+    }
+
+    #[test]
+    fn test_insert_term() {
+        let poly = from_terms(&[(3, 0), (4, 2), (-1, 5), (2, 2), (0, 7)]);
+        assert_eq!(
+            format!("{}", poly),
+            "-1x^5+6x^2+3x^0",
+            "Linked Polynomial terms must be sorted and merged!"
+        );
+        assert_eq!(poly.term_count(), 3, "Invalid Linked Polynomial count!");
+        assert_eq!(poly.degree(), Some(5), "Invalid Linked Polynomial degree!");
+    }
+
+    #[test]
+    fn test_insert_term_cancels() {
+        let mut poly = from_terms(&[(3, 2), (4, 1)]);
+        poly.insert_term(-3, 2);
+        assert_eq!(format!("{}", poly), "4x^1", "Zero terms must be removed!");
+        assert_eq!(poly.term_count(), 1, "Invalid Linked Polynomial count!");
+    }
+
+    #[test]
+    fn test_remove_term() {
+        let mut poly = from_terms(&[(3, 2), (4, 1), (5, 0)]);
+        assert_eq!(poly.remove_term(1), Some(4), "Invalid removed coefficient!");
+        assert_eq!(poly.remove_term(1), None, "Missing term can't be removed!");
+        assert_eq!(
+            poly.coefficient(1),
+            0,
+            "Removed term must have zero coefficient!"
+        );
+        assert_eq!(
+            format!("{}", poly),
+            "3x^2+5x^0",
+            "Invalid Linked Polynomial!"
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        // p(x) = 3x^2 + 4x^1 + 3x^0
+        let poly = from_terms(&[(3, 2), (4, 1), (3, 0)]);
+        assert_eq!(
+            poly.evaluate(2),
+            23,
+            "Invalid Linked Polynomial evaluation!"
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        // p1(x) = 3x^2 + 4x^1 + 3x^0
+        // p2(x) = 4x^3 - 3x^2 + 5x^1 + 2x^0
+        let p1 = from_terms(&[(3, 2), (4, 1), (3, 0)]);
+        let p2 = from_terms(&[(4, 3), (-3, 2), (5, 1), (2, 0)]);
+        let res = p1.add(&p2);
+        assert_eq!(
+            res,
+            from_terms(&[(4, 3), (9, 1), (5, 0)]),
+            "Invalid Linked Polynomial addition!"
+        );
+        assert_eq!(res.term_count(), 3, "Cancelled terms must not be counted!");
+    }
+
+    #[test]
+    fn test_multiply() {
+        // (x + 1) * (x - 1) = x^2 - 1
+        let p1 = from_terms(&[(1, 1), (1, 0)]);
+        let p2 = from_terms(&[(1, 1), (-1, 0)]);
+        assert_eq!(
+            p1.multiply(&p2),
+            from_terms(&[(1, 2), (-1, 0)]),
+            "Invalid Linked Polynomial multiplication!"
+        );
+        // (2x^3 + 3) * 4x^2 = 8x^5 + 12x^2
+        let p1 = from_terms(&[(2, 3), (3, 0)]);
+        let p2 = from_terms(&[(4, 2)]);
+        assert_eq!(
+            format!("{}", p1.multiply(&p2)),
+            "8x^5+12x^2",
+            "Invalid Linked Polynomial multiplication!"
+        );
+    }
+
+    #[test]
+    fn test_polynomial_conversions() {
+        let mut poly = Polynomial::new(3);
+        poly.set_term(4, 3);
+        poly.set_term(0, 2);
+        poly.set_term(2, 0);
+        let linked = LinkedPolynomial::from_polynomial(&poly);
+        assert_eq!(
+            linked.term_count(),
+            2,
+            "Zero terms must be dropped on conversion!"
+        );
+        assert_eq!(
+            format!("{}", linked),
+            "4x^3+2x^0",
+            "Invalid Linked Polynomial!"
+        );
+
+        let array = linked.to_polynomial();
+        assert_eq!(
+            format!("{}", array),
+            "4x^3+2x^0",
+            "Invalid Polynomial conversion!"
+        );
+        assert_eq!(
+            array.evaluate(2),
+            linked.evaluate(2),
+            "Invalid Polynomial conversion!"
+        );
+    }
+}
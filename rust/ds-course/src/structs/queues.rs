@@ -12,31 +12,155 @@ Queue Data Structures
         - Element itself represents priorities
 - Dynamic Queues with non-contiguous memory using a Linked List data structure
 */
-use crate::structs::arrays::HeapArray;
-use std::fmt::{Display, Formatter};
+use crate::structs::arrays::{ArrayIterator, HeapArray};
+use crate::structs::linked_lists::{
+    DoublyLinkedList, LinkedListADT, Node, NodeListADT, SinglyLinkedList,
+};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::ptr;
+use std::ptr::NonNull;
 
-trait QueueADT<T> {
-    fn enqueue(&mut self, item: T) -> Result<(), &str>;
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueueError {
+    Full,
+    Empty,
+}
+
+impl Display for QueueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            QueueError::Full => write!(f, "Queue is full!"),
+            QueueError::Empty => write!(f, "Queue is empty!"),
+        }
+    }
+}
+
+impl Error for QueueError {}
+
+pub trait QueueADT<T> {
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError>;
+
+    fn dequeue(&mut self) -> Result<T, QueueError>;
 
-    fn dequeue(&mut self) -> Result<(T), &str>;
+    fn peek_front(&self) -> Option<&T>;
 
-    fn is_empty(&self) -> bool;
+    fn peek_back(&self) -> Option<&T>;
+
+    fn len(&self) -> usize;
+
+    fn capacity(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 
     fn is_full(&self) -> bool;
+
+    // Iterates from the front to the back of the Queue.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    fn clear(&mut self);
+
+    // Dequeues the items while iterating over them. The items which weren't reached are dropped with the iterator.
+    fn drain(&mut self) -> Drain<'_, T, Self>
+    where
+        Self: Sized,
+    {
+        Drain {
+            queue: self,
+            marker: PhantomData,
+        }
+    }
 }
 
-trait DeQueueADT<T> {
-    fn enqueue_front(&mut self, item: T) -> Result<(), &str>;
+// Every DeQueue can be used as a Queue which enqueues at the back and dequeues from the front.
+pub trait DeQueueADT<T>: QueueADT<T> {
+    fn enqueue_front(&mut self, item: T) -> Result<(), QueueError>;
+
+    fn enqueue_back(&mut self, item: T) -> Result<(), QueueError> {
+        self.enqueue(item)
+    }
+
+    fn dequeue_front(&mut self) -> Result<T, QueueError> {
+        self.dequeue()
+    }
 
-    fn enqueue_back(&mut self, item: T) -> Result<(), &str>;
+    fn dequeue_back(&mut self) -> Result<T, QueueError>;
+}
 
-    fn dequeue_front(&mut self) -> Result<(T), &str>;
+pub struct QueueIterator<'a, T> {
+    data: &'a HeapArray<T>,
+    front: usize,
+    index: usize,
+    length: usize,
+}
 
-    fn dequeue_back(&mut self) -> Result<(T), &str>;
+impl<'a, T> QueueIterator<'a, T> {
+    fn new(data: &'a HeapArray<T>, front: usize, length: usize) -> Self {
+        Self {
+            data,
+            front,
+            index: 0,
+            length,
+        }
+    }
+}
 
-    fn is_empty(&self) -> bool;
+impl<'a, T> Iterator for QueueIterator<'a, T> {
+    type Item = &'a T;
 
-    fn is_full(&self) -> bool;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.length {
+            return None;
+        }
+        let item = &self.data[(self.front + self.index) % self.data.get_size()];
+        self.index += 1;
+        Some(item)
+    }
+}
+
+pub struct Drain<'a, T, Q: QueueADT<T>> {
+    queue: &'a mut Q,
+    marker: PhantomData<T>,
+}
+
+impl<T, Q: QueueADT<T>> Iterator for Drain<'_, T, Q> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.queue.dequeue().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T, Q: QueueADT<T>> Drop for Drain<'_, T, Q> {
+    fn drop(&mut self) {
+        self.queue.clear();
+    }
+}
+
+/*
+NOTE: The Queues below move their values out of the Array with get_copy, so the Array length only tracks how many
+slots were ever written and not which ones still hold values. They drop their values in place through this function
+and reset the Array length, otherwise the Array would drop the values which were already dequeued a second time.
+*/
+fn drop_values<T>(data: &mut HeapArray<T>, front: usize, length: usize) {
+    let size = data.get_size();
+    unsafe {
+        for i in 0..length {
+            ptr::drop_in_place(data.as_ptr_mut().add((front + i) % size));
+        }
+        data.set_len(0);
+    }
 }
 
 pub struct BasicQueue<T> {
@@ -57,9 +181,9 @@ impl<T> BasicQueue<T> {
 
 impl<T> QueueADT<T> for BasicQueue<T> {
     // Time Complexity is O(1).
-    fn enqueue(&mut self, item: T) -> Result<(), &str> {
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
         if self.is_full() {
-            return Err("Queue is full!");
+            return Err(QueueError::Full);
         }
         self.data.push(item);
         self.length += 1;
@@ -67,20 +191,35 @@ impl<T> QueueADT<T> for BasicQueue<T> {
     }
 
     // Time Complexity is O(n) because Array shifts all the elements left after item is removed.
-    fn dequeue(&mut self) -> Result<(T), &str> {
+    fn dequeue(&mut self) -> Result<T, QueueError> {
         if self.is_empty() {
-            return Err("Queue is empty!");
+            return Err(QueueError::Empty);
         }
         let data = self.data.delete(0);
         self.length -= 1;
         Ok(data)
     }
 
-    fn is_empty(&self) -> bool {
-        if self.length == 0 {
-            return true;
+    fn peek_front(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[0]),
+        }
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[self.length - 1]),
         }
-        false
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn capacity(&self) -> usize {
+        self.size
     }
 
     fn is_full(&self) -> bool {
@@ -89,6 +228,19 @@ impl<T> QueueADT<T> for BasicQueue<T> {
         }
         false
     }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        QueueIterator::new(&self.data, 0, self.length)
+    }
+
+    // Time Complexity is O(n).
+    fn clear(&mut self) {
+        while self.data.pop().is_some() {}
+        self.length = 0;
+    }
 }
 
 pub struct EphemeralQueue<T> {
@@ -113,9 +265,9 @@ impl<T> EphemeralQueue<T> {
 
 impl<T> QueueADT<T> for EphemeralQueue<T> {
     // Time Complexity is O(1).
-    fn enqueue(&mut self, item: T) -> Result<(), &str> {
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
         if self.is_full() {
-            return Err("Queue is full!");
+            return Err(QueueError::Full);
         }
         self.rear += 1;
         self.data.push(item);
@@ -124,15 +276,38 @@ impl<T> QueueADT<T> for EphemeralQueue<T> {
     }
 
     // Time Complexity is O(1).
-    fn dequeue(&mut self) -> Result<(T), &str> {
+    fn dequeue(&mut self) -> Result<T, QueueError> {
         if self.is_empty() {
-            return Err("Queue is empty!");
+            return Err(QueueError::Empty);
         }
         let data = unsafe { self.data.get_copy(self.front) };
         self.front += 1;
         Ok(data)
     }
 
+    fn peek_front(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[self.front]),
+        }
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[self.rear - 1]),
+        }
+    }
+
+    // The length counts every enqueued item, the items which are still in the Queue are between front and rear.
+    fn len(&self) -> usize {
+        self.rear - self.front
+    }
+
+    fn capacity(&self) -> usize {
+        self.size
+    }
+
     fn is_empty(&self) -> bool {
         if self.rear == self.front {
             return true;
@@ -140,15 +315,36 @@ impl<T> QueueADT<T> for EphemeralQueue<T> {
         false
     }
 
+    // The slots before front are never reused, so the Queue stays full after it is drained.
     fn is_full(&self) -> bool {
         if self.rear == self.size {
             return true;
         }
         false
     }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        QueueIterator::new(&self.data, self.front, self.len())
+    }
+
+    // Clearing makes the Queue usable again.
+    fn clear(&mut self) {
+        drop_values(&mut self.data, self.front, self.rear - self.front);
+        self.front = 0;
+        self.rear = 0;
+        self.length = 0;
+    }
+}
+
+impl<T> Drop for EphemeralQueue<T> {
+    fn drop(&mut self) {
+        drop_values(&mut self.data, self.front, self.rear - self.front);
+    }
 }
 
-#[derive(Debug)]
 pub struct CircularQueue<T> {
     front: usize,
     rear: usize,
@@ -171,12 +367,12 @@ impl<T> CircularQueue<T> {
 
 impl<T> QueueADT<T> for CircularQueue<T> {
     // Time Complexity is O(1).
-    fn enqueue(&mut self, item: T) -> Result<(), &str> {
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
         if self.is_full() {
-            return Err("Queue is full!");
+            return Err(QueueError::Full);
         }
         // We have to use push in this case because set operation doesn't work for arrays with no value at index.
-        if self.rear >= self.front {
+        if self.rear == self.data.get_len() {
             self.data.push(item);
         } else {
             self.data.set(self.rear, item);
@@ -187,9 +383,9 @@ impl<T> QueueADT<T> for CircularQueue<T> {
     }
 
     // Time Complexity is O(1).
-    fn dequeue(&mut self) -> Result<(T), &str> {
+    fn dequeue(&mut self) -> Result<T, QueueError> {
         if self.is_empty() {
-            return Err("Queue is empty!");
+            return Err(QueueError::Empty);
         }
         let data = unsafe { self.data.get_copy(self.front) };
         self.front = (self.front + 1) % self.size;
@@ -197,11 +393,26 @@ impl<T> QueueADT<T> for CircularQueue<T> {
         Ok(data)
     }
 
-    fn is_empty(&self) -> bool {
-        if self.length == 0 {
-            return true;
+    fn peek_front(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[self.front]),
         }
-        false
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[(self.rear + self.size - 1) % self.size]),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn capacity(&self) -> usize {
+        self.size
     }
 
     fn is_full(&self) -> bool {
@@ -210,21 +421,25 @@ impl<T> QueueADT<T> for CircularQueue<T> {
         }
         false
     }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        QueueIterator::new(&self.data, self.front, self.length)
+    }
+
+    fn clear(&mut self) {
+        drop_values(&mut self.data, self.front, self.length);
+        self.front = 0;
+        self.rear = 0;
+        self.length = 0;
+    }
 }
 
-impl<T: Display> Display for CircularQueue<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut l = self.length;
-        let mut i = self.front;
-        while l > 0 {
-            write!(f, "[{}]", self.data[i])?;
-            i = (i + 1) % self.size;
-            l -= 1;
-        }
-        for i in 0..(self.size - self.length) {
-            write!(f, "[]")?;
-        }
-        Ok(())
+impl<T> Drop for CircularQueue<T> {
+    fn drop(&mut self) {
+        drop_values(&mut self.data, self.front, self.length);
     }
 }
 
@@ -249,62 +464,68 @@ impl<T> DeQueue<T> {
     }
 }
 
-impl<T> DeQueueADT<T> for DeQueue<T> {
-    fn enqueue_front(&mut self, item: T) -> Result<(), &str> {
-        if self.is_full() {
-            return Err("Queue is full!");
-        }
-        // We have to use push in this case because set operation doesn't work for arrays with no value at index.
-        self.front = (self.front - 1) % self.size;
-        if self.front >= self.rear {
+impl<T> DeQueue<T> {
+    /*
+    NOTE: Enqueueing at the front writes the slots from the end of the Array, so the slots are not written in order
+    and the Array length can't tell which slots hold values. The values are written and moved out by their index
+    instead and the Queue drops the remaining values itself.
+    */
+    fn write(&mut self, index: usize, item: T) {
+        if index == self.data.get_len() {
             self.data.push(item);
         } else {
-            self.data.set(self.front, item);
+            self.data.set(index, item);
         }
-        self.length += 1;
-        Ok(())
     }
 
-    fn enqueue_back(&mut self, item: T) -> Result<(), &str> {
+    fn take(&mut self, index: usize) -> T {
+        unsafe { ptr::read(self.data.as_ptr().add(index)) }
+    }
+}
+
+impl<T> QueueADT<T> for DeQueue<T> {
+    // Time Complexity is O(1).
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
         if self.is_full() {
-            return Err("Queue is full!");
-        }
-        // We have to use push in this case because set operation doesn't work for arrays with no value at index.
-        if self.rear >= self.front {
-            self.data.push(item);
-        } else {
-            self.data.set(self.rear, item);
+            return Err(QueueError::Full);
         }
+        self.write(self.rear, item);
         self.rear = (self.rear + 1) % self.size;
         self.length += 1;
         Ok(())
     }
 
-    fn dequeue_front(&mut self) -> Result<(T), &str> {
+    // Time Complexity is O(1).
+    fn dequeue(&mut self) -> Result<T, QueueError> {
         if self.is_empty() {
-            return Err("DeQueue is empty!");
+            return Err(QueueError::Empty);
         }
-        let data = unsafe { self.data.get_copy(self.front) };
+        let data = self.take(self.front);
         self.front = (self.front + 1) % self.size;
         self.length -= 1;
         Ok(data)
     }
 
-    fn dequeue_back(&mut self) -> Result<(T), &str> {
-        if self.is_empty() {
-            return Err("DeQueue is empty!");
+    fn peek_front(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[self.front]),
         }
-        self.rear = (self.rear - 1) % self.size;
-        let data = unsafe { self.data.get_copy(self.rear) };
-        self.length -= 1;
-        Ok(data)
     }
 
-    fn is_empty(&self) -> bool {
-        if self.length == 0 {
-            return true;
+    fn peek_back(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[(self.rear + self.size - 1) % self.size]),
         }
-        false
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn capacity(&self) -> usize {
+        self.size
     }
 
     fn is_full(&self) -> bool {
@@ -313,356 +534,2598 @@ impl<T> DeQueueADT<T> for DeQueue<T> {
         }
         false
     }
-}
-
-#[cfg(test)]
-mod basic_queue {
-    use crate::structs::arrays::HeapArray;
-    use crate::structs::queues::{BasicQueue, QueueADT};
 
-    #[test]
-    fn test_new() {
-        let queue: BasicQueue<u8> = BasicQueue::new(5);
-        assert_eq!(queue.size, 5, "BasicQueue size is invalid!");
-        assert_eq!(queue.length, 0, "BasicQueue length is invalid!");
-        assert_eq!(queue.data, HeapArray::new(), "BasicQueue array is invalid!");
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        QueueIterator::new(&self.data, self.front, self.length)
     }
 
-    #[test]
-    fn test_enqueue() {
-        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 1, "BasicQueue length is invalid!");
-        queue.enqueue(2).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 2, "BasicQueue length is invalid!");
-        assert!(
-            queue.enqueue(3).is_err(),
-            "BasicQueue must throw error for being full!"
-        );
+    fn clear(&mut self) {
+        drop_values(&mut self.data, self.front, self.length);
+        self.front = 0;
+        self.rear = 0;
+        self.length = 0;
     }
+}
 
-    #[test]
-    fn test_dequeue() {
-        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        queue.enqueue(2).expect("Failed to enqueue!");
-        queue.dequeue().expect("Failed to dequeue!");
-        assert_eq!(queue.length, 1, "BasicQueue length is invalid!");
-        queue.dequeue().expect("Failed to dequeue!");
+impl<T> DeQueueADT<T> for DeQueue<T> {
+    // Time Complexity is O(1). The front moves backwards and wraps around to the end of the Array.
+    fn enqueue_front(&mut self, item: T) -> Result<(), QueueError> {
+        if self.is_full() {
+            return Err(QueueError::Full);
+        }
+        self.front = (self.front + self.size - 1) % self.size;
+        self.write(self.front, item);
+        self.length += 1;
+        Ok(())
     }
 
-    #[test]
-    fn test_is_empty() {
-        let mut queue: BasicQueue<u8> = BasicQueue::new(5);
-        assert_eq!(queue.is_empty(), true, "BasicQueue must be empty!");
-        queue.enqueue(5).expect("Failed to enqueue!");
-        assert_eq!(queue.is_empty(), false, "BasicQueue must not be empty!");
+    // Time Complexity is O(1). The rear moves backwards and wraps around to the end of the Array.
+    fn dequeue_back(&mut self) -> Result<T, QueueError> {
+        if self.is_empty() {
+            return Err(QueueError::Empty);
+        }
+        self.rear = (self.rear + self.size - 1) % self.size;
+        let data = self.take(self.rear);
+        self.length -= 1;
+        Ok(data)
     }
+}
 
-    #[test]
-    fn test_is_full() {
-        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
-        queue.enqueue(5).expect("Failed to enqueue!");
-        assert_eq!(queue.is_full(), false, "BasicQueue must not be full!");
-        queue.enqueue(5).expect("Failed to enqueue!");
-        assert_eq!(queue.is_full(), true, "BasicQueue must be full!");
+impl<T> Drop for DeQueue<T> {
+    fn drop(&mut self) {
+        drop_values(&mut self.data, self.front, self.length);
     }
 }
 
-mod ephemeral_queue {
-    use crate::structs::arrays::HeapArray;
-    use crate::structs::queues::{EphemeralQueue, QueueADT};
+/*
+Restricted DeQueues
+- Input Restricted DeQueue enqueues only at the back but dequeues at both ends.
+- Output Restricted DeQueue enqueues at both ends but dequeues only from the front.
+Both are DeQueues which only expose the allowed operations. They are Queues with one extra operation and don't
+implement DeQueueADT, so the restricted operation can't be called at all.
+*/
+macro_rules! impl_restricted_queue {
+    ($name:ident) => {
+        impl<T> QueueADT<T> for $name<T> {
+            fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
+                self.deque.enqueue(item)
+            }
 
-    #[test]
-    fn test_new() {
-        let queue: EphemeralQueue<u8> = EphemeralQueue::new(5);
-        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
-        assert_eq!(queue.rear, 0, "EphemeralQueue rear index is invalid!");
-        assert_eq!(queue.size, 5, "EphemeralQueue size is invalid!");
-        assert_eq!(queue.length, 0, "EphemeralQueue length is invalid!");
-        assert_eq!(
-            queue.data,
-            HeapArray::new(),
-            "EphemeralQueue array is invalid!"
-        );
-    }
+            fn dequeue(&mut self) -> Result<T, QueueError> {
+                self.deque.dequeue()
+            }
 
-    #[test]
-    fn test_enqueue() {
-        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 1, "EphemeralQueue length is invalid!");
-        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "EphemeralQueue rear index is invalid!");
-        queue.enqueue(2).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
-        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
-        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
-        assert!(
-            queue.enqueue(3).is_err(),
-            "EphemeralQueue must throw error for being full!"
-        );
-    }
+            fn peek_front(&self) -> Option<&T> {
+                self.deque.peek_front()
+            }
 
-    #[test]
-    fn test_dequeue() {
-        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        queue.enqueue(2).expect("Failed to enqueue!");
-        queue.dequeue().expect("Failed to dequeue!");
-        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
-        assert_eq!(queue.front, 1, "EphemeralQueue front index is invalid!");
-        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
-        queue.dequeue().expect("Failed to dequeue!");
-        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
-        assert_eq!(queue.front, 2, "EphemeralQueue front index is invalid!");
-        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
-        assert!(
-            queue.dequeue().is_err(),
-            "EphemeralQueue must throw error for being full!"
-        );
-    }
+            fn peek_back(&self) -> Option<&T> {
+                self.deque.peek_back()
+            }
 
-    #[test]
-    fn test_is_empty() {
-        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(5);
-        assert_eq!(queue.is_empty(), true, "EphemeralQueue must be empty!");
-        queue.enqueue(5);
-        assert_eq!(queue.is_empty(), false, "EphemeralQueue must not be empty!");
-    }
+            fn len(&self) -> usize {
+                self.deque.len()
+            }
 
-    #[test]
-    fn test_is_full() {
-        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
-        queue.enqueue(5).expect("Error");
-        assert_eq!(queue.is_full(), false, "EphemeralQueue must not be full!");
-        queue.enqueue(5).expect("Error");
-        assert_eq!(queue.is_full(), true, "EphemeralQueue must be full!");
-    }
+            fn capacity(&self) -> usize {
+                self.deque.capacity()
+            }
+
+            fn is_full(&self) -> bool {
+                self.deque.is_full()
+            }
+
+            fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+            where
+                T: 'a,
+            {
+                self.deque.iter()
+            }
+
+            fn clear(&mut self) {
+                self.deque.clear()
+            }
+        }
+    };
 }
 
-mod circular_queue {
-    use crate::structs::arrays::HeapArray;
-    use crate::structs::queues::{CircularQueue, QueueADT};
+pub struct InputRestrictedDeQueue<T> {
+    deque: DeQueue<T>,
+}
 
-    #[test]
+impl<T> InputRestrictedDeQueue<T> {
+    pub fn new(size: usize) -> Self {
+        Self {
+            deque: DeQueue::new(size),
+        }
+    }
+
+    // Time Complexity is O(1).
+    pub fn dequeue_back(&mut self) -> Result<T, QueueError> {
+        self.deque.dequeue_back()
+    }
+}
+
+impl_restricted_queue!(InputRestrictedDeQueue);
+
+pub struct OutputRestrictedDeQueue<T> {
+    deque: DeQueue<T>,
+}
+
+impl<T> OutputRestrictedDeQueue<T> {
+    pub fn new(size: usize) -> Self {
+        Self {
+            deque: DeQueue::new(size),
+        }
+    }
+
+    // Time Complexity is O(1).
+    pub fn enqueue_front(&mut self, item: T) -> Result<(), QueueError> {
+        self.deque.enqueue_front(item)
+    }
+}
+
+impl_restricted_queue!(OutputRestrictedDeQueue);
+
+/*
+Priority Queues
+- Element itself represents priorities: the heaps keep the element with the highest priority at the root.
+- Defined priority types: Priority Queue stores an explicit priority next to every element.
+
+The heaps are complete trees stored level by level in an Array. The children of the node at index i are at indices
+d * i + 1 to d * i + d and its parent is at index (i - 1) / d, where d is the number of children per node.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeapOrder {
+    Min,
+    Max,
+}
+
+impl HeapOrder {
+    // Returns true if the first value has to be closer to the root than the second one.
+    fn precedes<T: Ord>(&self, first: &T, second: &T) -> bool {
+        match self {
+            HeapOrder::Min => first < second,
+            HeapOrder::Max => first > second,
+        }
+    }
+}
+
+// Time Complexity is O(log n).
+fn sift_up<T: Ord>(data: &mut HeapArray<T>, mut index: usize, arity: usize, order: HeapOrder) {
+    while index > 0 {
+        let parent = (index - 1) / arity;
+        if !order.precedes(&data[index], &data[parent]) {
+            break;
+        }
+        data.swap(index, parent);
+        index = parent;
+    }
+}
+
+// Time Complexity is O(d log n) because every level compares all the children of the node.
+fn sift_down<T: Ord>(
+    data: &mut HeapArray<T>,
+    mut index: usize,
+    length: usize,
+    arity: usize,
+    order: HeapOrder,
+) {
+    loop {
+        let first_child = arity * index + 1;
+        let mut next = index;
+        for child in first_child..length.min(first_child + arity) {
+            if order.precedes(&data[child], &data[next]) {
+                next = child;
+            }
+        }
+        if next == index {
+            return;
+        }
+        data.swap(index, next);
+        index = next;
+    }
+}
+
+// Time Complexity is O(n) because most of the nodes are close to the leaves and only move down a few levels.
+fn heapify<T: Ord>(data: &mut HeapArray<T>, length: usize, arity: usize, order: HeapOrder) {
+    if length < 2 {
+        return;
+    }
+    for index in (0..=(length - 2) / arity).rev() {
+        sift_down(data, index, length, arity, order);
+    }
+}
+
+// Sorts the Array in ascending order in place.
+// Time Complexity is O(n log n).
+pub fn heap_sort<T: Ord>(array: &mut HeapArray<T>) {
+    let length = array.get_len();
+    heapify(array, length, 2, HeapOrder::Max);
+    for end in (1..length).rev() {
+        array.swap(0, end);
+        sift_down(array, 0, end, 2, HeapOrder::Max);
+    }
+}
+
+pub struct BinaryHeap<T: Ord> {
+    order: HeapOrder,
+    data: HeapArray<T>,
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new(size: usize, order: HeapOrder) -> Self {
+        Self {
+            order,
+            data: HeapArray::with_capacity(size),
+        }
+    }
+
+    // Builds the Heap from the elements of the Array. The size of the Array becomes the capacity of the Heap.
+    // Time Complexity is O(n).
+    pub fn from_array(mut array: HeapArray<T>, order: HeapOrder) -> Self {
+        let length = array.get_len();
+        heapify(&mut array, length, 2, order);
+        Self { order, data: array }
+    }
+
+    pub fn order(&self) -> HeapOrder {
+        self.order
+    }
+
+    // Time Complexity is O(log n).
+    pub fn push(&mut self, item: T) -> Result<(), QueueError> {
+        if self.is_full() {
+            return Err(QueueError::Full);
+        }
+        self.data.push(item);
+        let index = self.data.get_len() - 1;
+        sift_up(&mut self.data, index, 2, self.order);
+        Ok(())
+    }
+
+    // Time Complexity is O(log n).
+    pub fn pop(&mut self) -> Option<T> {
+        let length = self.data.get_len();
+        if length == 0 {
+            return None;
+        }
+        self.data.swap(0, length - 1);
+        let item = self.data.pop();
+        sift_down(&mut self.data, 0, length - 1, 2, self.order);
+        item
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[0]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.get_len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.get_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.get_len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.data.get_len() == self.data.get_size()
+    }
+
+    // Iterates in the order of the Array, which is not the order of the priorities.
+    pub fn iter(&self) -> ArrayIterator<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn clear(&mut self) {
+        while self.data.pop().is_some() {}
+    }
+}
+
+// Heap with d children per node. Pushing is faster because the tree is shallower, popping compares more children.
+pub struct DaryHeap<T: Ord> {
+    arity: usize,
+    order: HeapOrder,
+    data: HeapArray<T>,
+}
+
+impl<T: Ord> DaryHeap<T> {
+    pub fn new(arity: usize, size: usize, order: HeapOrder) -> Self {
+        Self::check_arity(arity);
+        Self {
+            arity,
+            order,
+            data: HeapArray::with_capacity(size),
+        }
+    }
+
+    // Time Complexity is O(n).
+    pub fn from_array(arity: usize, mut array: HeapArray<T>, order: HeapOrder) -> Self {
+        Self::check_arity(arity);
+        let length = array.get_len();
+        heapify(&mut array, length, arity, order);
+        Self {
+            arity,
+            order,
+            data: array,
+        }
+    }
+
+    fn check_arity(arity: usize) {
+        if arity < 2 {
+            panic!("Heap arity must be at least 2!");
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn order(&self) -> HeapOrder {
+        self.order
+    }
+
+    // Time Complexity is O(log n) with the logarithm base d.
+    pub fn push(&mut self, item: T) -> Result<(), QueueError> {
+        if self.is_full() {
+            return Err(QueueError::Full);
+        }
+        self.data.push(item);
+        let index = self.data.get_len() - 1;
+        sift_up(&mut self.data, index, self.arity, self.order);
+        Ok(())
+    }
+
+    // Time Complexity is O(d log n) with the logarithm base d.
+    pub fn pop(&mut self) -> Option<T> {
+        let length = self.data.get_len();
+        if length == 0 {
+            return None;
+        }
+        self.data.swap(0, length - 1);
+        let item = self.data.pop();
+        sift_down(&mut self.data, 0, length - 1, self.arity, self.order);
+        item
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        match self.is_empty() {
+            true => None,
+            false => Some(&self.data[0]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.get_len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.get_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.get_len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.data.get_len() == self.data.get_size()
+    }
+
+    // Iterates in the order of the Array, which is not the order of the priorities.
+    pub fn iter(&self) -> ArrayIterator<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn clear(&mut self) {
+        while self.data.pop().is_some() {}
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IndexedQueueError {
+    IndexOutOfBounds(usize),
+    IndexInUse(usize),
+    IndexNotFound(usize),
+    KeyNotDecreased,
+    KeyNotIncreased,
+}
+
+impl Display for IndexedQueueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexedQueueError::IndexOutOfBounds(index) => {
+                write!(f, "Index {} is out of bounds!", index)
+            }
+            IndexedQueueError::IndexInUse(index) => {
+                write!(f, "Index {} is already in the Queue!", index)
+            }
+            IndexedQueueError::IndexNotFound(index) => {
+                write!(f, "Index {} is not in the Queue!", index)
+            }
+            IndexedQueueError::KeyNotDecreased => {
+                write!(f, "Key must be less than the current key!")
+            }
+            IndexedQueueError::KeyNotIncreased => {
+                write!(f, "Key must be greater than the current key!")
+            }
+        }
+    }
+}
+
+impl Error for IndexedQueueError {}
+
+/*
+Indexed Priority Queue
+Every key is stored under an index between 0 and the capacity, so the key of an element can be changed later, e.g.
+the distance of a vertex in Dijkstra's algorithm. The Heap only stores the indices and every index remembers its
+position in the Heap, so the element can be found in O(1) and moved up or down in O(log n).
+*/
+pub struct IndexedPriorityQueue<T: Ord> {
+    order: HeapOrder,
+    heap: HeapArray<usize>,
+    positions: HeapArray<Option<usize>>,
+    keys: HeapArray<Option<T>>,
+}
+
+impl<T: Ord> IndexedPriorityQueue<T> {
+    pub fn new(size: usize, order: HeapOrder) -> Self {
+        let mut positions = HeapArray::with_capacity(size);
+        let mut keys = HeapArray::with_capacity(size);
+        for _i in 0..size {
+            positions.push(None);
+            keys.push(None);
+        }
+        Self {
+            order,
+            heap: HeapArray::with_capacity(size),
+            positions,
+            keys,
+        }
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), IndexedQueueError> {
+        if index >= self.capacity() {
+            return Err(IndexedQueueError::IndexOutOfBounds(index));
+        }
+        Ok(())
+    }
+
+    fn position(&self, index: usize) -> Result<usize, IndexedQueueError> {
+        self.check_index(index)?;
+        self.positions[index].ok_or(IndexedQueueError::IndexNotFound(index))
+    }
+
+    fn key_at(&self, position: usize) -> &T {
+        self.keys[self.heap[position]].as_ref().unwrap()
+    }
+
+    fn swap(&mut self, position_1: usize, position_2: usize) {
+        self.heap.swap(position_1, position_2);
+        self.positions[self.heap[position_1]] = Some(position_1);
+        self.positions[self.heap[position_2]] = Some(position_2);
+    }
+
+    fn sift_up(&mut self, mut position: usize) {
+        while position > 0 {
+            let parent = (position - 1) / 2;
+            if !self
+                .order
+                .precedes(self.key_at(position), self.key_at(parent))
+            {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut position: usize) {
+        let length = self.heap.get_len();
+        loop {
+            let mut next = position;
+            for child in (2 * position + 1)..length.min(2 * position + 3) {
+                if self.order.precedes(self.key_at(child), self.key_at(next)) {
+                    next = child;
+                }
+            }
+            if next == position {
+                return;
+            }
+            self.swap(position, next);
+            position = next;
+        }
+    }
+
+    // Time Complexity is O(log n).
+    pub fn insert(&mut self, index: usize, key: T) -> Result<(), IndexedQueueError> {
+        self.check_index(index)?;
+        if self.positions[index].is_some() {
+            return Err(IndexedQueueError::IndexInUse(index));
+        }
+        let position = self.heap.get_len();
+        self.heap.push(index);
+        self.positions[index] = Some(position);
+        self.keys[index] = Some(key);
+        self.sift_up(position);
+        Ok(())
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < self.capacity() && self.positions[index].is_some()
+    }
+
+    pub fn key_of(&self, index: usize) -> Option<&T> {
+        match index < self.capacity() {
+            true => self.keys[index].as_ref(),
+            false => None,
+        }
+    }
+
+    // Returns the index and the key with the highest priority.
+    pub fn peek(&self) -> Option<(usize, &T)> {
+        match self.is_empty() {
+            true => None,
+            false => Some((self.heap[0], self.key_at(0))),
+        }
+    }
+
+    // Time Complexity is O(log n).
+    pub fn pop(&mut self) -> Option<(usize, T)> {
+        let index = self.peek()?.0;
+        self.remove(index).ok().map(|key| (index, key))
+    }
+
+    // Time Complexity is O(log n).
+    pub fn remove(&mut self, index: usize) -> Result<T, IndexedQueueError> {
+        let position = self.position(index)?;
+        let last = self.heap.get_len() - 1;
+        self.swap(position, last);
+        self.heap.pop();
+        self.positions[index] = None;
+        let key = self.keys[index].take().unwrap();
+        if position < last {
+            self.sift_up(position);
+            self.sift_down(position);
+        }
+        Ok(key)
+    }
+
+    // Replaces the key and moves the index to its new position.
+    // Time Complexity is O(log n).
+    pub fn change_key(&mut self, index: usize, key: T) -> Result<(), IndexedQueueError> {
+        let position = self.position(index)?;
+        self.keys[index] = Some(key);
+        self.sift_up(position);
+        self.sift_down(self.positions[index].unwrap());
+        Ok(())
+    }
+
+    // Time Complexity is O(log n).
+    pub fn decrease_key(&mut self, index: usize, key: T) -> Result<(), IndexedQueueError> {
+        self.position(index)?;
+        if self.key_of(index).is_some_and(|current| key >= *current) {
+            return Err(IndexedQueueError::KeyNotDecreased);
+        }
+        self.change_key(index, key)
+    }
+
+    // Time Complexity is O(log n).
+    pub fn increase_key(&mut self, index: usize, key: T) -> Result<(), IndexedQueueError> {
+        self.position(index)?;
+        if self.key_of(index).is_some_and(|current| key <= *current) {
+            return Err(IndexedQueueError::KeyNotIncreased);
+        }
+        self.change_key(index, key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.get_len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.keys.get_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.get_len() == 0
+    }
+}
+
+struct PriorityEntry<P, T> {
+    priority: P,
+    sequence: usize,
+    item: T,
+}
+
+// Higher priorities come first and the elements with equal priorities keep the order they were enqueued in.
+impl<P: Ord, T> Ord for PriorityEntry<P, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<P: Ord, T> PartialOrd for PriorityEntry<P, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Ord, T> PartialEq for PriorityEntry<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<P: Ord, T> Eq for PriorityEntry<P, T> {}
+
+/*
+Priority Queue with defined priority types
+The element with the highest priority is dequeued first and elements of the same priority class are dequeued in
+FIFO order. Wrap the priority in std::cmp::Reverse to dequeue the lowest priority first.
+*/
+pub struct PriorityQueue<P: Ord, T> {
+    heap: BinaryHeap<PriorityEntry<P, T>>,
+    sequence: usize,
+}
+
+impl<P: Ord, T> PriorityQueue<P, T> {
+    pub fn new(size: usize) -> Self {
+        Self {
+            heap: BinaryHeap::new(size, HeapOrder::Max),
+            sequence: 0,
+        }
+    }
+
+    // Time Complexity is O(log n).
+    pub fn enqueue(&mut self, priority: P, item: T) -> Result<(), QueueError> {
+        self.heap.push(PriorityEntry {
+            priority,
+            sequence: self.sequence,
+            item,
+        })?;
+        self.sequence += 1;
+        Ok(())
+    }
+
+    // Time Complexity is O(log n).
+    pub fn dequeue(&mut self) -> Result<T, QueueError> {
+        self.dequeue_with_priority().map(|(_, item)| item)
+    }
+
+    pub fn dequeue_with_priority(&mut self) -> Result<(P, T), QueueError> {
+        let entry = self.heap.pop().ok_or(QueueError::Empty)?;
+        Ok((entry.priority, entry.item))
+    }
+
+    pub fn peek(&self) -> Option<(&P, &T)> {
+        self.heap.peek().map(|entry| (&entry.priority, &entry.item))
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.heap.capacity()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.heap.is_full()
+    }
+
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.sequence = 0;
+    }
+}
+
+/*
+Dynamic Queues
+The Queues are built on Linked Lists, so they grow one node at a time and are never full.
+NOTE: The Linked Lists copy the values out of the nodes when they are popped, so the elements must be Copy.
+*/
+pub struct LinkedQueue<T> {
+    list: SinglyLinkedList<T>,
+    // Last node of the list, which lets enqueue skip walking the whole list.
+    tail: Option<NonNull<Node<T>>>,
+}
+
+impl<T> LinkedQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            list: SinglyLinkedList::new(),
+            tail: None,
+        }
+    }
+}
+
+impl<T: Copy> QueueADT<T> for LinkedQueue<T> {
+    // Time Complexity is O(1).
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
+        // The tail is only changed by the Queue, so it always points to the last node of the list.
+        self.tail = Some(unsafe { self.list.push_back_after(self.tail, item) });
+        Ok(())
+    }
+
+    // Time Complexity is O(1).
+    fn dequeue(&mut self) -> Result<T, QueueError> {
+        let item = self.list.pop_front().ok_or(QueueError::Empty)?;
+        if self.list.len() == 0 {
+            self.tail = None;
+        }
+        Ok(item)
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        self.list.head_as_ref().map(|node| node.data_as_ref())
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        self.tail.map(|tail| unsafe { tail.as_ref() }.as_ref())
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.list.iter()
+    }
+
+    fn clear(&mut self) {
+        while self.list.pop_front().is_some() {}
+        self.tail = None;
+    }
+}
+
+pub struct LinkedDeQueue<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> LinkedDeQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            list: DoublyLinkedList::new(),
+        }
+    }
+}
+
+impl<T: Copy> QueueADT<T> for LinkedDeQueue<T> {
+    // Time Complexity is O(1).
+    fn enqueue(&mut self, item: T) -> Result<(), QueueError> {
+        self.list.push_back(item);
+        Ok(())
+    }
+
+    // Time Complexity is O(1).
+    fn dequeue(&mut self) -> Result<T, QueueError> {
+        self.list.pop_front().ok_or(QueueError::Empty)
+    }
+
+    fn peek_front(&self) -> Option<&T> {
+        self.list.head_as_ref().map(|node| node.data_as_ref())
+    }
+
+    fn peek_back(&self) -> Option<&T> {
+        self.list.tail_as_ref().map(|node| node.data_as_ref())
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn capacity(&self) -> usize {
+        usize::MAX
+    }
+
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.list.iter()
+    }
+
+    fn clear(&mut self) {
+        while self.list.pop_front().is_some() {}
+    }
+}
+
+impl<T: Copy> DeQueueADT<T> for LinkedDeQueue<T> {
+    // Time Complexity is O(1).
+    fn enqueue_front(&mut self, item: T) -> Result<(), QueueError> {
+        self.list.push_front(item);
+        Ok(())
+    }
+
+    // Time Complexity is O(1).
+    fn dequeue_back(&mut self) -> Result<T, QueueError> {
+        self.list.pop_back().ok_or(QueueError::Empty)
+    }
+}
+
+/*
+Common traits of the Queues
+Queues are compared, cloned and printed by their items from the front to the back. The capacity and the position of
+the items in the Array don't matter, so a Queue which wrapped around equals a Queue with the same items which didn't.
+Fixed Queues print their empty slots as well, e.g. [1][2][] for a Queue of size 3.
+*/
+fn fmt_queue<'a, T: Display + 'a>(
+    f: &mut Formatter<'_>,
+    items: impl Iterator<Item = &'a T>,
+    empty_slots: usize,
+) -> fmt::Result {
+    for item in items {
+        write!(f, "[{}]", item)?;
+    }
+    for _i in 0..empty_slots {
+        write!(f, "[]")?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_queue_traits {
+    ($name:ident<T: $bound:path>, $new:expr, $empty_slots:expr) => {
+        impl<T: $bound + Display> Display for $name<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                fmt_queue(f, self.iter(), $empty_slots(self))
+            }
+        }
+
+        impl<T: $bound + Debug> Debug for $name<T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: $bound + PartialEq> PartialEq for $name<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len() == other.len() && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: $bound + Clone> Clone for $name<T> {
+            fn clone(&self) -> Self {
+                let mut queue = $new(self.capacity());
+                queue.extend(self.iter().cloned());
+                queue
+            }
+        }
+
+        // Panics if the Queue becomes full.
+        impl<T: $bound> Extend<T> for $name<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for item in iter {
+                    if let Err(error) = self.enqueue(item) {
+                        panic!("{}", error);
+                    }
+                }
+            }
+        }
+
+        // Fixed Queues are as large as the number of the items.
+        impl<T: $bound> FromIterator<T> for $name<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let items: Vec<T> = iter.into_iter().collect();
+                let mut queue = $new(items.len());
+                queue.extend(items);
+                queue
+            }
+        }
+    };
+}
+
+fn fixed_empty_slots<T, Q: QueueADT<T>>(queue: &Q) -> usize {
+    queue.capacity() - queue.len()
+}
+
+fn linked_empty_slots<T, Q: QueueADT<T>>(_queue: &Q) -> usize {
+    0
+}
+
+impl_queue_traits!(BasicQueue<T: Sized>, BasicQueue::new, fixed_empty_slots);
+impl_queue_traits!(EphemeralQueue<T: Sized>, EphemeralQueue::new, fixed_empty_slots);
+impl_queue_traits!(CircularQueue<T: Sized>, CircularQueue::new, fixed_empty_slots);
+impl_queue_traits!(DeQueue<T: Sized>, DeQueue::new, fixed_empty_slots);
+impl_queue_traits!(InputRestrictedDeQueue<T: Sized>, InputRestrictedDeQueue::new, fixed_empty_slots);
+impl_queue_traits!(OutputRestrictedDeQueue<T: Sized>, OutputRestrictedDeQueue::new, fixed_empty_slots);
+impl_queue_traits!(LinkedQueue<T: Copy>, |_| LinkedQueue::new(), linked_empty_slots);
+impl_queue_traits!(LinkedDeQueue<T: Copy>, |_| LinkedDeQueue::new(), linked_empty_slots);
+
+#[cfg(test)]
+mod basic_queue {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{BasicQueue, QueueADT, QueueError};
+
+    #[test]
+    fn test_new() {
+        let queue: BasicQueue<u8> = BasicQueue::new(5);
+        assert_eq!(queue.size, 5, "BasicQueue size is invalid!");
+        assert_eq!(queue.length, 0, "BasicQueue length is invalid!");
+        assert_eq!(queue.data, HeapArray::new(), "BasicQueue array is invalid!");
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 1, "BasicQueue length is invalid!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 2, "BasicQueue length is invalid!");
+        assert!(
+            queue.enqueue(3).is_err(),
+            "BasicQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_dequeue() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert_eq!(queue.length, 1, "BasicQueue length is invalid!");
+        queue.dequeue().expect("Failed to dequeue!");
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(5);
+        assert_eq!(queue.is_empty(), true, "BasicQueue must be empty!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        assert_eq!(queue.is_empty(), false, "BasicQueue must not be empty!");
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
+        queue.enqueue(5).expect("Failed to enqueue!");
+        assert_eq!(queue.is_full(), false, "BasicQueue must not be full!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        assert_eq!(queue.is_full(), true, "BasicQueue must be full!");
+    }
+
+    #[test]
+    fn test_peek() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(3);
+        assert_eq!(queue.peek_front(), None, "Empty BasicQueue must not peek!");
+        assert_eq!(queue.peek_back(), None, "Empty BasicQueue must not peek!");
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        assert_eq!(queue.peek_front(), Some(&1), "BasicQueue front is invalid!");
+        assert_eq!(queue.peek_back(), Some(&2), "BasicQueue back is invalid!");
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(1);
+        assert_eq!(
+            queue.dequeue(),
+            Err(QueueError::Empty),
+            "BasicQueue must be empty!"
+        );
+        queue.enqueue(1).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.enqueue(2),
+            Err(QueueError::Full),
+            "BasicQueue must be full!"
+        );
+        assert_eq!(
+            format!("{}", QueueError::Full),
+            "Queue is full!",
+            "QueueError message is invalid!"
+        );
+        assert_eq!(
+            format!("{}", QueueError::Empty),
+            "Queue is empty!",
+            "QueueError message is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_iter_and_clear() {
+        let mut queue: BasicQueue<String> = BasicQueue::new(3);
+        queue
+            .enqueue(String::from("a"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue(String::from("b"))
+            .expect("Failed to enqueue!");
+        assert_eq!(queue.len(), 2, "BasicQueue length is invalid!");
+        assert_eq!(queue.capacity(), 3, "BasicQueue capacity is invalid!");
+        assert_eq!(
+            queue.iter().collect::<Vec<_>>(),
+            vec!["a", "b"],
+            "BasicQueue iterator is invalid!"
+        );
+        queue.clear();
+        assert!(queue.is_empty(), "BasicQueue must be empty after clear!");
+        queue
+            .enqueue(String::from("c"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.peek_front().map(String::as_str),
+            Some("c"),
+            "BasicQueue front is invalid!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod ephemeral_queue {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{EphemeralQueue, QueueADT};
+
+    #[test]
+    fn test_new() {
+        let queue: EphemeralQueue<u8> = EphemeralQueue::new(5);
+        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
+        assert_eq!(queue.rear, 0, "EphemeralQueue rear index is invalid!");
+        assert_eq!(queue.size, 5, "EphemeralQueue size is invalid!");
+        assert_eq!(queue.length, 0, "EphemeralQueue length is invalid!");
+        assert_eq!(
+            queue.data,
+            HeapArray::new(),
+            "EphemeralQueue array is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 1, "EphemeralQueue length is invalid!");
+        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "EphemeralQueue rear index is invalid!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
+        assert_eq!(queue.front, 0, "EphemeralQueue front index is invalid!");
+        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
+        assert!(
+            queue.enqueue(3).is_err(),
+            "EphemeralQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_dequeue() {
+        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
+        assert_eq!(queue.front, 1, "EphemeralQueue front index is invalid!");
+        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert_eq!(queue.length, 2, "EphemeralQueue length is invalid!");
+        assert_eq!(queue.front, 2, "EphemeralQueue front index is invalid!");
+        assert_eq!(queue.rear, 2, "EphemeralQueue rear index is invalid!");
+        assert!(
+            queue.dequeue().is_err(),
+            "EphemeralQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(5);
+        assert_eq!(queue.is_empty(), true, "EphemeralQueue must be empty!");
+        queue.enqueue(5);
+        assert_eq!(queue.is_empty(), false, "EphemeralQueue must not be empty!");
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(2);
+        queue.enqueue(5).expect("Error");
+        assert_eq!(queue.is_full(), false, "EphemeralQueue must not be full!");
+        queue.enqueue(5).expect("Error");
+        assert_eq!(queue.is_full(), true, "EphemeralQueue must be full!");
+    }
+
+    #[test]
+    fn test_peek_and_len() {
+        let mut queue: EphemeralQueue<u8> = EphemeralQueue::new(3);
+        assert_eq!(
+            queue.peek_front(),
+            None,
+            "Empty EphemeralQueue must not peek!"
+        );
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert_eq!(queue.len(), 2, "EphemeralQueue length is invalid!");
+        assert_eq!(
+            queue.peek_front(),
+            Some(&2),
+            "EphemeralQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.peek_back(),
+            Some(&3),
+            "EphemeralQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3],
+            "EphemeralQueue iterator is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut queue: EphemeralQueue<String> = EphemeralQueue::new(2);
+        queue
+            .enqueue(String::from("a"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue(String::from("b"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue().expect("Failed to dequeue!"),
+            "a",
+            "EphemeralQueue dequeue is invalid!"
+        );
+        assert!(queue.is_full(), "Drained EphemeralQueue must stay full!");
+        queue.clear();
+        assert!(
+            !queue.is_full(),
+            "Cleared EphemeralQueue must be usable again!"
+        );
+        queue
+            .enqueue(String::from("c"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.peek_back().map(String::as_str),
+            Some("c"),
+            "EphemeralQueue back is invalid!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod circular_queue {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{CircularQueue, QueueADT};
+
+    #[test]
+    fn test_new() {
+        let queue: CircularQueue<u8> = CircularQueue::new(5);
+        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 0, "CircularQueue rear index is invalid!");
+        assert_eq!(queue.size, 5, "CircularQueue size is invalid!");
+        assert_eq!(queue.length, 0, "CircularQueue length is invalid!");
+        assert_eq!(
+            queue.data,
+            HeapArray::new(),
+            "CircularQueue array is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 1, "CircularQueue length is invalid!");
+        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
+        assert_eq!(
+            format!("{}", queue.data),
+            "[1]",
+            "CircularQueue is invalid!"
+        );
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 3, "CircularQueue length is invalid!");
+        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 0, "CircularQueue rear index is invalid!");
+        assert_eq!(
+            format!("{}", queue.data),
+            "[1, 2, 3]",
+            "CircularQueue is invalid!"
+        );
+        assert!(
+            queue.enqueue(3).is_err(),
+            "CircularQueue must throw error for being full!"
+        );
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 3, "CircularQueue length is invalid!");
+        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
+        assert_eq!(
+            format!("{}", queue.data),
+            "[4, 2, 3]",
+            "CircularQueue is invalid!"
+        );
+        assert!(
+            queue.enqueue(3).is_err(),
+            "CircularQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_dequeue() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue().expect("Failed to dequeue!"),
+            1,
+            "CircularQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(queue.length, 1, "CircularQueue length is invalid!");
+        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 2, "CircularQueue rear index is invalid!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        println!("{}", queue.data);
+        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
+        assert_eq!(
+            queue.dequeue().expect("Failed to dequeue!"),
+            2,
+            "CircularQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(queue.length, 2, "CircularQueue length is invalid!");
+        assert_eq!(queue.front, 2, "CircularQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert!(
+            queue.dequeue().is_err(),
+            "CircularQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(5);
+        assert_eq!(queue.is_empty(), true, "CircularQueue must be empty!");
+        queue.enqueue(5);
+        assert_eq!(queue.is_empty(), false, "CircularQueue must not be empty!");
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(2);
+        queue.enqueue(5).expect("Error");
+        assert_eq!(queue.is_full(), false, "CircularQueue must not be full!");
+        queue.enqueue(5).expect("Error");
+        assert_eq!(queue.is_full(), true, "CircularQueue must be full!");
+    }
+
+    #[test]
+    fn test_display_trait() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
+        queue.enqueue(1).expect("Error");
+        assert_eq!(format!("{}", queue), "[1][][]");
+        queue.enqueue(2).expect("Error");
+        println!("{}", queue.data);
+        assert_eq!(format!("{}", queue), "[1][2][]");
+        queue.enqueue(3).expect("Error");
+        assert_eq!(format!("{}", queue), "[1][2][3]");
+        queue.dequeue().expect("Error");
+        assert_eq!(format!("{}", queue), "[2][3][]");
+        queue.enqueue(4).expect("Error");
+        assert_eq!(format!("{}", queue), "[2][3][4]");
+        queue.dequeue().expect("Error");
+        assert_eq!(format!("{}", queue), "[3][4][]");
+        queue.dequeue().expect("Error");
+        assert_eq!(format!("{}", queue), "[4][][]");
+        queue.dequeue().expect("Error");
+        assert_eq!(format!("{}", queue), "[][][]");
+    }
+
+    #[test]
+    fn test_enqueue_after_wraparound() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
+        for i in 0..3 {
+            queue.enqueue(i).expect("Failed to enqueue!");
+        }
+        for _i in 0..3 {
+            queue.dequeue().expect("Failed to dequeue!");
+        }
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue(),
+            Ok(3),
+            "CircularQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok(4),
+            "CircularQueue dequeue returned value is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_peek_and_iter() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.peek_front(),
+            Some(&2),
+            "CircularQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.peek_back(),
+            Some(&4),
+            "CircularQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![2, 3, 4],
+            "CircularQueue iterator is invalid!"
+        );
+        assert_eq!(queue.len(), 3, "CircularQueue length is invalid!");
+        assert_eq!(queue.capacity(), 3, "CircularQueue capacity is invalid!");
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut queue: CircularQueue<String> = CircularQueue::new(2);
+        queue
+            .enqueue(String::from("a"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue(String::from("b"))
+            .expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue
+            .enqueue(String::from("c"))
+            .expect("Failed to enqueue!");
+        queue.clear();
+        assert!(queue.is_empty(), "CircularQueue must be empty after clear!");
+        assert_eq!(
+            queue.peek_front(),
+            None,
+            "Cleared CircularQueue must not peek!"
+        );
+        queue
+            .enqueue(String::from("d"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.iter().collect::<Vec<_>>(),
+            vec!["d"],
+            "CircularQueue iterator is invalid!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod dequeue {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{DeQueue, DeQueueADT, QueueADT, QueueError};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_new() {
+        let queue: DeQueue<u8> = DeQueue::new(5);
+        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
+        assert_eq!(queue.size, 5, "DeQueue size is invalid!");
+        assert_eq!(queue.length, 0, "DeQueue length is invalid!");
+        assert_eq!(queue.data, HeapArray::new(), "DeQueue array is invalid!");
+    }
+
+    #[test]
+    fn test_enqueue_back() {
+        let mut queue: DeQueue<u8> = DeQueue::new(3);
+        queue.enqueue_back(1).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 1, "DeQueue length is invalid!");
+        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        assert_eq!(format!("{}", queue.data), "[1]", "DeQueue is invalid!");
+        queue.enqueue_back(2).expect("Failed to enqueue!");
+        queue.enqueue_back(3).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 3, "DeQueue length is invalid!");
+        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
+        assert!(
+            queue.enqueue_back(3).is_err(),
+            "DeQueue must throw error for being full!"
+        );
+        queue.dequeue_front().expect("Failed to dequeue!");
+        queue.enqueue_back(4).expect("Failed to enqueue!");
+        assert_eq!(queue.length, 3, "DeQueue length is invalid!");
+        assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        assert_eq!(
+            format!("{}", queue.data),
+            "[4, 2, 3]",
+            "DeQueue is invalid!"
+        );
+        assert!(
+            queue.enqueue_back(3).is_err(),
+            "DeQueue must throw error for being full!"
+        );
+    }
+
+    fn fill<Q: QueueADT<u8>>(queue: &mut Q, items: &[u8]) {
+        for item in items {
+            queue.enqueue(*item).expect("Failed to enqueue!");
+        }
+    }
+
+    #[test]
+    fn test_as_queue() {
+        let mut queue: DeQueue<u8> = DeQueue::new(3);
+        fill(&mut queue, &[1, 2]);
+        assert_eq!(queue.peek_front(), Some(&1), "DeQueue front is invalid!");
+        assert_eq!(queue.peek_back(), Some(&2), "DeQueue back is invalid!");
+        assert_eq!(
+            queue.dequeue_front(),
+            Ok(1),
+            "DeQueue must dequeue in order!"
+        );
+        assert_eq!(queue.dequeue(), Ok(2), "DeQueue must dequeue in order!");
+        assert_eq!(
+            queue.dequeue_back(),
+            Err(QueueError::Empty),
+            "DeQueue must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_iter_and_clear() {
+        let mut queue: DeQueue<String> = DeQueue::new(3);
+        queue
+            .enqueue_back(String::from("a"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue_back(String::from("b"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.iter().collect::<Vec<_>>(),
+            vec!["a", "b"],
+            "DeQueue iterator is invalid!"
+        );
+        assert_eq!(queue.len(), 2, "DeQueue length is invalid!");
+        assert_eq!(queue.capacity(), 3, "DeQueue capacity is invalid!");
+        queue.clear();
+        assert!(queue.is_empty(), "DeQueue must be empty after clear!");
+    }
+
+    #[test]
+    fn test_dequeue() {
+        let mut queue: DeQueue<u8> = DeQueue::new(3);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue().expect("Failed to dequeue!"),
+            1,
+            "DeQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(queue.length, 1, "DeQueue length is invalid!");
+        assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 2, "DeQueue rear index is invalid!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        assert_eq!(
+            queue.dequeue().expect("Failed to dequeue!"),
+            2,
+            "DeQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(queue.length, 2, "DeQueue length is invalid!");
+        assert_eq!(queue.front, 2, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        assert!(
+            queue.dequeue().is_err(),
+            "DeQueue must throw error for being empty!"
+        );
+    }
+
+    #[test]
+    fn test_enqueue_front() {
+        let mut queue: DeQueue<u8> = DeQueue::new(3);
+        queue.enqueue_front(1).expect("Failed to enqueue!");
+        assert_eq!(queue.front, 2, "DeQueue front index must wrap around!");
+        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
+        queue.enqueue_front(2).expect("Failed to enqueue!");
+        queue.enqueue_back(3).expect("Failed to enqueue!");
+        assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
+        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![2, 1, 3],
+            "DeQueue order is invalid!"
+        );
+        assert_eq!(
+            queue.enqueue_front(4),
+            Err(QueueError::Full),
+            "DeQueue must throw error for being full!"
+        );
+    }
+
+    #[test]
+    fn test_dequeue_back() {
+        let mut queue: DeQueue<u8> = DeQueue::new(3);
+        assert_eq!(
+            queue.dequeue_back(),
+            Err(QueueError::Empty),
+            "DeQueue must throw error for being empty!"
+        );
+        queue.enqueue_back(1).expect("Failed to enqueue!");
+        queue.enqueue_back(2).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(2),
+            "DeQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(1),
+            "DeQueue dequeue returned value is invalid!"
+        );
+        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
+        queue.enqueue_front(3).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(3),
+            "DeQueue rear index must wrap around!"
+        );
+        assert_eq!(queue.rear, 2, "DeQueue rear index is invalid!");
+        assert!(queue.is_empty(), "DeQueue must be empty!");
+    }
+
+    #[test]
+    fn test_wraparound() {
+        let mut queue: DeQueue<usize> = DeQueue::new(4);
+        let mut expected: VecDeque<usize> = VecDeque::new();
+        for i in 0..200 {
+            match i % 7 {
+                0 | 3 => {
+                    let result = queue.enqueue_front(i);
+                    assert_eq!(
+                        result.is_ok(),
+                        expected.len() < 4,
+                        "DeQueue enqueue_front is invalid!"
+                    );
+                    if result.is_ok() {
+                        expected.push_front(i);
+                    }
+                }
+                1 | 4 | 6 => {
+                    let result = queue.enqueue_back(i);
+                    assert_eq!(
+                        result.is_ok(),
+                        expected.len() < 4,
+                        "DeQueue enqueue_back is invalid!"
+                    );
+                    if result.is_ok() {
+                        expected.push_back(i);
+                    }
+                }
+                2 => assert_eq!(
+                    queue.dequeue_front().ok(),
+                    expected.pop_front(),
+                    "DeQueue front is invalid!"
+                ),
+                _ => assert_eq!(
+                    queue.dequeue_back().ok(),
+                    expected.pop_back(),
+                    "DeQueue back is invalid!"
+                ),
+            }
+            assert_eq!(queue.len(), expected.len(), "DeQueue length is invalid!");
+            assert_eq!(
+                queue.peek_front(),
+                expected.front(),
+                "DeQueue front is invalid!"
+            );
+            assert_eq!(
+                queue.peek_back(),
+                expected.back(),
+                "DeQueue back is invalid!"
+            );
+            assert!(
+                queue.iter().eq(expected.iter()),
+                "DeQueue order is invalid!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_drop_values() {
+        let mut queue: DeQueue<String> = DeQueue::new(3);
+        queue
+            .enqueue_front(String::from("b"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue_front(String::from("a"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue_back(String::from("c"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue_back().expect("Failed to dequeue!"),
+            "c",
+            "DeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_front().expect("Failed to dequeue!"),
+            "a",
+            "DeQueue front is invalid!"
+        );
+        queue
+            .enqueue_back(String::from("d"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.iter().collect::<Vec<_>>(),
+            vec!["b", "d"],
+            "DeQueue order is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut queue: DeQueue<u8> = DeQueue::new(5);
+        assert_eq!(queue.is_empty(), true, "DeQueue must be empty!");
+        queue.enqueue_front(5).expect("Failed to enqueue!");
+        assert_eq!(queue.is_empty(), false, "DeQueue must not be empty!");
+    }
+
+    #[test]
+    fn test_is_full() {
+        let mut queue: DeQueue<u8> = DeQueue::new(2);
+        queue.enqueue_front(5).expect("Error");
+        assert_eq!(queue.is_full(), false, "DeQueue must not be full!");
+        queue.enqueue_back(5).expect("Error");
+        assert_eq!(queue.is_full(), true, "DeQueue must be full!");
+    }
+
+    #[test]
+    fn test_empty_capacity() {
+        let mut queue: DeQueue<u8> = DeQueue::new(0);
+        assert_eq!(
+            queue.enqueue_front(1),
+            Err(QueueError::Full),
+            "DeQueue must throw error for being full!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Err(QueueError::Empty),
+            "DeQueue must throw error for being empty!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod input_restricted_dequeue {
+    use crate::structs::queues::{InputRestrictedDeQueue, QueueADT, QueueError};
+
+    #[test]
+    fn test_enqueue_and_dequeue() {
+        let mut queue: InputRestrictedDeQueue<u8> = InputRestrictedDeQueue::new(3);
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.enqueue(4),
+            Err(QueueError::Full),
+            "InputRestrictedDeQueue must be full!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(3),
+            "InputRestrictedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok(1),
+            "InputRestrictedDeQueue front is invalid!"
+        );
+        queue.enqueue(4).expect("Failed to enqueue!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![2, 4, 5],
+            "InputRestrictedDeQueue is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(5),
+            "InputRestrictedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(4),
+            "InputRestrictedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(2),
+            "InputRestrictedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Err(QueueError::Empty),
+            "InputRestrictedDeQueue must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_queue_operations() {
+        let mut queue: InputRestrictedDeQueue<u8> = InputRestrictedDeQueue::new(2);
+        assert_eq!(
+            queue.capacity(),
+            2,
+            "InputRestrictedDeQueue capacity is invalid!"
+        );
+        queue.enqueue(1).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.peek_front(),
+            Some(&1),
+            "InputRestrictedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.peek_back(),
+            Some(&1),
+            "InputRestrictedDeQueue back is invalid!"
+        );
+        assert_eq!(queue.len(), 1, "InputRestrictedDeQueue length is invalid!");
+        queue.clear();
+        assert!(queue.is_empty(), "InputRestrictedDeQueue must be empty!");
+    }
+}
+
+#[cfg(test)]
+mod output_restricted_dequeue {
+    use crate::structs::queues::{OutputRestrictedDeQueue, QueueADT, QueueError};
+
+    #[test]
+    fn test_enqueue_and_dequeue() {
+        let mut queue: OutputRestrictedDeQueue<u8> = OutputRestrictedDeQueue::new(3);
+        queue.enqueue_front(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        queue.enqueue_front(1).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.enqueue_front(0),
+            Err(QueueError::Full),
+            "OutputRestrictedDeQueue must be full!"
+        );
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3],
+            "OutputRestrictedDeQueue is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok(1),
+            "OutputRestrictedDeQueue front is invalid!"
+        );
+        queue.enqueue_front(0).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.dequeue(),
+            Ok(0),
+            "OutputRestrictedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok(2),
+            "OutputRestrictedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok(3),
+            "OutputRestrictedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Err(QueueError::Empty),
+            "OutputRestrictedDeQueue must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_queue_operations() {
+        let mut queue: OutputRestrictedDeQueue<String> = OutputRestrictedDeQueue::new(2);
+        queue
+            .enqueue(String::from("b"))
+            .expect("Failed to enqueue!");
+        queue
+            .enqueue_front(String::from("a"))
+            .expect("Failed to enqueue!");
+        assert!(queue.is_full(), "OutputRestrictedDeQueue must be full!");
+        assert_eq!(
+            queue.peek_back().map(String::as_str),
+            Some("b"),
+            "OutputRestrictedDeQueue back is invalid!"
+        );
+        queue.clear();
+        assert_eq!(queue.len(), 0, "OutputRestrictedDeQueue length is invalid!");
+    }
+}
+
+#[cfg(test)]
+mod binary_heap {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{heap_sort, BinaryHeap, HeapOrder, QueueError};
+    use rand::random;
+
+    fn drain(heap: &mut BinaryHeap<i32>) -> Vec<i32> {
+        let mut items = Vec::new();
+        while let Some(item) = heap.pop() {
+            items.push(item);
+        }
+        items
+    }
+
+    #[test]
     fn test_new() {
-        let queue: CircularQueue<u8> = CircularQueue::new(5);
-        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 0, "CircularQueue rear index is invalid!");
-        assert_eq!(queue.size, 5, "CircularQueue size is invalid!");
-        assert_eq!(queue.length, 0, "CircularQueue length is invalid!");
+        let heap: BinaryHeap<i32> = BinaryHeap::new(5, HeapOrder::Min);
+        assert_eq!(heap.capacity(), 5, "BinaryHeap capacity is invalid!");
+        assert_eq!(heap.order(), HeapOrder::Min, "BinaryHeap order is invalid!");
+        assert!(heap.is_empty(), "BinaryHeap must be empty!");
+        assert_eq!(heap.peek(), None, "Empty BinaryHeap must not peek!");
+    }
+
+    #[test]
+    fn test_min_heap() {
+        let mut heap: BinaryHeap<i32> = BinaryHeap::new(6, HeapOrder::Min);
+        for item in [5, 3, 8, 1, 9, 2] {
+            heap.push(item).expect("Failed to push!");
+            assert_eq!(
+                heap.peek(),
+                heap.iter().min(),
+                "BinaryHeap root must be the minimum!"
+            );
+        }
+        assert_eq!(
+            heap.push(0),
+            Err(QueueError::Full),
+            "BinaryHeap must be full!"
+        );
+        assert_eq!(
+            drain(&mut heap),
+            vec![1, 2, 3, 5, 8, 9],
+            "BinaryHeap must pop in ascending order!"
+        );
+        assert_eq!(heap.pop(), None, "Empty BinaryHeap must not pop!");
+    }
+
+    #[test]
+    fn test_max_heap() {
+        let mut heap: BinaryHeap<i32> = BinaryHeap::new(6, HeapOrder::Max);
+        for item in [5, 3, 8, 1, 9, 3] {
+            heap.push(item).expect("Failed to push!");
+        }
+        assert_eq!(
+            heap.peek(),
+            Some(&9),
+            "BinaryHeap root must be the maximum!"
+        );
+        assert_eq!(heap.len(), 6, "BinaryHeap length is invalid!");
+        assert_eq!(
+            drain(&mut heap),
+            vec![9, 8, 5, 3, 3, 1],
+            "BinaryHeap must pop in descending order!"
+        );
+    }
+
+    #[test]
+    fn test_from_array() {
+        let array: HeapArray<i32> = HeapArray::values(&[4, 10, 3, 5, 1, 7, 7, 2]);
+        let mut heap = BinaryHeap::from_array(array, HeapOrder::Max);
+        assert!(
+            heap.is_full(),
+            "BinaryHeap must keep the size of the Array!"
+        );
+        assert_eq!(
+            drain(&mut heap),
+            vec![10, 7, 7, 5, 4, 3, 2, 1],
+            "Heapified Array is invalid!"
+        );
+        heap.push(11).expect("Failed to push!");
+        assert_eq!(
+            heap.peek(),
+            Some(&11),
+            "BinaryHeap must be usable after heapify!"
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut heap: BinaryHeap<String> = BinaryHeap::new(3, HeapOrder::Min);
+        heap.push(String::from("b")).expect("Failed to push!");
+        heap.push(String::from("a")).expect("Failed to push!");
+        assert_eq!(
+            heap.pop().as_deref(),
+            Some("a"),
+            "BinaryHeap root is invalid!"
+        );
+        heap.clear();
+        assert!(heap.is_empty(), "BinaryHeap must be empty after clear!");
+    }
+
+    #[test]
+    fn test_random() {
+        let mut heap: BinaryHeap<i32> = BinaryHeap::new(500, HeapOrder::Min);
+        let mut expected: Vec<i32> = (0..500).map(|_| random::<i32>() % 100).collect();
+        for item in &expected {
+            heap.push(*item).expect("Failed to push!");
+        }
+        expected.sort();
+        assert_eq!(
+            drain(&mut heap),
+            expected,
+            "BinaryHeap must pop in sorted order!"
+        );
+    }
+
+    #[test]
+    fn test_heap_sort() {
+        let mut array: HeapArray<i32> = HeapArray::values(&[5, -1, 3, 3, 0, 12, -7]);
+        heap_sort(&mut array);
+        assert_eq!(
+            format!("{}", array),
+            "[-7, -1, 0, 3, 3, 5, 12]",
+            "Array must be sorted!"
+        );
+
+        let mut empty: HeapArray<i32> = HeapArray::new();
+        heap_sort(&mut empty);
+        assert_eq!(empty.get_len(), 0, "Empty Array must stay empty!");
+
+        let values: Vec<i32> = (0..300).map(|_| random::<i32>()).collect();
+        let mut array = HeapArray::values(&values);
+        heap_sort(&mut array);
+        let mut expected = values.clone();
+        expected.sort();
+        assert!(
+            array.iter().eq(expected.iter()),
+            "Random Array must be sorted!"
+        );
+    }
+}
+
+#[cfg(test)]
+mod dary_heap {
+    use crate::structs::arrays::HeapArray;
+    use crate::structs::queues::{DaryHeap, HeapOrder, QueueError};
+    use rand::random;
+
+    #[test]
+    #[should_panic(expected = "Heap arity must be at least 2!")]
+    fn test_new_panic() {
+        let _heap: DaryHeap<i32> = DaryHeap::new(1, 5, HeapOrder::Min);
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        for arity in 2..6 {
+            let mut heap: DaryHeap<i32> = DaryHeap::new(arity, 200, HeapOrder::Max);
+            let mut expected: Vec<i32> = (0..200).map(|_| random::<i32>() % 50).collect();
+            for item in &expected {
+                heap.push(*item).expect("Failed to push!");
+            }
+            assert_eq!(
+                heap.push(0),
+                Err(QueueError::Full),
+                "DaryHeap must be full!"
+            );
+            expected.sort_by(|a, b| b.cmp(a));
+            for item in expected {
+                assert_eq!(
+                    heap.pop(),
+                    Some(item),
+                    "DaryHeap must pop in descending order!"
+                );
+            }
+            assert!(heap.is_empty(), "DaryHeap must be empty!");
+        }
+    }
+
+    #[test]
+    fn test_from_array() {
+        let array: HeapArray<i32> = HeapArray::values(&[9, 4, 7, 1, 8, 2, 6, 3, 5]);
+        let mut heap = DaryHeap::from_array(3, array, HeapOrder::Min);
+        assert_eq!(heap.arity(), 3, "DaryHeap arity is invalid!");
+        assert_eq!(heap.order(), HeapOrder::Min, "DaryHeap order is invalid!");
+        assert_eq!(heap.len(), 9, "DaryHeap length is invalid!");
+        for item in 1..10 {
+            assert_eq!(heap.pop(), Some(item), "Heapified Array is invalid!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod indexed_priority_queue {
+    use crate::structs::queues::{HeapOrder, IndexedPriorityQueue, IndexedQueueError};
+
+    #[test]
+    fn test_insert_and_pop() {
+        let mut queue: IndexedPriorityQueue<u32> = IndexedPriorityQueue::new(5, HeapOrder::Min);
+        queue.insert(3, 30).expect("Failed to insert!");
+        queue.insert(0, 50).expect("Failed to insert!");
+        queue.insert(4, 10).expect("Failed to insert!");
+        assert_eq!(queue.len(), 3, "IndexedPriorityQueue length is invalid!");
+        assert!(
+            queue.contains(3),
+            "IndexedPriorityQueue must contain the index!"
+        );
+        assert!(
+            !queue.contains(1),
+            "IndexedPriorityQueue must not contain the index!"
+        );
+        assert_eq!(
+            queue.key_of(0),
+            Some(&50),
+            "IndexedPriorityQueue key is invalid!"
+        );
+        assert_eq!(
+            queue.peek(),
+            Some((4, &10)),
+            "IndexedPriorityQueue root is invalid!"
+        );
+        assert_eq!(
+            queue.pop(),
+            Some((4, 10)),
+            "IndexedPriorityQueue root is invalid!"
+        );
+        assert_eq!(
+            queue.pop(),
+            Some((3, 30)),
+            "IndexedPriorityQueue root is invalid!"
+        );
+        assert_eq!(
+            queue.pop(),
+            Some((0, 50)),
+            "IndexedPriorityQueue root is invalid!"
+        );
+        assert_eq!(
+            queue.pop(),
+            None,
+            "Empty IndexedPriorityQueue must not pop!"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut queue: IndexedPriorityQueue<u32> = IndexedPriorityQueue::new(2, HeapOrder::Min);
+        queue.insert(1, 5).expect("Failed to insert!");
+        assert_eq!(
+            queue.insert(2, 1),
+            Err(IndexedQueueError::IndexOutOfBounds(2)),
+            "Index must be checked!"
+        );
         assert_eq!(
-            queue.data,
-            HeapArray::new(),
-            "CircularQueue array is invalid!"
+            queue.insert(1, 1),
+            Err(IndexedQueueError::IndexInUse(1)),
+            "Index must be unique!"
+        );
+        assert_eq!(
+            queue.remove(0),
+            Err(IndexedQueueError::IndexNotFound(0)),
+            "Index must be in the Queue!"
+        );
+        assert_eq!(
+            queue.decrease_key(1, 7),
+            Err(IndexedQueueError::KeyNotDecreased),
+            "Key must decrease!"
+        );
+        assert_eq!(
+            queue.increase_key(1, 5),
+            Err(IndexedQueueError::KeyNotIncreased),
+            "Key must increase!"
+        );
+        assert_eq!(
+            format!("{}", IndexedQueueError::IndexNotFound(3)),
+            "Index 3 is not in the Queue!",
+            "IndexedQueueError message is invalid!"
         );
     }
 
     #[test]
-    fn test_enqueue() {
-        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 1, "CircularQueue length is invalid!");
-        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
+    fn test_change_keys() {
+        let mut queue: IndexedPriorityQueue<u32> = IndexedPriorityQueue::new(6, HeapOrder::Min);
+        for (index, key) in [(0, 40), (1, 20), (2, 60), (3, 10), (4, 50), (5, 30)] {
+            queue.insert(index, key).expect("Failed to insert!");
+        }
+        queue
+            .decrease_key(2, 5)
+            .expect("Failed to decrease the key!");
+        assert_eq!(queue.peek(), Some((2, &5)), "Decreased key must move up!");
+        queue
+            .increase_key(2, 45)
+            .expect("Failed to increase the key!");
         assert_eq!(
-            format!("{}", queue.data),
-            "[1]",
-            "CircularQueue is invalid!"
+            queue.peek(),
+            Some((3, &10)),
+            "Increased key must move down!"
         );
-        queue.enqueue(2).expect("Failed to enqueue!");
-        queue.enqueue(3).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 3, "CircularQueue length is invalid!");
-        assert_eq!(queue.front, 0, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 0, "CircularQueue rear index is invalid!");
+        queue.change_key(4, 1).expect("Failed to change the key!");
+        assert_eq!(queue.remove(5), Ok(30), "Removed key is invalid!");
+        let mut order = Vec::new();
+        while let Some((index, _key)) = queue.pop() {
+            order.push(index);
+        }
         assert_eq!(
-            format!("{}", queue.data),
-            "[1, 2, 3]",
-            "CircularQueue is invalid!"
+            order,
+            vec![4, 3, 1, 0, 2],
+            "IndexedPriorityQueue order is invalid!"
         );
-        assert!(
-            queue.enqueue(3).is_err(),
-            "CircularQueue must throw error for being full!"
+    }
+
+    #[test]
+    fn test_max_order() {
+        let mut queue: IndexedPriorityQueue<i32> = IndexedPriorityQueue::new(4, HeapOrder::Max);
+        for index in 0..4 {
+            queue
+                .insert(index, index as i32)
+                .expect("Failed to insert!");
+        }
+        queue
+            .decrease_key(3, -1)
+            .expect("Failed to decrease the key!");
+        queue
+            .increase_key(0, 10)
+            .expect("Failed to increase the key!");
+        assert_eq!(
+            queue.pop(),
+            Some((0, 10)),
+            "IndexedPriorityQueue root is invalid!"
         );
-        queue.dequeue().expect("Failed to dequeue!");
-        queue.enqueue(4).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 3, "CircularQueue length is invalid!");
-        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
         assert_eq!(
-            format!("{}", queue.data),
-            "[4, 2, 3]",
-            "CircularQueue is invalid!"
+            queue.pop(),
+            Some((2, 2)),
+            "IndexedPriorityQueue root is invalid!"
         );
-        assert!(
-            queue.enqueue(3).is_err(),
-            "CircularQueue must throw error for being full!"
+        assert_eq!(
+            queue.pop(),
+            Some((1, 1)),
+            "IndexedPriorityQueue root is invalid!"
+        );
+        assert_eq!(
+            queue.pop(),
+            Some((3, -1)),
+            "IndexedPriorityQueue root is invalid!"
         );
     }
+}
+
+#[cfg(test)]
+mod priority_queue {
+    use crate::structs::queues::{PriorityQueue, QueueError};
+    use std::cmp::Reverse;
 
     #[test]
-    fn test_dequeue() {
-        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
-        queue.enqueue(1).expect("Failed to enqueue!");
-        queue.enqueue(2).expect("Failed to enqueue!");
+    fn test_fifo_within_class() {
+        let mut queue: PriorityQueue<u8, &str> = PriorityQueue::new(6);
+        queue.enqueue(1, "low 1").expect("Failed to enqueue!");
+        queue.enqueue(3, "high 1").expect("Failed to enqueue!");
+        queue.enqueue(2, "medium 1").expect("Failed to enqueue!");
+        queue.enqueue(3, "high 2").expect("Failed to enqueue!");
+        queue.enqueue(1, "low 2").expect("Failed to enqueue!");
+        queue.enqueue(3, "high 3").expect("Failed to enqueue!");
         assert_eq!(
-            queue.dequeue().expect("Failed to dequeue!"),
-            1,
-            "CircularQueue dequeue returned value is invalid!"
+            queue.enqueue(3, "high 4"),
+            Err(QueueError::Full),
+            "PriorityQueue must be full!"
         );
-        assert_eq!(queue.length, 1, "CircularQueue length is invalid!");
-        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 2, "CircularQueue rear index is invalid!");
-        queue.enqueue(3).expect("Failed to enqueue!");
-        queue.enqueue(4).expect("Failed to enqueue!");
-        println!("{}", queue.data);
-        assert_eq!(queue.front, 1, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
         assert_eq!(
-            queue.dequeue().expect("Failed to dequeue!"),
-            2,
-            "CircularQueue dequeue returned value is invalid!"
+            queue.peek(),
+            Some((&3, &"high 1")),
+            "PriorityQueue front is invalid!"
         );
-        assert_eq!(queue.length, 2, "CircularQueue length is invalid!");
-        assert_eq!(queue.front, 2, "CircularQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "CircularQueue rear index is invalid!");
-        queue.enqueue(5).expect("Failed to enqueue!");
-        queue.dequeue().expect("Failed to dequeue!");
-        queue.dequeue().expect("Failed to dequeue!");
-        queue.dequeue().expect("Failed to dequeue!");
-        assert!(
-            queue.dequeue().is_err(),
-            "CircularQueue must throw error for being full!"
+        let mut items = Vec::new();
+        while let Ok(item) = queue.dequeue() {
+            items.push(item);
+        }
+        assert_eq!(
+            items,
+            vec!["high 1", "high 2", "high 3", "medium 1", "low 1", "low 2"],
+            "PriorityQueue order is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Err(QueueError::Empty),
+            "PriorityQueue must be empty!"
         );
     }
 
     #[test]
-    fn test_is_empty() {
-        let mut queue: CircularQueue<u8> = CircularQueue::new(5);
-        assert_eq!(queue.is_empty(), true, "CircularQueue must be empty!");
-        queue.enqueue(5);
-        assert_eq!(queue.is_empty(), false, "CircularQueue must not be empty!");
+    fn test_reverse_priority() {
+        let mut queue: PriorityQueue<Reverse<u8>, char> = PriorityQueue::new(4);
+        queue.enqueue(Reverse(2), 'a').expect("Failed to enqueue!");
+        queue.enqueue(Reverse(1), 'b').expect("Failed to enqueue!");
+        queue.enqueue(Reverse(2), 'c').expect("Failed to enqueue!");
+        assert_eq!(queue.len(), 3, "PriorityQueue length is invalid!");
+        assert_eq!(
+            queue.dequeue_with_priority(),
+            Ok((Reverse(1), 'b')),
+            "Lowest priority must come first!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Ok('a'),
+            "PriorityQueue must keep FIFO order!"
+        );
+        queue.clear();
+        assert!(queue.is_empty(), "PriorityQueue must be empty after clear!");
+        assert_eq!(queue.capacity(), 4, "PriorityQueue capacity is invalid!");
     }
+}
+
+#[cfg(test)]
+mod linked_queue {
+    use crate::structs::queues::{LinkedQueue, QueueADT, QueueError};
 
     #[test]
-    fn test_is_full() {
-        let mut queue: CircularQueue<u8> = CircularQueue::new(2);
-        queue.enqueue(5).expect("Error");
-        assert_eq!(queue.is_full(), false, "CircularQueue must not be full!");
-        queue.enqueue(5).expect("Error");
-        assert_eq!(queue.is_full(), true, "CircularQueue must be full!");
+    fn test_enqueue_and_dequeue() {
+        let mut queue: LinkedQueue<u8> = LinkedQueue::new();
+        assert_eq!(
+            queue.dequeue(),
+            Err(QueueError::Empty),
+            "LinkedQueue must be empty!"
+        );
+        queue.enqueue(1).expect("Failed to enqueue!");
+        queue.enqueue(2).expect("Failed to enqueue!");
+        queue.enqueue(3).expect("Failed to enqueue!");
+        assert_eq!(queue.len(), 3, "LinkedQueue length is invalid!");
+        assert_eq!(queue.dequeue(), Ok(1), "LinkedQueue must dequeue in order!");
+        assert_eq!(queue.dequeue(), Ok(2), "LinkedQueue must dequeue in order!");
+        queue.enqueue(4).expect("Failed to enqueue!");
+        assert_eq!(queue.dequeue(), Ok(3), "LinkedQueue must dequeue in order!");
+        assert_eq!(queue.dequeue(), Ok(4), "LinkedQueue must dequeue in order!");
+        assert!(queue.is_empty(), "LinkedQueue must be empty!");
+        queue.enqueue(5).expect("Failed to enqueue!");
+        assert_eq!(
+            queue.peek_back(),
+            Some(&5),
+            "LinkedQueue tail must be reset after it is drained!"
+        );
     }
 
     #[test]
-    fn test_display_trait() {
-        let mut queue: CircularQueue<u8> = CircularQueue::new(3);
-        queue.enqueue(1).expect("Error");
-        assert_eq!(format!("{}", queue), "[1][][]");
-        queue.enqueue(2).expect("Error");
-        println!("{}", queue.data);
-        assert_eq!(format!("{}", queue), "[1][2][]");
-        queue.enqueue(3).expect("Error");
-        assert_eq!(format!("{}", queue), "[1][2][3]");
-        queue.dequeue().expect("Error");
-        assert_eq!(format!("{}", queue), "[2][3][]");
-        queue.enqueue(4).expect("Error");
-        assert_eq!(format!("{}", queue), "[2][3][4]");
-        queue.dequeue().expect("Error");
-        assert_eq!(format!("{}", queue), "[3][4][]");
-        queue.dequeue().expect("Error");
-        assert_eq!(format!("{}", queue), "[4][][]");
-        queue.dequeue().expect("Error");
-        assert_eq!(format!("{}", queue), "[][][]");
+    fn test_peek_and_iter() {
+        let mut queue: LinkedQueue<u8> = LinkedQueue::new();
+        assert_eq!(queue.peek_front(), None, "Empty LinkedQueue must not peek!");
+        assert_eq!(queue.peek_back(), None, "Empty LinkedQueue must not peek!");
+        for item in 1..=4 {
+            queue.enqueue(item).expect("Failed to enqueue!");
+        }
+        assert_eq!(
+            queue.peek_front(),
+            Some(&1),
+            "LinkedQueue front is invalid!"
+        );
+        assert_eq!(queue.peek_back(), Some(&4), "LinkedQueue back is invalid!");
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4],
+            "LinkedQueue iterator is invalid!"
+        );
+        queue.clear();
+        assert_eq!(queue.len(), 0, "LinkedQueue must be empty after clear!");
+        assert_eq!(
+            queue.peek_back(),
+            None,
+            "Cleared LinkedQueue must not peek!"
+        );
     }
-}
-
-mod dequeue {
-    use crate::structs::arrays::HeapArray;
-    use crate::structs::queues::{DeQueue, DeQueueADT};
 
     #[test]
-    fn test_new() {
-        let queue: DeQueue<u8> = DeQueue::new(5);
-        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
-        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
-        assert_eq!(queue.size, 5, "DeQueue size is invalid!");
-        assert_eq!(queue.length, 0, "DeQueue length is invalid!");
-        assert_eq!(queue.data, HeapArray::new(), "DeQueue array is invalid!");
+    fn test_never_full() {
+        let mut queue: LinkedQueue<usize> = LinkedQueue::new();
+        // Walking the list on every enqueue would make this quadratic.
+        for item in 0..100_000 {
+            queue.enqueue(item).expect("Failed to enqueue!");
+            assert!(!queue.is_full(), "LinkedQueue must never be full!");
+        }
+        assert_eq!(
+            queue.capacity(),
+            usize::MAX,
+            "LinkedQueue capacity is invalid!"
+        );
+        for item in 0..100_000 {
+            assert_eq!(
+                queue.dequeue(),
+                Ok(item),
+                "LinkedQueue must dequeue in order!"
+            );
+        }
     }
+}
+
+#[cfg(test)]
+mod linked_dequeue {
+    use crate::structs::queues::{DeQueueADT, LinkedDeQueue, QueueADT, QueueError};
+    use std::collections::VecDeque;
 
     #[test]
-    fn test_enqueue_back() {
-        let mut queue: DeQueue<u8> = DeQueue::new(3);
-        queue.enqueue_back(1).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 1, "DeQueue length is invalid!");
-        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
-        assert_eq!(format!("{}", queue.data), "[1]", "DeQueue is invalid!");
+    fn test_both_ends() {
+        let mut queue: LinkedDeQueue<u8> = LinkedDeQueue::new();
         queue.enqueue_back(2).expect("Failed to enqueue!");
+        queue.enqueue_front(1).expect("Failed to enqueue!");
         queue.enqueue_back(3).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 3, "DeQueue length is invalid!");
-        assert_eq!(queue.front, 0, "DeQueue front index is invalid!");
-        assert_eq!(queue.rear, 0, "DeQueue rear index is invalid!");
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3],
+            "LinkedDeQueue order is invalid!"
+        );
+        assert_eq!(
+            queue.peek_front(),
+            Some(&1),
+            "LinkedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.peek_back(),
+            Some(&3),
+            "LinkedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(3),
+            "LinkedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_front(),
+            Ok(1),
+            "LinkedDeQueue front is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Ok(2),
+            "LinkedDeQueue back is invalid!"
+        );
+        assert_eq!(
+            queue.dequeue_back(),
+            Err(QueueError::Empty),
+            "LinkedDeQueue must be empty!"
+        );
+        assert_eq!(
+            queue.dequeue(),
+            Err(QueueError::Empty),
+            "LinkedDeQueue must be empty!"
+        );
+    }
+
+    #[test]
+    fn test_against_vecdeque() {
+        let mut queue: LinkedDeQueue<usize> = LinkedDeQueue::new();
+        let mut expected: VecDeque<usize> = VecDeque::new();
+        for i in 0..1000 {
+            match i % 5 {
+                0 | 1 => {
+                    queue.enqueue_back(i).expect("Failed to enqueue!");
+                    expected.push_back(i);
+                }
+                2 => {
+                    queue.enqueue_front(i).expect("Failed to enqueue!");
+                    expected.push_front(i);
+                }
+                3 => assert_eq!(
+                    queue.dequeue_front().ok(),
+                    expected.pop_front(),
+                    "LinkedDeQueue front is invalid!"
+                ),
+                _ => assert_eq!(
+                    queue.dequeue_back().ok(),
+                    expected.pop_back(),
+                    "LinkedDeQueue back is invalid!"
+                ),
+            }
+            assert_eq!(
+                queue.len(),
+                expected.len(),
+                "LinkedDeQueue length is invalid!"
+            );
+            assert!(!queue.is_full(), "LinkedDeQueue must never be full!");
+        }
         assert!(
-            queue.enqueue_back(3).is_err(),
-            "DeQueue must throw error for being full!"
+            queue.iter().eq(expected.iter()),
+            "LinkedDeQueue order is invalid!"
         );
-        queue.dequeue_front().expect("Failed to dequeue!");
-        queue.enqueue_back(4).expect("Failed to enqueue!");
-        assert_eq!(queue.length, 3, "DeQueue length is invalid!");
-        assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
-        assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
+        queue.clear();
+        assert!(queue.is_empty(), "LinkedDeQueue must be empty after clear!");
+    }
+}
+
+#[cfg(test)]
+mod queue_traits {
+    use crate::structs::queues::{
+        BasicQueue, CircularQueue, DeQueue, DeQueueADT, EphemeralQueue, InputRestrictedDeQueue,
+        LinkedDeQueue, LinkedQueue, OutputRestrictedDeQueue, QueueADT,
+    };
+    use std::fmt::{Debug, Display};
+
+    fn check_traits<Q>(name: &str)
+    where
+        Q: QueueADT<u8> + FromIterator<u8> + Extend<u8> + Clone + PartialEq + Debug + Display,
+    {
+        let mut queue: Q = (1..=3).collect();
+        assert_eq!(queue.len(), 3, "{} collected length is invalid!", name);
         assert_eq!(
-            format!("{}", queue.data),
-            "[4, 2, 3]",
-            "DeQueue is invalid!"
+            format!("{:?}", queue),
+            "[1, 2, 3]",
+            "{} Debug is invalid!",
+            name
         );
         assert!(
-            queue.enqueue_back(3).is_err(),
-            "DeQueue must throw error for being full!"
+            format!("{}", queue).starts_with("[1][2][3]"),
+            "{} Display is invalid!",
+            name
+        );
+
+        let clone = queue.clone();
+        assert!(clone == queue, "{} clone must be equal!", name);
+        queue.dequeue().expect("Failed to dequeue!");
+        assert!(clone != queue, "{} clone must be independent!", name);
+        assert_eq!(clone.len(), 3, "{} clone length is invalid!", name);
+
+        let drained: Vec<u8> = queue.drain().collect();
+        assert_eq!(drained, vec![2, 3], "{} drain is invalid!", name);
+        assert!(queue.is_empty(), "{} must be empty after drain!", name);
+    }
+
+    #[test]
+    fn test_all_queues() {
+        check_traits::<BasicQueue<u8>>("BasicQueue");
+        check_traits::<EphemeralQueue<u8>>("EphemeralQueue");
+        check_traits::<CircularQueue<u8>>("CircularQueue");
+        check_traits::<DeQueue<u8>>("DeQueue");
+        check_traits::<InputRestrictedDeQueue<u8>>("InputRestrictedDeQueue");
+        check_traits::<OutputRestrictedDeQueue<u8>>("OutputRestrictedDeQueue");
+        check_traits::<LinkedQueue<u8>>("LinkedQueue");
+        check_traits::<LinkedDeQueue<u8>>("LinkedDeQueue");
+    }
+
+    #[test]
+    fn test_iter_wraparound() {
+        let mut queue: CircularQueue<u8> = CircularQueue::new(4);
+        queue.extend([1, 2, 3, 4]);
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.dequeue().expect("Failed to dequeue!");
+        queue.extend([5, 6]);
+        assert_eq!(
+            queue.iter().copied().collect::<Vec<_>>(),
+            vec![3, 4, 5, 6],
+            "Iterator must wrap around!"
+        );
+        assert_eq!(
+            format!("{}", queue),
+            "[3][4][5][6]",
+            "CircularQueue Display is invalid!"
+        );
+
+        let mut deque: DeQueue<u8> = DeQueue::new(4);
+        deque.enqueue_front(2).expect("Failed to enqueue!");
+        deque.enqueue_front(1).expect("Failed to enqueue!");
+        deque.enqueue_back(3).expect("Failed to enqueue!");
+        assert_eq!(
+            deque.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3],
+            "Iterator must wrap around!"
+        );
+        assert_eq!(
+            format!("{}", deque),
+            "[1][2][3][]",
+            "DeQueue Display is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_equality_ignores_layout() {
+        let mut wrapped: CircularQueue<u8> = CircularQueue::new(3);
+        wrapped.extend([0, 0, 1]);
+        wrapped.dequeue().expect("Failed to dequeue!");
+        wrapped.dequeue().expect("Failed to dequeue!");
+        wrapped.extend([2, 3]);
+        let mut straight: CircularQueue<u8> = CircularQueue::new(5);
+        straight.extend([1, 2, 3]);
+        assert_eq!(
+            wrapped, straight,
+            "Queues with the same items must be equal!"
+        );
+        straight.enqueue(4).expect("Failed to enqueue!");
+        assert_ne!(
+            wrapped, straight,
+            "Queues with different items must not be equal!"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(3);
+        assert_eq!(
+            format!("{}", queue),
+            "[][][]",
+            "Empty BasicQueue Display is invalid!"
+        );
+        queue.enqueue(7).expect("Failed to enqueue!");
+        assert_eq!(
+            format!("{}", queue),
+            "[7][][]",
+            "BasicQueue Display is invalid!"
         );
+        let linked: LinkedQueue<u8> = [4, 5].into_iter().collect();
+        assert_eq!(
+            format!("{}", linked),
+            "[4][5]",
+            "LinkedQueue Display is invalid!"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Queue is full!")]
+    fn test_extend_panic() {
+        let mut queue: BasicQueue<u8> = BasicQueue::new(2);
+        queue.extend([1, 2, 3]);
     }
 
-    // #[test]
-    // fn test_dequeue() {
-    //     let mut queue: DeQueue<u8> = DeQueue::new(3);
-    //     queue.enqueue(1).expect("Failed to enqueue!");
-    //     queue.enqueue(2).expect("Failed to enqueue!");
-    //     assert_eq!(queue.dequeue().expect("Failed to dequeue!"), 1, "DeQueue dequeue returned value is invalid!");
-    //     assert_eq!(queue.length, 1, "DeQueue length is invalid!");
-    //     assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
-    //     assert_eq!(queue.rear, 2, "DeQueue rear index is invalid!");
-    //     queue.enqueue(3).expect("Failed to enqueue!");
-    //     queue.enqueue(4).expect("Failed to enqueue!");
-    //     println!("{}", queue.data);
-    //     assert_eq!(queue.front, 1, "DeQueue front index is invalid!");
-    //     assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
-    //     assert_eq!(queue.dequeue().expect("Failed to dequeue!"), 2, "DeQueue dequeue returned value is invalid!");
-    //     assert_eq!(queue.length, 2, "DeQueue length is invalid!");
-    //     assert_eq!(queue.front, 2, "DeQueue front index is invalid!");
-    //     assert_eq!(queue.rear, 1, "DeQueue rear index is invalid!");
-    //     queue.enqueue(5).expect("Failed to enqueue!");
-    //     queue.dequeue().expect("Failed to dequeue!");
-    //     queue.dequeue().expect("Failed to dequeue!");
-    //     queue.dequeue().expect("Failed to dequeue!");
-    //     assert!(queue.dequeue().is_err(), "DeQueue must throw error for being full!");
-    // }
-    //
-    // #[test]
-    // fn test_is_empty() {
-    //     let mut queue: DeQueue<u8> = DeQueue::new(5);
-    //     assert_eq!(queue.is_empty(), true, "DeQueue must be empty!");
-    //     queue.enqueue(5);
-    //     assert_eq!(queue.is_empty(), false, "DeQueue must not be empty!");
-    // }
-    //
-    // #[test]
-    // fn test_is_full() {
-    //     let mut queue: DeQueue<u8> = DeQueue::new(2);
-    //     queue.enqueue(5).expect("Error");
-    //     assert_eq!(queue.is_full(), false, "DeQueue must not be full!");
-    //     queue.enqueue(5).expect("Error");
-    //     assert_eq!(queue.is_full(), true, "DeQueue must be full!");
-    // }
+    #[test]
+    fn test_drain() {
+        let mut queue: CircularQueue<String> =
+            ["a", "b", "c"].into_iter().map(String::from).collect();
+        let mut drain = queue.drain();
+        assert_eq!(
+            drain.size_hint(),
+            (3, Some(3)),
+            "Drain size hint is invalid!"
+        );
+        assert_eq!(
+            drain.next().as_deref(),
+            Some("a"),
+            "Drain must dequeue from the front!"
+        );
+        drop(drain);
+        assert!(
+            queue.is_empty(),
+            "Dropped Drain must clear the remaining items!"
+        );
+        queue
+            .enqueue(String::from("d"))
+            .expect("Failed to enqueue!");
+        assert_eq!(
+            queue.drain().collect::<Vec<_>>(),
+            vec!["d"],
+            "Queue must be usable after drain!"
+        );
+    }
 }
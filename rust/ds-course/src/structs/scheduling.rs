@@ -0,0 +1,678 @@
+/*
+CPU Scheduling Simulator
+Discrete event simulation of a single CPU which runs a list of jobs with arrival times and CPU bursts. The clock jumps
+from one event to the next, i.e. an arrival, the end of a time slice or the completion of a job.
+- First Come First Served runs the jobs in their arrival order from a Circular Queue.
+- Shortest Job First runs the job with the shortest burst next from a min Binary Heap. It doesn't preempt.
+- Round Robin runs every job for at most one quantum and then moves it to the back of a Circular Queue.
+- Priority runs the job with the lowest priority number next from a min Binary Heap. It doesn't preempt.
+- Multilevel Feedback Queue keeps a Circular Queue for every level. New jobs start at the top level and a job which
+  uses its whole quantum moves a level down. A job only runs while the levels above it are empty, and it gives the CPU
+  back as soon as a new job arrives at the top level.
+Jobs which arrive at the same time are ordered by their position in the job list.
+*/
+#![allow(dead_code)]
+use crate::structs::queues::{BinaryHeap, CircularQueue, HeapOrder, QueueADT};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Job {
+    pub id: usize,
+    pub arrival: u32,
+    pub burst: u32,
+    // Lower numbers run first.
+    pub priority: u32,
+}
+
+impl Job {
+    pub fn new(id: usize, arrival: u32, burst: u32) -> Self {
+        Self::with_priority(id, arrival, burst, 0)
+    }
+
+    pub fn with_priority(id: usize, arrival: u32, burst: u32, priority: u32) -> Self {
+        Self {
+            id,
+            arrival,
+            burst,
+            priority,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Policy {
+    FirstComeFirstServed,
+    ShortestJobFirst,
+    RoundRobin(u32),
+    Priority,
+    // Quantum of every level from the top to the bottom. The bottom level is Round Robin with its quantum.
+    MultilevelFeedback(Vec<u32>),
+}
+
+// Part of the timeline where the CPU runs a job, or idles if there is no job.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slice {
+    pub job: Option<usize>,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JobStats {
+    pub id: usize,
+    pub arrival: u32,
+    pub burst: u32,
+    pub completion: u32,
+    // Time from the arrival to the completion.
+    pub turnaround: u32,
+    // Time spent in the ready queue.
+    pub waiting: u32,
+    // Time from the arrival to the first run.
+    pub response: u32,
+}
+
+pub struct Schedule {
+    slices: Vec<Slice>,
+    stats: Vec<JobStats>,
+}
+
+impl Schedule {
+    pub fn slices(&self) -> &[Slice] {
+        &self.slices
+    }
+
+    // Statistics in the order of the job list.
+    pub fn stats(&self) -> &[JobStats] {
+        &self.stats
+    }
+
+    pub fn stats_of(&self, id: usize) -> Option<&JobStats> {
+        self.stats.iter().find(|stats| stats.id == id)
+    }
+
+    fn average(&self, value: impl Fn(&JobStats) -> u32) -> f64 {
+        if self.stats.is_empty() {
+            return 0.0;
+        }
+        let total: u64 = self.stats.iter().map(|stats| value(stats) as u64).sum();
+        total as f64 / self.stats.len() as f64
+    }
+
+    pub fn average_waiting(&self) -> f64 {
+        self.average(|stats| stats.waiting)
+    }
+
+    pub fn average_turnaround(&self) -> f64 {
+        self.average(|stats| stats.turnaround)
+    }
+
+    pub fn average_response(&self) -> f64 {
+        self.average(|stats| stats.response)
+    }
+
+    // Time when the last job completes.
+    pub fn makespan(&self) -> u32 {
+        self.slices.last().map_or(0, |slice| slice.end)
+    }
+
+    /*
+    Gantt chart with the jobs on the first line and the times when they start on the second one, e.g.
+    | P1 | idle | P2 |
+    0    3      5    7
+    */
+    pub fn gantt(&self) -> String {
+        let mut jobs = String::from("|");
+        let mut times = String::new();
+        for slice in &self.slices {
+            let label = match slice.job {
+                Some(id) => format!("P{}", id),
+                None => String::from("idle"),
+            };
+            let start = slice.start.to_string();
+            let width = (label.len() + 2).max(start.len());
+            jobs.push_str(&format!("{:^width$}|", label, width = width));
+            times.push_str(&format!("{:<width$}", start, width = width + 1));
+        }
+        times.push_str(&self.makespan().to_string());
+        format!("{}\n{}", jobs, times)
+    }
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.gantt())?;
+        for stats in &self.stats {
+            writeln!(
+                f,
+                "P{}: arrival {}, burst {}, completion {}, waiting {}, turnaround {}, response {}",
+                stats.id,
+                stats.arrival,
+                stats.burst,
+                stats.completion,
+                stats.waiting,
+                stats.turnaround,
+                stats.response
+            )?;
+        }
+        write!(
+            f,
+            "Average waiting {:.2}, average turnaround {:.2}, average response {:.2}",
+            self.average_waiting(),
+            self.average_turnaround(),
+            self.average_response()
+        )
+    }
+}
+
+// State of the CPU while the jobs run. The jobs are referred to by their position in the job list.
+struct Simulation<'a> {
+    jobs: &'a [Job],
+    // Positions of the jobs sorted by their arrival.
+    arrivals: Vec<usize>,
+    arrived: usize,
+    clock: u32,
+    remaining: Vec<u32>,
+    first_start: Vec<Option<u32>>,
+    completion: Vec<u32>,
+    finished: usize,
+    slices: Vec<Slice>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(jobs: &'a [Job]) -> Self {
+        if jobs.iter().any(|job| job.burst == 0) {
+            panic!("Job burst must be at least 1!");
+        }
+        let mut arrivals: Vec<usize> = (0..jobs.len()).collect();
+        arrivals.sort_by_key(|&job| jobs[job].arrival);
+        Self {
+            jobs,
+            arrivals,
+            arrived: 0,
+            clock: 0,
+            remaining: jobs.iter().map(|job| job.burst).collect(),
+            first_start: vec![None; jobs.len()],
+            completion: vec![0; jobs.len()],
+            finished: 0,
+            slices: Vec::new(),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.finished == self.jobs.len()
+    }
+
+    // Hands every job which arrived until now to the ready queue.
+    fn admit(&mut self, mut ready: impl FnMut(usize)) {
+        while let Some(&job) = self.arrivals.get(self.arrived) {
+            if self.jobs[job].arrival > self.clock {
+                return;
+            }
+            ready(job);
+            self.arrived += 1;
+        }
+    }
+
+    fn next_arrival(&self) -> Option<u32> {
+        self.arrivals
+            .get(self.arrived)
+            .map(|&job| self.jobs[job].arrival)
+    }
+
+    // Moves the clock to the next arrival when no job is ready.
+    fn idle(&mut self) {
+        let next = self
+            .next_arrival()
+            .expect("Simulation has no job left to wait for!");
+        self.push_slice(None, next);
+    }
+
+    // Runs the job for the duration, which must not be longer than its remaining burst.
+    fn run(&mut self, job: usize, duration: u32) {
+        self.first_start[job].get_or_insert(self.clock);
+        self.remaining[job] -= duration;
+        self.push_slice(Some(self.jobs[job].id), self.clock + duration);
+        if self.remaining[job] == 0 {
+            self.completion[job] = self.clock;
+            self.finished += 1;
+        }
+    }
+
+    // Extends the last slice if the same job keeps running.
+    fn push_slice(&mut self, job: Option<usize>, end: u32) {
+        match self.slices.last_mut() {
+            Some(last) if last.job == job && last.end == self.clock => last.end = end,
+            _ => self.slices.push(Slice {
+                job,
+                start: self.clock,
+                end,
+            }),
+        }
+        self.clock = end;
+    }
+
+    fn finish(self) -> Schedule {
+        let stats = self
+            .jobs
+            .iter()
+            .enumerate()
+            .map(|(index, job)| {
+                let completion = self.completion[index];
+                let turnaround = completion - job.arrival;
+                JobStats {
+                    id: job.id,
+                    arrival: job.arrival,
+                    burst: job.burst,
+                    completion,
+                    turnaround,
+                    waiting: turnaround - job.burst,
+                    response: self.first_start[index].unwrap() - job.arrival,
+                }
+            })
+            .collect();
+        Schedule {
+            slices: self.slices,
+            stats,
+        }
+    }
+}
+
+// Every job is at most once in a ready queue, so a queue as large as the job list never becomes full.
+fn enqueue(queue: &mut CircularQueue<usize>, job: usize) {
+    if queue.enqueue(job).is_err() {
+        unreachable!("Ready queue must have space for every job!");
+    }
+}
+
+pub fn simulate(jobs: &[Job], policy: &Policy) -> Schedule {
+    let simulation = Simulation::new(jobs);
+    match policy {
+        Policy::FirstComeFirstServed => first_come_first_served(simulation),
+        Policy::ShortestJobFirst => shortest_job_first(simulation),
+        Policy::RoundRobin(quantum) => round_robin(simulation, *quantum),
+        Policy::Priority => priority(simulation),
+        Policy::MultilevelFeedback(quanta) => multilevel_feedback(simulation, quanta),
+    }
+}
+
+fn first_come_first_served(mut simulation: Simulation) -> Schedule {
+    let mut ready: CircularQueue<usize> = CircularQueue::new(simulation.jobs.len());
+    while !simulation.is_done() {
+        simulation.admit(|job| enqueue(&mut ready, job));
+        match ready.dequeue() {
+            Ok(job) => simulation.run(job, simulation.remaining[job]),
+            Err(_) => simulation.idle(),
+        }
+    }
+    simulation.finish()
+}
+
+// The heap holds the key of the job, the arrival and the position, so equal keys run in their arrival order.
+fn non_preemptive(mut simulation: Simulation, key: impl Fn(&Job) -> u32) -> Schedule {
+    let jobs = simulation.jobs;
+    let mut ready: BinaryHeap<(u32, u32, usize)> = BinaryHeap::new(jobs.len(), HeapOrder::Min);
+    while !simulation.is_done() {
+        simulation.admit(|job| {
+            if ready
+                .push((key(&jobs[job]), jobs[job].arrival, job))
+                .is_err()
+            {
+                unreachable!("Ready heap must have space for every job!");
+            }
+        });
+        match ready.pop() {
+            Some((_, _, job)) => simulation.run(job, simulation.remaining[job]),
+            None => simulation.idle(),
+        }
+    }
+    simulation.finish()
+}
+
+fn shortest_job_first(simulation: Simulation) -> Schedule {
+    non_preemptive(simulation, |job| job.burst)
+}
+
+fn priority(simulation: Simulation) -> Schedule {
+    non_preemptive(simulation, |job| job.priority)
+}
+
+fn check_quantum(quantum: u32) {
+    if quantum == 0 {
+        panic!("Time quantum must be at least 1!");
+    }
+}
+
+fn round_robin(mut simulation: Simulation, quantum: u32) -> Schedule {
+    check_quantum(quantum);
+    let mut ready: CircularQueue<usize> = CircularQueue::new(simulation.jobs.len());
+    while !simulation.is_done() {
+        simulation.admit(|job| enqueue(&mut ready, job));
+        match ready.dequeue() {
+            Ok(job) => {
+                simulation.run(job, quantum.min(simulation.remaining[job]));
+                // The jobs which arrived during the slice get in line before the preempted job.
+                simulation.admit(|job| enqueue(&mut ready, job));
+                if simulation.remaining[job] > 0 {
+                    enqueue(&mut ready, job);
+                }
+            }
+            Err(_) => simulation.idle(),
+        }
+    }
+    simulation.finish()
+}
+
+fn multilevel_feedback(mut simulation: Simulation, quanta: &[u32]) -> Schedule {
+    if quanta.is_empty() {
+        panic!("Multilevel feedback queue needs at least one level!");
+    }
+    quanta.iter().for_each(|&quantum| check_quantum(quantum));
+    let bottom = quanta.len() - 1;
+    let mut levels: Vec<CircularQueue<usize>> = quanta
+        .iter()
+        .map(|_| CircularQueue::new(simulation.jobs.len()))
+        .collect();
+    while !simulation.is_done() {
+        simulation.admit(|job| enqueue(&mut levels[0], job));
+        let Some(level) = levels.iter().position(|queue| !queue.is_empty()) else {
+            simulation.idle();
+            continue;
+        };
+        let job = levels[level].dequeue().unwrap();
+        let mut duration = quanta[level].min(simulation.remaining[job]);
+        // A new job starts at the top level, so it takes the CPU from a job at a lower level.
+        if let Some(next) = simulation.next_arrival().filter(|_| level > 0) {
+            duration = duration.min(next - simulation.clock);
+        }
+        simulation.run(job, duration);
+        simulation.admit(|job| enqueue(&mut levels[0], job));
+        if simulation.remaining[job] > 0 {
+            // Only a job which used its whole quantum moves down, a preempted job stays at its level.
+            let next_level = match duration == quanta[level] {
+                true => (level + 1).min(bottom),
+                false => level,
+            };
+            enqueue(&mut levels[next_level], job);
+        }
+    }
+    simulation.finish()
+}
+
+#[cfg(test)]
+mod simulator {
+    use crate::structs::scheduling::{simulate, Job, Policy, Slice};
+
+    fn slice(job: Option<usize>, start: u32, end: u32) -> Slice {
+        Slice { job, start, end }
+    }
+
+    fn waiting(jobs: &[Job], policy: Policy) -> Vec<u32> {
+        simulate(jobs, &policy)
+            .stats()
+            .iter()
+            .map(|stats| stats.waiting)
+            .collect()
+    }
+
+    // Three jobs which arrive together, with the long one first.
+    fn convoy() -> Vec<Job> {
+        vec![Job::new(1, 0, 24), Job::new(2, 0, 3), Job::new(3, 0, 3)]
+    }
+
+    #[test]
+    fn test_first_come_first_served() {
+        let schedule = simulate(&convoy(), &Policy::FirstComeFirstServed);
+        assert_eq!(
+            schedule.slices(),
+            &[
+                slice(Some(1), 0, 24),
+                slice(Some(2), 24, 27),
+                slice(Some(3), 27, 30)
+            ],
+            "FCFS timeline is invalid!"
+        );
+        assert_eq!(
+            waiting(&convoy(), Policy::FirstComeFirstServed),
+            vec![0, 24, 27],
+            "FCFS waiting is invalid!"
+        );
+        assert_eq!(
+            schedule.average_waiting(),
+            17.0,
+            "FCFS average waiting is invalid!"
+        );
+        assert_eq!(
+            schedule.average_turnaround(),
+            27.0,
+            "FCFS average turnaround is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_shortest_job_first() {
+        let schedule = simulate(&convoy(), &Policy::ShortestJobFirst);
+        assert_eq!(
+            schedule.average_waiting(),
+            3.0,
+            "SJF average waiting is invalid!"
+        );
+
+        // The long job already runs when the short ones arrive, because SJF doesn't preempt.
+        let jobs = [
+            Job::new(1, 0, 8),
+            Job::new(2, 1, 4),
+            Job::new(3, 2, 9),
+            Job::new(4, 3, 5),
+        ];
+        let schedule = simulate(&jobs, &Policy::ShortestJobFirst);
+        let order: Vec<Option<usize>> = schedule.slices().iter().map(|slice| slice.job).collect();
+        assert_eq!(
+            order,
+            vec![Some(1), Some(2), Some(4), Some(3)],
+            "SJF order is invalid!"
+        );
+        assert_eq!(
+            schedule.average_waiting(),
+            7.75,
+            "SJF average waiting is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let schedule = simulate(&convoy(), &Policy::RoundRobin(4));
+        assert_eq!(
+            schedule.slices(),
+            &[
+                slice(Some(1), 0, 4),
+                slice(Some(2), 4, 7),
+                slice(Some(3), 7, 10),
+                slice(Some(1), 10, 30)
+            ],
+            "Round Robin timeline is invalid!"
+        );
+        assert_eq!(
+            waiting(&convoy(), Policy::RoundRobin(4)),
+            vec![6, 4, 7],
+            "Round Robin waiting is invalid!"
+        );
+        let response: Vec<u32> = schedule
+            .stats()
+            .iter()
+            .map(|stats| stats.response)
+            .collect();
+        assert_eq!(response, vec![0, 4, 7], "Round Robin response is invalid!");
+    }
+
+    #[test]
+    fn test_round_robin_arrivals() {
+        // Job 3 arrives while job 1 runs, so it gets in line before job 1 is preempted.
+        let jobs = [Job::new(1, 0, 5), Job::new(2, 0, 3), Job::new(3, 1, 2)];
+        let schedule = simulate(&jobs, &Policy::RoundRobin(2));
+        let order: Vec<Option<usize>> = schedule.slices().iter().map(|slice| slice.job).collect();
+        assert_eq!(
+            order,
+            vec![Some(1), Some(2), Some(3), Some(1), Some(2), Some(1)],
+            "Round Robin order is invalid!"
+        );
+        assert_eq!(schedule.makespan(), 10, "Round Robin makespan is invalid!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Time quantum must be at least 1!")]
+    fn test_round_robin_panic() {
+        simulate(&convoy(), &Policy::RoundRobin(0));
+    }
+
+    #[test]
+    fn test_priority() {
+        let jobs = [
+            Job::with_priority(1, 0, 10, 3),
+            Job::with_priority(2, 0, 1, 1),
+            Job::with_priority(3, 0, 2, 4),
+            Job::with_priority(4, 0, 1, 5),
+            Job::with_priority(5, 0, 5, 2),
+        ];
+        let schedule = simulate(&jobs, &Policy::Priority);
+        let order: Vec<Option<usize>> = schedule.slices().iter().map(|slice| slice.job).collect();
+        assert_eq!(
+            order,
+            vec![Some(2), Some(5), Some(1), Some(3), Some(4)],
+            "Priority order is invalid!"
+        );
+        assert_eq!(
+            waiting(&jobs, Policy::Priority),
+            vec![6, 0, 16, 18, 1],
+            "Priority waiting is invalid!"
+        );
+        assert_eq!(
+            schedule.average_waiting(),
+            8.2,
+            "Priority average waiting is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_multilevel_feedback() {
+        // Job 1 uses its whole quantum at the top level and finishes at the second level after job 2.
+        let jobs = [Job::new(1, 0, 5), Job::new(2, 1, 2)];
+        let schedule = simulate(&jobs, &Policy::MultilevelFeedback(vec![2, 4]));
+        assert_eq!(
+            schedule.slices(),
+            &[
+                slice(Some(1), 0, 2),
+                slice(Some(2), 2, 4),
+                slice(Some(1), 4, 7)
+            ],
+            "MLFQ timeline is invalid!"
+        );
+
+        // Job 2 arrives at the top level and takes the CPU from job 1 at the second level.
+        let jobs = [Job::new(1, 0, 5), Job::new(2, 3, 1)];
+        let schedule = simulate(&jobs, &Policy::MultilevelFeedback(vec![1, 10]));
+        assert_eq!(
+            schedule.slices(),
+            &[
+                slice(Some(1), 0, 3),
+                slice(Some(2), 3, 4),
+                slice(Some(1), 4, 6)
+            ],
+            "MLFQ must preempt lower levels!"
+        );
+        assert_eq!(
+            schedule.stats_of(1).map(|stats| stats.completion),
+            Some(6),
+            "MLFQ completion is invalid!"
+        );
+        assert_eq!(
+            schedule.stats_of(2).map(|stats| stats.waiting),
+            Some(0),
+            "MLFQ waiting is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_multilevel_feedback_bottom_level() {
+        // The bottom level is Round Robin, so long jobs keep taking turns there.
+        let jobs = [Job::new(1, 0, 6), Job::new(2, 0, 6)];
+        let schedule = simulate(&jobs, &Policy::MultilevelFeedback(vec![1, 2]));
+        let order: Vec<Option<usize>> = schedule.slices().iter().map(|slice| slice.job).collect();
+        assert_eq!(
+            order,
+            vec![
+                Some(1),
+                Some(2),
+                Some(1),
+                Some(2),
+                Some(1),
+                Some(2),
+                Some(1),
+                Some(2)
+            ],
+            "MLFQ order is invalid!"
+        );
+        assert_eq!(schedule.makespan(), 12, "MLFQ makespan is invalid!");
+    }
+
+    #[test]
+    fn test_idle_and_gantt() {
+        let jobs = [Job::new(1, 2, 3), Job::new(2, 10, 1)];
+        let schedule = simulate(&jobs, &Policy::FirstComeFirstServed);
+        assert_eq!(
+            schedule.slices(),
+            &[
+                slice(None, 0, 2),
+                slice(Some(1), 2, 5),
+                slice(None, 5, 10),
+                slice(Some(2), 10, 11)
+            ],
+            "Idle time must be part of the timeline!"
+        );
+        assert_eq!(
+            schedule.gantt(),
+            "| idle | P1 | idle | P2 |\n0      2    5      10   11",
+            "Gantt chart is invalid!"
+        );
+        assert_eq!(
+            schedule.average_waiting(),
+            0.0,
+            "Jobs must not wait on an idle CPU!"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let jobs = [Job::new(1, 0, 3), Job::new(2, 1, 2)];
+        let schedule = simulate(&jobs, &Policy::FirstComeFirstServed);
+        assert_eq!(
+            format!("{}", schedule),
+            "| P1 | P2 |\n0    3    5\n\
+             P1: arrival 0, burst 3, completion 3, waiting 0, turnaround 3, response 0\n\
+             P2: arrival 1, burst 2, completion 5, waiting 2, turnaround 4, response 2\n\
+             Average waiting 1.00, average turnaround 3.50, average response 1.00",
+            "Schedule Display is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_empty_jobs() {
+        let schedule = simulate(&[], &Policy::RoundRobin(3));
+        assert!(
+            schedule.slices().is_empty(),
+            "Empty job list must have no timeline!"
+        );
+        assert_eq!(
+            schedule.average_waiting(),
+            0.0,
+            "Empty job list must have no waiting!"
+        );
+        assert_eq!(schedule.gantt(), "|\n0", "Empty Gantt chart is invalid!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Job burst must be at least 1!")]
+    fn test_zero_burst_panic() {
+        simulate(&[Job::new(1, 0, 0)], &Policy::FirstComeFirstServed);
+    }
+}
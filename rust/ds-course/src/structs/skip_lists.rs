@@ -0,0 +1,676 @@
+/*
+Skip List
+An ordered map made of a sorted Linked List with extra express lanes on top of it. Every node is promoted to the
+next level with a fixed probability, so the search skips over most of the nodes and takes O(log n) on average.
+
+Every link also stores its width, i.e. how many nodes it jumps over on the bottom level. The widths make it possible
+to find the rank of a key and to select the key at a given rank in O(log n) as well.
+
+The nodes are allocated with HeapBox and every node keeps its links to the next nodes in a HeapArray with one entry
+per level. The head is not a real node, the links of the head are kept in the list itself.
+*/
+#![allow(dead_code)]
+use crate::structs::arrays::HeapArray;
+use crate::structs::smart_ptrs::HeapBox;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::ptr::NonNull;
+use std::{fmt, mem, ptr};
+
+const DEFAULT_MAX_LEVEL: usize = 16;
+const DEFAULT_PROBABILITY: f64 = 0.5;
+
+// A null node pointer marks the end of a level.
+struct Link<K, V> {
+    node: *mut SkipNode<K, V>,
+    width: usize,
+}
+
+impl<K, V> Clone for Link<K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V> Copy for Link<K, V> {}
+
+/*
+NOTE: linked_lists::Node can't be reused here since it has a single next link, while a skip node needs one forward link
+per level. The same node is also reached from every level it was promoted to, so its links can't own the next node
+the way the HeapBox of NodeType::Singly does, and a reference counted NodeType::Doubly would count every level.
+*/
+struct SkipNode<K, V> {
+    key: K,
+    value: V,
+    forward: HeapArray<Link<K, V>>,
+}
+
+pub struct SkipList<K, V> {
+    head: HeapArray<Link<K, V>>,
+    length: usize,
+    max_level: usize,
+    probability: f64,
+    rng: StdRng,
+}
+
+// The list owns all of its nodes and they are never shared, so it is safe to move it to or share it with
+// another thread as long as the keys and values are.
+unsafe impl<K: Send, V: Send> Send for SkipList<K, V> {}
+unsafe impl<K: Sync, V: Sync> Sync for SkipList<K, V> {}
+
+impl<K: Ord, V> SkipList<K, V> {
+    pub fn new() -> Self {
+        Self::with_config(DEFAULT_MAX_LEVEL, DEFAULT_PROBABILITY)
+    }
+
+    pub fn with_config(max_level: usize, probability: f64) -> Self {
+        if max_level == 0 {
+            panic!("Skip List needs at least one level!");
+        }
+        if !(probability > 0.0 && probability < 1.0) {
+            panic!("Skip List promotion probability must be between 0 and 1!");
+        }
+        let mut head = HeapArray::with_capacity(max_level);
+        for _i in 0..max_level {
+            head.push(Link {
+                node: ptr::null_mut(),
+                width: 1,
+            });
+        }
+        Self {
+            head,
+            length: 0,
+            max_level,
+            probability,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn max_level(&self) -> usize {
+        self.max_level
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    // A null node stands for the head.
+    fn forward(&self, node: *mut SkipNode<K, V>) -> &HeapArray<Link<K, V>> {
+        if node.is_null() {
+            &self.head
+        } else {
+            unsafe { &(*node).forward }
+        }
+    }
+
+    fn forward_mut(&mut self, node: *mut SkipNode<K, V>) -> &mut HeapArray<Link<K, V>> {
+        if node.is_null() {
+            &mut self.head
+        } else {
+            unsafe { &mut (*node).forward }
+        }
+    }
+
+    fn random_level(&mut self) -> usize {
+        let mut level: usize = 1;
+        while level < self.max_level && self.rng.gen_bool(self.probability) {
+            level += 1;
+        }
+        level
+    }
+
+    // Finds the last node before the key on every level and how many nodes were passed to reach it.
+    // Time Complexity is O(log n) on average
+    fn find_path(&self, key: &K) -> (HeapArray<*mut SkipNode<K, V>>, HeapArray<usize>) {
+        let mut update: HeapArray<*mut SkipNode<K, V>> = HeapArray::with_capacity(self.max_level);
+        let mut rank: HeapArray<usize> = HeapArray::with_capacity(self.max_level);
+        update.fill(ptr::null_mut());
+        rank.fill(0);
+
+        let mut current: *mut SkipNode<K, V> = ptr::null_mut();
+        let mut traversed: usize = 0;
+        for level in (0..self.max_level).rev() {
+            loop {
+                let link = self.forward(current)[level];
+                if !link.node.is_null() && unsafe { &(*link.node).key } < key {
+                    traversed += link.width;
+                    current = link.node;
+                } else {
+                    break;
+                }
+            }
+            update.set(level, current);
+            rank.set(level, traversed);
+        }
+        (update, rank)
+    }
+
+    // Returns the first node with a key that is not less than the given key.
+    fn lower_bound(&self, key: &K) -> *mut SkipNode<K, V> {
+        let (update, _rank) = self.find_path(key);
+        self.forward(update[0])[0].node
+    }
+
+    fn find(&self, key: &K) -> *mut SkipNode<K, V> {
+        let node = self.lower_bound(key);
+        if !node.is_null() && unsafe { &(*node).key } == key {
+            return node;
+        }
+        ptr::null_mut()
+    }
+
+    // Returns the old value if the key was already present.
+    // Time Complexity is O(log n) on average
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let (update, rank) = self.find_path(&key);
+        let next = self.forward(update[0])[0].node;
+        if !next.is_null() && unsafe { &(*next).key } == &key {
+            return Some(mem::replace(unsafe { &mut (*next).value }, value));
+        }
+
+        let level = self.random_level();
+        let mut forward: HeapArray<Link<K, V>> = HeapArray::with_capacity(level);
+        let node = HeapBox::leak(HeapBox::new(SkipNode {
+            key,
+            value,
+            forward: HeapArray::new(),
+        }))
+        .as_ptr();
+        for i in 0..level {
+            let link = self.forward(update[i])[i];
+            let offset = rank[0] - rank[i];
+            forward.push(Link {
+                node: link.node,
+                width: link.width - offset,
+            });
+            self.forward_mut(update[i])[i] = Link {
+                node,
+                width: offset + 1,
+            };
+        }
+        unsafe {
+            (*node).forward = forward;
+        }
+        for i in level..self.max_level {
+            self.forward_mut(update[i])[i].width += 1;
+        }
+        self.length += 1;
+        None
+    }
+
+    // Time Complexity is O(log n) on average
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (update, _rank) = self.find_path(key);
+        let target = self.forward(update[0])[0].node;
+        if target.is_null() || unsafe { &(*target).key } != key {
+            return None;
+        }
+        for i in 0..self.max_level {
+            let link = self.forward(update[i])[i];
+            if link.node == target {
+                let next = unsafe { (&(*target).forward)[i] };
+                self.forward_mut(update[i])[i] = Link {
+                    node: next.node,
+                    width: link.width + next.width - 1,
+                };
+            } else {
+                self.forward_mut(update[i])[i].width -= 1;
+            }
+        }
+        self.length -= 1;
+        let node = HeapBox::into_inner(HeapBox::unleak(NonNull::new(target).unwrap()));
+        Some(node.value)
+    }
+
+    // Time Complexity is O(log n) on average
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let node = self.find(key);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(&(*node).value) }
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let node = self.find(key);
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some(&mut (*node).value) }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        !self.find(key).is_null()
+    }
+
+    // Greatest key that is less than or equal to the given key.
+    // Time Complexity is O(log n) on average
+    pub fn floor(&self, key: &K) -> Option<(&K, &V)> {
+        let (update, _rank) = self.find_path(key);
+        let next = self.forward(update[0])[0].node;
+        if !next.is_null() && unsafe { &(*next).key } == key {
+            return Self::entry(next);
+        }
+        Self::entry(update[0])
+    }
+
+    // Smallest key that is greater than or equal to the given key.
+    // Time Complexity is O(log n) on average
+    pub fn ceiling(&self, key: &K) -> Option<(&K, &V)> {
+        Self::entry(self.lower_bound(key))
+    }
+
+    // Number of keys that are less than the given key. It is the index of the key when the key is present.
+    // Time Complexity is O(log n) on average
+    pub fn rank(&self, key: &K) -> usize {
+        let (_update, rank) = self.find_path(key);
+        rank[0]
+    }
+
+    // Key and value at the given zero based index in the sorted order.
+    // Time Complexity is O(log n) on average
+    pub fn select(&self, index: usize) -> Option<(&K, &V)> {
+        if index >= self.length {
+            return None;
+        }
+        let target = index + 1;
+        let mut current: *mut SkipNode<K, V> = ptr::null_mut();
+        let mut traversed: usize = 0;
+        for level in (0..self.max_level).rev() {
+            loop {
+                let link = self.forward(current)[level];
+                if !link.node.is_null() && traversed + link.width <= target {
+                    traversed += link.width;
+                    current = link.node;
+                } else {
+                    break;
+                }
+            }
+            if traversed == target {
+                break;
+            }
+        }
+        Self::entry(current)
+    }
+
+    fn entry<'a>(node: *mut SkipNode<K, V>) -> Option<(&'a K, &'a V)> {
+        if node.is_null() {
+            return None;
+        }
+        unsafe { Some((&(*node).key, &(*node).value)) }
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        Self::entry(self.head[0].node)
+    }
+
+    pub fn iter(&self) -> SkipListIterator<'_, K, V> {
+        SkipListIterator {
+            current: self.head[0].node,
+            stop: ptr::null_mut(),
+            marker: PhantomData,
+        }
+    }
+
+    // Time Complexity is O(log n) to find both ends of the range and then O(1) per entry.
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> SkipListIterator<'_, K, V> {
+        let mut start = match range.start_bound() {
+            Bound::Included(key) => self.lower_bound(key),
+            Bound::Excluded(key) => {
+                let node = self.lower_bound(key);
+                if !node.is_null() && unsafe { &(*node).key } == key {
+                    unsafe { (&(*node).forward)[0].node }
+                } else {
+                    node
+                }
+            }
+            Bound::Unbounded => self.head[0].node,
+        };
+        let stop = match range.end_bound() {
+            Bound::Included(key) => {
+                let node = self.lower_bound(key);
+                if !node.is_null() && unsafe { &(*node).key } == key {
+                    unsafe { (&(*node).forward)[0].node }
+                } else {
+                    node
+                }
+            }
+            Bound::Excluded(key) => self.lower_bound(key),
+            Bound::Unbounded => ptr::null_mut(),
+        };
+        // Every node between start and stop is in the range unless the range itself is empty.
+        if !start.is_null() && !range.contains(unsafe { &(*start).key }) {
+            start = stop;
+        }
+        SkipListIterator {
+            current: start,
+            stop,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<K: Ord, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Display + Ord, V: Display> Display for SkipList<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", key, value)?;
+        }
+        write!(f, "}}")?;
+        Ok(())
+    }
+}
+
+impl<K, V> Drop for SkipList<K, V> {
+    fn drop(&mut self) {
+        let mut current = self.head[0].node;
+        while !current.is_null() {
+            let node = HeapBox::unleak(NonNull::new(current).unwrap());
+            current = node.forward[0].node;
+        }
+    }
+}
+
+pub struct SkipListIterator<'a, K, V> {
+    current: *mut SkipNode<K, V>,
+    stop: *mut SkipNode<K, V>,
+    marker: PhantomData<&'a SkipNode<K, V>>,
+}
+
+impl<'a, K, V> Iterator for SkipListIterator<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_null() || self.current == self.stop {
+            return None;
+        }
+        let node = unsafe { &*self.current };
+        self.current = node.forward[0].node;
+        Some((&node.key, &node.value))
+    }
+}
+
+#[cfg(test)]
+mod skip_list {
+    use crate::structs::skip_lists::SkipList;
+    use rand::{thread_rng, Rng};
+    use std::collections::BTreeMap;
+
+    fn random_pair(len: usize, max: u32) -> (SkipList<u32, u32>, BTreeMap<u32, u32>) {
+        let mut rng = thread_rng();
+        let mut list: SkipList<u32, u32> = SkipList::new();
+        let mut map: BTreeMap<u32, u32> = BTreeMap::new();
+        for _i in 0..len {
+            let key = rng.gen_range(0..max);
+            let value = rng.gen();
+            assert_eq!(
+                list.insert(key, value),
+                map.insert(key, value),
+                "Skip List insert returned invalid old value!"
+            );
+        }
+        (list, map)
+    }
+
+    // Verifies that every link width matches the number of nodes it skips on the bottom level.
+    fn assert_widths(list: &SkipList<u32, u32>) {
+        for level in 0..list.max_level {
+            let mut current = std::ptr::null_mut();
+            let mut rank: usize = 0;
+            loop {
+                let link = list.forward(current)[level];
+                let target_rank = match link.node.is_null() {
+                    true => list.len() + 1,
+                    false => list.rank(unsafe { &(*link.node).key }) + 1,
+                };
+                assert_eq!(
+                    link.width,
+                    target_rank - rank,
+                    "Skip List link width is invalid!"
+                );
+                if link.node.is_null() {
+                    break;
+                }
+                rank = target_rank;
+                current = link.node;
+            }
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let list: SkipList<u32, u32> = SkipList::new();
+        assert_eq!(list.len(), 0, "Skip List has invalid initial length!");
+        assert_eq!(
+            list.max_level(),
+            16,
+            "Skip List has invalid default max level!"
+        );
+        assert_eq!(
+            list.probability(),
+            0.5,
+            "Skip List has invalid default probability!"
+        );
+        assert!(list.first().is_none(), "Skip List must be empty!");
+    }
+
+    #[test]
+    fn test_with_config() {
+        let mut list: SkipList<u32, u32> = SkipList::with_config(4, 0.9);
+        for i in 0..200 {
+            list.insert(i, i);
+        }
+        let mut current = list.head[0].node;
+        while !current.is_null() {
+            let node = unsafe { &*current };
+            assert!(
+                node.forward.get_len() <= 4,
+                "Skip List node exceeds the max level!"
+            );
+            current = node.forward[0].node;
+        }
+        assert_widths(&list);
+    }
+
+    #[test]
+    #[should_panic(expected = "Skip List needs at least one level!")]
+    fn test_with_config_level_panic() {
+        let _list: SkipList<u32, u32> = SkipList::with_config(0, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Skip List promotion probability must be between 0 and 1!")]
+    fn test_with_config_probability_panic() {
+        let _list: SkipList<u32, u32> = SkipList::with_config(4, 1.0);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list: SkipList<u32, &str> = SkipList::new();
+        assert_eq!(
+            list.insert(2, "b"),
+            None,
+            "New key must not return a value!"
+        );
+        assert_eq!(
+            list.insert(1, "a"),
+            None,
+            "New key must not return a value!"
+        );
+        assert_eq!(
+            list.insert(2, "c"),
+            Some("b"),
+            "Existing key must return the old value!"
+        );
+        assert_eq!(list.len(), 2, "Skip List has invalid length!");
+        assert_eq!(format!("{}", list), "{1: a, 2: c}", "Skip List is invalid!");
+    }
+
+    #[test]
+    fn test_get() {
+        let (mut list, map) = random_pair(300, 500);
+        for key in 0..500 {
+            assert_eq!(list.get(&key), map.get(&key), "Skip List get is invalid!");
+            assert_eq!(
+                list.contains_key(&key),
+                map.contains_key(&key),
+                "Skip List contains is invalid!"
+            );
+        }
+        if let Some(value) = list.get_mut(map.keys().next().unwrap()) {
+            *value = 7;
+        }
+        assert_eq!(
+            list.first().unwrap().1,
+            &7,
+            "Skip List get_mut failed to update the value!"
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let (mut list, mut map) = random_pair(300, 500);
+        let mut rng = thread_rng();
+        for _i in 0..400 {
+            let key = rng.gen_range(0..500);
+            assert_eq!(
+                list.remove(&key),
+                map.remove(&key),
+                "Skip List remove is invalid!"
+            );
+            assert_eq!(
+                list.len(),
+                map.len(),
+                "Skip List has invalid length after remove!"
+            );
+        }
+        assert_widths(&list);
+        let entries: Vec<(u32, u32)> = list.iter().map(|(k, v)| (*k, *v)).collect();
+        let expected: Vec<(u32, u32)> = map.into_iter().collect();
+        assert_eq!(
+            entries, expected,
+            "Skip List entries are invalid after removals!"
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let (list, map) = random_pair(200, 1000);
+        let entries: Vec<(&u32, &u32)> = list.iter().collect();
+        let expected: Vec<(&u32, &u32)> = map.iter().collect();
+        assert_eq!(entries, expected, "Skip List iteration order is invalid!");
+        assert_widths(&list);
+    }
+
+    #[test]
+    fn test_floor_and_ceiling() {
+        let (list, map) = random_pair(100, 300);
+        for key in 0..310 {
+            assert_eq!(
+                list.floor(&key),
+                map.range(..=key).next_back(),
+                "Skip List floor is invalid!"
+            );
+            assert_eq!(
+                list.ceiling(&key),
+                map.range(key..).next(),
+                "Skip List ceiling is invalid!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rank_and_select() {
+        let (list, map) = random_pair(200, 400);
+        let keys: Vec<&u32> = map.keys().collect();
+        for key in 0..410 {
+            assert_eq!(
+                list.rank(&key),
+                map.range(..key).count(),
+                "Skip List rank is invalid!"
+            );
+        }
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(
+                list.select(index).map(|(k, _v)| k),
+                Some(*key),
+                "Skip List select is invalid!"
+            );
+        }
+        assert_eq!(
+            list.select(keys.len()),
+            None,
+            "Select past the end must be None!"
+        );
+    }
+
+    #[test]
+    fn test_range() {
+        let (list, map) = random_pair(100, 200);
+        for (low, high) in [(0, 200), (10, 50), (50, 50), (120, 110), (199, 250)] {
+            let expected: Vec<(&u32, &u32)> = match low <= high {
+                true => map.range(low..high).collect(),
+                false => Vec::new(),
+            };
+            assert_eq!(
+                list.range(low..high).collect::<Vec<_>>(),
+                expected,
+                "Skip List half open range is invalid!"
+            );
+            if low <= high {
+                let expected: Vec<(&u32, &u32)> = map.range(low..=high).collect();
+                assert_eq!(
+                    list.range(low..=high).collect::<Vec<_>>(),
+                    expected,
+                    "Skip List closed range is invalid!"
+                );
+            }
+            let expected: Vec<(&u32, &u32)> = map.range(low..).collect();
+            assert_eq!(
+                list.range(low..).collect::<Vec<_>>(),
+                expected,
+                "Skip List open ended range is invalid!"
+            );
+            let expected: Vec<(&u32, &u32)> = map.range(..high).collect();
+            assert_eq!(
+                list.range(..high).collect::<Vec<_>>(),
+                expected,
+                "Skip List range without start is invalid!"
+            );
+        }
+    }
+
+    #[test]
+    fn test_drop() {
+        let mut list: SkipList<u32, String> = SkipList::new();
+        for i in 0..1000 {
+            list.insert(i, i.to_string());
+        }
+        assert_eq!(
+            list.remove(&10),
+            Some("10".to_string()),
+            "Skip List remove is invalid!"
+        );
+        drop(list);
+    }
+}
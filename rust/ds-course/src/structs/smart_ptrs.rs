@@ -37,6 +37,17 @@ impl<T> HeapBox<T> {
         Self { ptr }
     }
 
+    // Moves the value out and frees the memory without dropping the value.
+    pub fn into_inner(smart_ptr: HeapBox<T>) -> T {
+        let layout = Layout::new::<T>();
+        let ptr = HeapBox::leak(smart_ptr);
+        unsafe {
+            let value = ptr::read(ptr.as_ptr());
+            alloc::dealloc(ptr.as_ptr() as *mut u8, layout);
+            value
+        }
+    }
+
     pub fn as_ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
@@ -266,6 +277,13 @@ mod heap_box {
         }
     }
 
+    #[test]
+    fn test_into_inner() {
+        let s_ptr = HeapBox::new(String::from("hello"));
+        let value = HeapBox::into_inner(s_ptr);
+        assert_eq!(value, "hello", "Moved out value is different!");
+    }
+
     #[test]
     fn test_as_ptr() {
         let s_ptr: HeapBox<u8> = HeapBox::new(10);
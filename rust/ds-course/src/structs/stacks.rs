@@ -1,76 +1,202 @@
 use crate::structs::arrays::HeapArray;
-use crate::structs::linked_lists::{NodeType, LinkedListADT, SinglyLinkedList};
-use std::ptr::NonNull;
+use crate::structs::linked_lists::{LinkedListADT, SinglyLinkedList};
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverflowPolicy {
+    // Refuse the new element.
+    Error,
+    // Double the capacity of the Array.
+    Grow,
+    // Drop the element at the bottom of the Stack to make room.
+    OverwriteOldest,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StackError {
+    Overflow,
+    Underflow,
+}
+
+impl Display for StackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StackError::Overflow => write!(f, "Stack is full!"),
+            StackError::Underflow => write!(f, "Stack is empty!"),
+        }
+    }
+}
+
+impl Error for StackError {}
+
+pub trait StackADT<T> {
+    fn push(&mut self, data: T);
+
+    fn pop(&mut self) -> Option<T>;
+
+    fn peek(&self) -> Option<&T>;
+
+    // Index 0 is the top of the Stack.
+    fn peek_at(&self, index: usize) -> Option<&T>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn is_full(&self) -> bool;
+
+    // Iterates from the top to the bottom of the Stack.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    fn clear(&mut self);
+}
 
 pub struct Stack<T> {
-    top: NonNull<T>,
     size: usize,
     length: usize,
+    policy: OverflowPolicy,
     data: HeapArray<T>, // It would be better to use an Array on Stack since it's fixed size.
 }
 
 impl<T> Stack<T> {
     pub fn new(size: usize) -> Self {
-        let arr = HeapArray::with_capacity(size);
+        Self::with_policy(size, OverflowPolicy::Error)
+    }
+
+    pub fn with_policy(size: usize, policy: OverflowPolicy) -> Self {
         Self {
-            top: NonNull::new(arr.as_ptr_mut()).unwrap(),
             size,
             length: 0,
-            data: arr,
+            policy,
+            data: HeapArray::with_capacity(size),
         }
     }
 
-    pub fn push(&mut self, data: T) {
+    pub fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.size
+    }
+
+    fn grow(&mut self) {
+        let size = match self.size {
+            0 => 1,
+            _ => self.size * 2,
+        };
+        // An Array without capacity has no memory to resize yet.
+        match self.size {
+            0 => self.data = HeapArray::with_capacity(size),
+            _ => self
+                .data
+                .resize(size)
+                .expect("Failed to increase the Stack capacity!"),
+        }
+        self.size = size;
+    }
+
+    // Time Complexity is O(1), except when the Stack grows or the oldest element is overwritten which is O(n).
+    pub fn try_push(&mut self, data: T) -> Result<(), StackError> {
+        if self.is_full() {
+            match self.policy {
+                OverflowPolicy::Error => return Err(StackError::Overflow),
+                OverflowPolicy::Grow => self.grow(),
+                OverflowPolicy::OverwriteOldest => {
+                    if self.size == 0 {
+                        return Err(StackError::Overflow);
+                    }
+                    // The Array shifts all the elements left after the bottom element is removed.
+                    self.data.delete(0);
+                    self.length -= 1;
+                }
+            }
+        }
         self.data.push(data);
         self.length += 1;
-        let ptr: *mut T;
-        if self.length > 1 {
-            ptr = unsafe { self.data.as_ptr_mut().add(self.data.get_len() - 1) };
-        } else {
-            ptr = self.data.as_ptr_mut();
-        }
-        self.top = NonNull::new(ptr).unwrap();
+        Ok(())
+    }
+
+    pub fn try_pop(&mut self) -> Result<T, StackError> {
+        self.pop().ok_or(StackError::Underflow)
+    }
+
+    // Index 0 is the bottom of the Stack.
+    pub fn get(&self, index: usize) -> &T {
+        self.data.get(index)
     }
 
-    pub fn pop(&mut self) -> Option<T> {
+    pub fn top(&self) -> Option<&T> {
         if self.length == 0 {
             return None;
         }
-        let data = self.data.pop().unwrap();
-        self.length -= 1;
-        let ptr = unsafe { self.data.as_ptr_mut().add(self.data.get_len()) };
-        self.top = NonNull::new(ptr).unwrap();
-        Some(data)
+        Some(self.data.get(self.length - 1))
     }
 
-    pub fn peek(&self) -> Option<&T> {
+    pub fn top_mut(&mut self) -> Option<&mut T> {
         if self.length == 0 {
             return None;
         }
-        Some(self.data.get(self.data.get_len() - 1))
+        Some(&mut self.data[self.length - 1])
     }
+}
 
-    pub fn get(&self, index: usize) -> &T {
-        self.data.get(index)
+impl<T> StackADT<T> for Stack<T> {
+    // Panics when the Stack is full and the policy doesn't allow to make room for the element.
+    fn push(&mut self, data: T) {
+        if let Err(e) = self.try_push(data) {
+            panic!("{}", e);
+        }
     }
 
-    pub fn get_top(&self) -> NonNull<T> {
-        self.top
+    fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
+        let data = self.data.pop().unwrap();
+        self.length -= 1;
+        Some(data)
     }
 
-    pub fn is_empty(&self) -> bool {
-        if self.length == 0 {
-            return true;
+    fn peek(&self) -> Option<&T> {
+        self.top()
+    }
+
+    // Time Complexity is O(1)
+    fn peek_at(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
         }
-        false
+        Some(self.data.get(self.length - 1 - index))
+    }
+
+    fn len(&self) -> usize {
+        self.length
     }
 
-    pub fn is_full(&self) -> bool {
+    fn is_full(&self) -> bool {
         if self.length == self.size {
             return true;
         }
         false
     }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        (0..self.length).rev().map(|i| self.data.get(i))
+    }
+
+    fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
 }
 
 pub struct DynamicStack<T> {
@@ -86,18 +212,18 @@ impl<T> DynamicStack<T> {
             data: ll,
         }
     }
+}
 
+// The Linked List can only hand out the popped values by copying them.
+impl<T: Copy> StackADT<T> for DynamicStack<T> {
     // Time Complexity is O(1)
-    pub fn push(&mut self, data: T) where T: Clone {
+    fn push(&mut self, data: T) {
         self.data.push_front(data);
         self.length += 1;
     }
 
     // Time Complexity is O(1)
-    pub fn pop(&mut self) -> Option<T>
-    where
-        T: Copy,
-    {
+    fn pop(&mut self) -> Option<T> {
         if self.length == 0 {
             return None;
         }
@@ -106,41 +232,51 @@ impl<T> DynamicStack<T> {
         Some(data)
     }
 
-    // TODO: Use to get top value only and have separate method for get with index.
+    // Time Complexity is O(1)
+    fn peek(&self) -> Option<&T> {
+        self.peek_at(0)
+    }
+
     // Time Complexity is min: O(1), max: O(n)
-    pub fn peek(&self, index: usize) -> Option<&T> where T: Clone {
+    fn peek_at(&self, index: usize) -> Option<&T> {
+        if index >= self.length {
+            return None;
+        }
         Some(self.data.peek(index))
     }
 
-    pub fn top(&self) -> Option<&NodeType<T>> {
-        self.data.head_as_ref()
+    fn len(&self) -> usize {
+        self.length
     }
 
-    pub fn is_empty(&self) -> bool {
-        if self.length == 0 {
-            return true;
-        }
+    fn is_full(&self) -> bool {
         false
     }
 
-    pub fn is_full(&self) -> bool {
-        false
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.data.iter()
+    }
+
+    fn clear(&mut self) {
+        while self.pop().is_some() {}
     }
 }
 
 #[cfg(test)]
 mod stack {
     use crate::structs::arrays::HeapArray;
-    use crate::structs::stacks::Stack;
-    use std::ptr::NonNull;
+    use crate::structs::stacks::{OverflowPolicy, Stack, StackADT, StackError};
 
     #[test]
     fn test_new() {
         let stack: Stack<u8> = Stack::new(5);
         assert_eq!(
-            stack.top,
-            NonNull::new(stack.data.as_ptr_mut()).unwrap(),
-            "Stack top pointer is invalid!"
+            stack.policy,
+            OverflowPolicy::Error,
+            "Stack policy is invalid!"
         );
         assert_eq!(stack.size, 5, "Stack size is invalid!");
         assert_eq!(stack.length, 0, "Stack length is invalid!");
@@ -151,11 +287,7 @@ mod stack {
     fn test_push() {
         let mut stack: Stack<u8> = Stack::new(5);
         stack.push(1);
-        assert_eq!(
-            stack.top,
-            NonNull::new(stack.data.as_ptr_mut()).unwrap(),
-            "Stack top pointer is invalid!"
-        );
+        assert_eq!(stack.top(), Some(&1), "Stack top is invalid!");
         assert_eq!(stack.size, 5, "Stack size is invalid!");
         assert_eq!(stack.length, 1, "Stack length is invalid!");
         assert_eq!(
@@ -179,13 +311,7 @@ mod stack {
         stack.push(2);
         stack.push(3);
         assert_eq!(stack.pop(), Some(3), "Stack pop value is invalid!");
-        unsafe {
-            assert_eq!(
-                stack.top,
-                NonNull::new(stack.data.as_ptr_mut().add(2)).unwrap(),
-                "Stack top pointer is invalid!"
-            );
-        }
+        assert_eq!(stack.top(), Some(&2), "Stack top is invalid!");
         assert_eq!(stack.length, 2, "Stack length is invalid!");
         assert_eq!(
             format!("{}", stack.data),
@@ -196,11 +322,7 @@ mod stack {
         stack.pop();
         assert_eq!(stack.pop(), None, "Stack pop value is invalid!");
         assert_eq!(stack.length, 0, "Stack length is invalid!");
-        assert_eq!(
-            stack.top,
-            NonNull::new(stack.data.as_ptr_mut()).unwrap(),
-            "Stack top pointer is invalid!"
-        );
+        assert_eq!(stack.top(), None, "Empty stack top is invalid!");
     }
 
     #[test]
@@ -221,11 +343,156 @@ mod stack {
     }
 
     #[test]
-    fn test_get_top() {
+    fn test_top() {
+        let mut stack: Stack<u8> = Stack::new(5);
+        assert_eq!(stack.top(), None, "Empty stack top is invalid!");
+        stack.push(1);
+        stack.push(2);
+        assert_eq!(stack.top(), Some(&2), "Stack top is invalid!");
+    }
+
+    #[test]
+    fn test_peek_at() {
         let mut stack: Stack<u8> = Stack::new(5);
         stack.push(1);
         stack.push(2);
-        assert_eq!(stack.get_top(), stack.top, "Stack top pointer is invalid!");
+        assert_eq!(
+            stack.peek_at(0),
+            Some(&2),
+            "Stack peek value at index 0 is invalid!"
+        );
+        assert_eq!(
+            stack.peek_at(1),
+            Some(&1),
+            "Stack peek value at index 1 is invalid!"
+        );
+        assert_eq!(
+            stack.peek_at(2),
+            None,
+            "Stack peek value at index 2 is invalid!"
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut stack: Stack<u8> = Stack::new(5);
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+        assert_eq!(
+            stack.iter().copied().collect::<Vec<u8>>(),
+            vec![3, 2, 1],
+            "Stack must be iterated from the top!"
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut stack: Stack<u8> = Stack::new(5);
+        stack.push(1);
+        stack.push(2);
+        stack.clear();
+        assert_eq!(stack.len(), 0, "Stack length is invalid!");
+        assert_eq!(stack.top(), None, "Cleared stack must be empty!");
+        stack.push(3);
+        assert_eq!(stack.top(), Some(&3), "Cleared stack must be reusable!");
+    }
+
+    #[test]
+    fn test_top_mut() {
+        let mut stack: Stack<u8> = Stack::new(5);
+        assert_eq!(stack.top_mut(), None, "Empty stack top is invalid!");
+        stack.push(1);
+        stack.push(2);
+        *stack.top_mut().unwrap() = 5;
+        assert_eq!(stack.pop(), Some(5), "Stack top was not updated!");
+        assert_eq!(stack.len(), 1, "Stack length is invalid!");
+    }
+
+    #[test]
+    fn test_try_push_error() {
+        let mut stack: Stack<u8> = Stack::new(2);
+        assert_eq!(stack.try_push(1), Ok(()), "Stack push must succeed!");
+        assert_eq!(stack.try_push(2), Ok(()), "Stack push must succeed!");
+        assert_eq!(
+            stack.try_push(3),
+            Err(StackError::Overflow),
+            "Full stack must refuse the element!"
+        );
+        assert_eq!(stack.len(), 2, "Stack length is invalid!");
+        assert_eq!(stack.top(), Some(&2), "Stack top is invalid!");
+    }
+
+    #[test]
+    #[should_panic(expected = "Stack is full!")]
+    fn test_push_panic() {
+        let mut stack: Stack<u8> = Stack::new(1);
+        stack.push(1);
+        stack.push(2);
+    }
+
+    #[test]
+    fn test_try_push_grow() {
+        let mut stack: Stack<u8> = Stack::with_policy(2, OverflowPolicy::Grow);
+        for i in 0..5 {
+            assert_eq!(
+                stack.try_push(i),
+                Ok(()),
+                "Growable stack push must succeed!"
+            );
+        }
+        assert_eq!(stack.capacity(), 8, "Stack capacity must double!");
+        assert_eq!(
+            format!("{}", stack.data),
+            "[0, 1, 2, 3, 4]".to_string(),
+            "Stack array is invalid!"
+        );
+        assert_eq!(stack.top(), Some(&4), "Stack top is invalid after growing!");
+
+        let mut stack: Stack<u8> = Stack::with_policy(0, OverflowPolicy::Grow);
+        stack.push(1);
+        assert_eq!(stack.capacity(), 1, "Empty stack must grow to one element!");
+    }
+
+    #[test]
+    fn test_try_push_overwrite_oldest() {
+        let mut stack: Stack<u8> = Stack::with_policy(3, OverflowPolicy::OverwriteOldest);
+        for i in 1..=5 {
+            assert_eq!(
+                stack.try_push(i),
+                Ok(()),
+                "Overwriting stack push must succeed!"
+            );
+        }
+        assert_eq!(stack.capacity(), 3, "Stack capacity must not change!");
+        assert_eq!(
+            format!("{}", stack.data),
+            "[3, 4, 5]".to_string(),
+            "Oldest elements must be overwritten!"
+        );
+        let mut stack: Stack<u8> = Stack::with_policy(0, OverflowPolicy::OverwriteOldest);
+        assert_eq!(
+            stack.try_push(1),
+            Err(StackError::Overflow),
+            "Stack without capacity can't overwrite!"
+        );
+    }
+
+    #[test]
+    fn test_try_pop() {
+        let mut stack: Stack<u8> = Stack::new(2);
+        assert_eq!(
+            stack.try_pop(),
+            Err(StackError::Underflow),
+            "Empty stack must underflow!"
+        );
+        stack.push(1);
+        assert_eq!(stack.try_pop(), Ok(1), "Stack pop value is invalid!");
+        assert_eq!(
+            format!("{}", StackError::Underflow),
+            "Stack is empty!",
+            "Stack error message is invalid!"
+        );
     }
 
     #[test]
@@ -247,13 +514,12 @@ mod stack {
 }
 
 mod dynamic_stack {
-    use crate::structs::linked_lists::LinkedListADT;
-    use crate::structs::stacks::DynamicStack;
+    use crate::structs::stacks::{DynamicStack, StackADT};
 
     #[test]
     fn test_new() {
         let stack: DynamicStack<u8> = DynamicStack::new();
-        assert_eq!(stack.top(), None, "Stack top pointer is invalid!");
+        assert_eq!(stack.peek(), None, "Stack top is invalid!");
         assert_eq!(stack.length, 0, "Stack length is invalid!");
         assert_eq!(
             format!("{}", stack.data),
@@ -295,30 +561,47 @@ mod dynamic_stack {
         let mut stack: DynamicStack<u8> = DynamicStack::new();
         stack.push(1);
         stack.push(2);
+        assert_eq!(stack.peek(), Some(&2u8), "Stack peek value is invalid!");
         assert_eq!(
-            stack.peek(0),
+            stack.peek_at(0),
             Some(&2u8),
             "Stack peek value at index 0 is invalid!"
         );
         assert_eq!(
-            stack.peek(1),
+            stack.peek_at(1),
             Some(&1u8),
             "Stack peek value at index 1 is invalid!"
         );
+        assert_eq!(
+            stack.peek_at(2),
+            None,
+            "Stack peek value at index 2 is invalid!"
+        );
     }
 
     #[test]
-    fn test_top() {
+    fn test_iter() {
         let mut stack: DynamicStack<u8> = DynamicStack::new();
         stack.push(1);
         stack.push(2);
+        stack.push(3);
         assert_eq!(
-            stack.top(),
-            stack.data.head_as_ref(),
-            "Stack top pointer is invalid!"
+            stack.iter().copied().collect::<Vec<u8>>(),
+            vec![3, 2, 1],
+            "Stack must be iterated from the top!"
         );
     }
 
+    #[test]
+    fn test_clear() {
+        let mut stack: DynamicStack<u8> = DynamicStack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.clear();
+        assert_eq!(stack.len(), 0, "Stack length is invalid!");
+        assert_eq!(stack.pop(), None, "Cleared stack must be empty!");
+    }
+
     #[test]
     fn test_is_empty() {
         let mut stack: DynamicStack<u8> = DynamicStack::new();
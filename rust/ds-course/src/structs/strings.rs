@@ -69,12 +69,57 @@ impl HeapString {
         // self.data.left_shift()
     }
 
+    fn check_boundary(&self, index: usize) {
+        if index > self.data.get_len() {
+            panic!("Index out of bounds!");
+        }
+        if !self.as_str().is_char_boundary(index) {
+            panic!("Index is not on a character boundary!");
+        }
+    }
+
+    // The index is a byte offset and has to be on a character boundary.
+    // Time Complexity is O(n + m)
+    pub fn insert_str(&mut self, index: usize, string: &str) {
+        self.check_boundary(index);
+        let required = self.data.get_len() + string.len();
+        if required > self.data.get_size() {
+            // An Array without capacity has no memory to resize yet.
+            match self.data.get_size() {
+                0 => self.data = HeapArray::with_capacity(required),
+                _ => self
+                    .data
+                    .resize(required)
+                    .expect("Failed to resize the array"),
+            }
+        }
+        for (i, &byte) in string.as_bytes().iter().enumerate() {
+            self.data.insert(index + i, byte);
+        }
+    }
+
+    // Removes len bytes starting at the index and returns them. Both ends have to be on a character boundary.
+    // Time Complexity is O(n * m)
+    pub fn remove_range(&mut self, index: usize, len: usize) -> HeapString {
+        self.check_boundary(index);
+        self.check_boundary(index + len);
+        let removed = HeapString::from(&self.as_str()[index..index + len]);
+        for _i in 0..len {
+            self.data.delete(index);
+        }
+        removed
+    }
+
     pub fn as_bytes(&self) -> &HeapArray<u8> {
         &self.data
     }
 
     // TODO: Add test for this method
     pub fn as_str(&self) -> &str {
+        // An empty string might not have allocated any memory yet.
+        if self.data.get_len() == 0 {
+            return "";
+        }
         unsafe {
             let slice = slice::from_raw_parts(self.data.as_ptr(), self.data.get_len());
             std::str::from_utf8(slice).expect("Invalid UTF-8 data")
@@ -333,6 +378,49 @@ mod heap_string {
         );
     }
 
+    #[test]
+    fn test_insert_str() {
+        let mut heap_string = HeapString::from("Hlo");
+        heap_string.insert_str(1, "el");
+        heap_string.insert_str(5, " wörld");
+        heap_string.insert_str(0, "");
+        assert_eq!(
+            heap_string.as_str(),
+            "Hello wörld",
+            "Testing string insertion"
+        );
+
+        let mut heap_string = HeapString::new();
+        heap_string.insert_str(0, "abc");
+        assert_eq!(
+            heap_string.as_str(),
+            "abc",
+            "Testing insertion into an empty string"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Index is not on a character boundary!")]
+    fn test_insert_str_panic() {
+        let mut heap_string = HeapString::from("ö");
+        heap_string.insert_str(1, "a");
+    }
+
+    #[test]
+    fn test_remove_range() {
+        let mut heap_string = HeapString::from("Hello wörld");
+        let removed = heap_string.remove_range(5, 6);
+        assert_eq!(removed.as_str(), " wörl", "Testing removed string");
+        assert_eq!(heap_string.as_str(), "Hellod", "Testing string removal");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds!")]
+    fn test_remove_range_panic() {
+        let mut heap_string = HeapString::from("Hello");
+        heap_string.remove_range(3, 3);
+    }
+
     #[test]
     fn test_to_lowercase() {
         let test_str = "HELLO";
@@ -113,10 +113,16 @@ impl Operator {
 pub enum Punctuation {
     // Semicolon,
     // Comma,
-    // LeftBrace,
-    // RightBrace,
     LeftParen,
     RightParen,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+    LeftAngle,
+    RightAngle,
+    SingleQuote,
+    DoubleQuote,
     Unknown,
 }
 
@@ -125,6 +131,14 @@ impl From<char> for Punctuation {
         match value {
             '(' => Punctuation::LeftParen,
             ')' => Punctuation::RightParen,
+            '[' => Punctuation::LeftBracket,
+            ']' => Punctuation::RightBracket,
+            '{' => Punctuation::LeftBrace,
+            '}' => Punctuation::RightBrace,
+            '<' => Punctuation::LeftAngle,
+            '>' => Punctuation::RightAngle,
+            '\'' => Punctuation::SingleQuote,
+            '"' => Punctuation::DoubleQuote,
             _ => Punctuation::Unknown,
         }
     }
@@ -132,22 +146,60 @@ impl From<char> for Punctuation {
 
 impl Punctuation {
     fn charset() -> HashSet<char> {
-        HashSet::from(['(', ')'])
+        HashSet::from(['(', ')', '[', ']', '{', '}', '<', '>', '\'', '"'])
     }
 
     fn to_char(&self) -> Option<char> {
         match self {
             Punctuation::LeftParen => Some('('),
             Punctuation::RightParen => Some(')'),
+            Punctuation::LeftBracket => Some('['),
+            Punctuation::RightBracket => Some(']'),
+            Punctuation::LeftBrace => Some('{'),
+            Punctuation::RightBrace => Some('}'),
+            Punctuation::LeftAngle => Some('<'),
+            Punctuation::RightAngle => Some('>'),
+            Punctuation::SingleQuote => Some('\''),
+            Punctuation::DoubleQuote => Some('"'),
+            _ => None,
+        }
+    }
+
+    pub fn is_opening(&self) -> bool {
+        matches!(
+            self,
+            Self::LeftParen | Self::LeftBracket | Self::LeftBrace | Self::LeftAngle
+        )
+    }
+
+    pub fn is_closing(&self) -> bool {
+        matches!(
+            self,
+            Self::RightParen | Self::RightBracket | Self::RightBrace | Self::RightAngle
+        )
+    }
+
+    pub fn is_quote(&self) -> bool {
+        matches!(self, Self::SingleQuote | Self::DoubleQuote)
+    }
+
+    // Returns the delimiter that closes this one. Quotes are closed by themselves.
+    pub fn closing(&self) -> Option<Punctuation> {
+        match self {
+            Self::LeftParen => Some(Self::RightParen),
+            Self::LeftBracket => Some(Self::RightBracket),
+            Self::LeftBrace => Some(Self::RightBrace),
+            Self::LeftAngle => Some(Self::RightAngle),
+            Self::SingleQuote => Some(Self::SingleQuote),
+            Self::DoubleQuote => Some(Self::DoubleQuote),
             _ => None,
         }
     }
 
     pub fn precedence(&self) -> usize {
         match self {
-            Self::LeftParen => 0,
-            Self::RightParen => 0,
             Self::Unknown => 100,
+            _ => 0,
         }
     }
 }
@@ -460,11 +512,42 @@ mod punctuation {
     fn test_charset() {
         assert_eq!(
             Punctuation::charset(),
-            HashSet::from(['(', ')']),
+            HashSet::from(['(', ')', '[', ']', '{', '}', '<', '>', '\'', '"']),
             "Punctuation charset is invalid!"
         );
     }
 
+    #[test]
+    fn test_delimiters() {
+        for c in Punctuation::charset() {
+            let punc = Punctuation::from(c);
+            assert_eq!(punc.to_char(), Some(c), "Punctuation char is invalid!");
+            assert!(
+                [punc.is_opening(), punc.is_closing(), punc.is_quote()]
+                    .iter()
+                    .filter(|&&kind| kind)
+                    .count()
+                    == 1,
+                "Punctuation must be exactly one kind of delimiter!"
+            );
+        }
+        assert_eq!(
+            Punctuation::LeftBracket.closing(),
+            Some(Punctuation::RightBracket),
+            "Closing delimiter for '[' is invalid!"
+        );
+        assert_eq!(
+            Punctuation::DoubleQuote.closing(),
+            Some(Punctuation::DoubleQuote),
+            "Quotes must close themselves!"
+        );
+        assert_eq!(
+            Punctuation::RightBrace.closing(),
+            None,
+            "Closing delimiter has nothing to close!"
+        );
+    }
+
     #[test]
     fn test_to_char() {
         assert_eq!(